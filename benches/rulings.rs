@@ -0,0 +1,167 @@
+//! Rulings per second, comparing each law scanning the action itself with
+//! the law set's single compiled pass.
+//!
+//! `cargo bench --bench rulings`
+
+use aho_corasick::{AhoCorasick, AhoCorasickKind};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use judicial_core::laws::{Law, LawPatterns, Scan};
+use judicial_core::{JudicialCore, LawSet, Strictness, SystemAction};
+use memchr::memmem::Finder;
+use std::sync::Arc;
+
+/// A keyword law of the kind deployments add next to the Master Pair.
+#[derive(Debug)]
+struct KeywordLaw {
+    number: u32,
+    keywords: &'static [&'static str],
+}
+
+impl Law for KeywordLaw {
+    fn number(&self) -> u32 {
+        self.number
+    }
+
+    fn description(&self) -> &str {
+        "keyword law"
+    }
+
+    fn check(&self, action: &SystemAction) -> Option<String> {
+        self.keywords.iter()
+            .find(|keyword| action.payload.contains(*keyword))
+            .map(|keyword| format!("mentions {}", keyword))
+    }
+
+    fn patterns(&self) -> LawPatterns {
+        LawPatterns { payload: self.keywords, context: &[] }
+    }
+
+    fn check_scanned(&self, _action: &SystemAction, scan: &Scan<'_>) -> Option<String> {
+        scan.first_payload().map(|index| format!("mentions {}", self.keywords[index]))
+    }
+}
+
+const KEYWORDS: [&[&str]; 8] = [
+    &["exfiltrate", "keylogger", "botnet", "ransom"],
+    &["bypass_auth", "disable_mfa", "sudo su", "chmod 777"],
+    &["crypto_miner", "xmrig", "stratum+tcp"],
+    &["phishing", "spoofed_sender", "credential_harvest"],
+    &["shadow_copy", "vssadmin", "bcdedit"],
+    &["reverse_shell", "nc -e", "bash -i"],
+    &["pii_dump", "customer_export", "bulk_download"],
+    &["telemetry_off", "audit_disable", "log_clear"],
+];
+
+fn wide_laws() -> LawSet {
+    KEYWORDS.iter().enumerate().fold(LawSet::master_pair(), |laws, (i, keywords)| {
+        laws.with(KeywordLaw { number: 10 + i as u32, keywords })
+    })
+}
+
+fn actions() -> Vec<SystemAction> {
+    let filler = "summarise the quarterly figures for the regional teams and draft a reply ".repeat(8);
+    vec![
+        SystemAction::new("DATA_ANALYSIS", filler.clone(), "research"),
+        SystemAction::new("DB_QUERY", format!("{} delete from sessions with rollback", filler), "ops,backup"),
+        SystemAction::new("DATA_EXPORT", format!("{} credit_card", filler), "audit,compliance_approved"),
+    ]
+}
+
+/// A law with its patterns hidden, so the law set cannot compile it and the
+/// law scans the action itself, as every law did before compilation.
+#[derive(Debug)]
+struct PerLaw(Arc<dyn Law>);
+
+impl Law for PerLaw {
+    fn number(&self) -> u32 {
+        self.0.number()
+    }
+
+    fn description(&self) -> &str {
+        self.0.description()
+    }
+
+    fn is_absolute(&self) -> bool {
+        self.0.is_absolute()
+    }
+
+    fn suggestion(&self) -> Option<&str> {
+        self.0.suggestion()
+    }
+
+    fn check(&self, action: &SystemAction) -> Option<String> {
+        self.0.check(action)
+    }
+
+    fn check_warning(&self, action: &SystemAction) -> Option<String> {
+        self.0.check_warning(action)
+    }
+}
+
+fn per_law(laws: &LawSet) -> LawSet {
+    laws.iter().fold(LawSet::new(), |set, law| set.with(PerLaw(Arc::clone(law))))
+}
+
+fn law_sets(c: &mut Criterion) {
+    let actions = actions();
+    let mut group = c.benchmark_group("law_set");
+    group.throughput(Throughput::Elements(actions.len() as u64));
+
+    for (name, laws) in [("master_pair", LawSet::master_pair()), ("master_pair+8", wide_laws())] {
+        for (pipeline, laws) in [("per_law_scans", per_law(&laws)), ("compiled", laws)] {
+            group.bench_with_input(BenchmarkId::new(pipeline, name), &laws, |b, laws| {
+                b.iter(|| {
+                    actions.iter()
+                        .filter(|action| laws.evaluate(black_box(action), Strictness::Standard).0.is_approved())
+                        .count()
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+fn core_rule(c: &mut Criterion) {
+    let core = JudicialCore::builder().laws(wide_laws()).build();
+    let actions = actions();
+    let mut group = c.benchmark_group("core");
+    group.throughput(Throughput::Elements(actions.len() as u64));
+    group.bench_function("rule", |b| {
+        b.iter(|| actions.iter().filter(|action| core.rule((*action).clone()).is_approved()).count())
+    });
+    group.bench_function("rule_ref", |b| {
+        b.iter(|| actions.iter().filter(|action| core.rule_ref(action).is_approved()).count())
+    });
+    group.bench_function("rule_batch", |b| {
+        b.iter(|| core.rule_batch(&actions).iter().filter(|verdict| verdict.is_approved()).count())
+    });
+    group.finish();
+}
+
+/// The two ways a compiled law set can search for its merged patterns,
+/// for choosing the pattern count at which it switches between them.
+fn matchers(c: &mut Criterion) {
+    let patterns: Vec<&str> = KEYWORDS.iter().flat_map(|keywords| keywords.iter().copied())
+        .chain(["rm -rf", "DROP TABLE", "DELETE FROM", "TRUNCATE", "mkfs", "dd if=", "password", "credit_card"])
+        .chain(["api_key", "private_key", "id_rsa", "/etc/shadow", "kill -9", "setenforce 0", "base64 -d", "wipe"])
+        .chain(["purge", "iptables -F", "curl | sh", "os.system", "authorized_keys", "exec(", "eval(", "ssn"])
+        .collect();
+    let payload = actions()[0].payload.clone();
+    let mut group = c.benchmark_group("matchers");
+
+    for count in [16, 24, 32, 40, 48] {
+        let patterns = &patterns[..count];
+        let finders: Vec<Finder<'_>> = patterns.iter().map(Finder::new).collect();
+        group.bench_with_input(BenchmarkId::new("finders", count), &finders, |b, finders| {
+            b.iter(|| finders.iter().filter(|finder| finder.find(black_box(payload.as_bytes())).is_some()).count())
+        });
+        let automaton = AhoCorasick::builder().kind(Some(AhoCorasickKind::DFA)).build(patterns).unwrap();
+        group.bench_with_input(BenchmarkId::new("automaton", count), &automaton, |b, automaton| {
+            b.iter(|| automaton.find_overlapping_iter(black_box(&payload)).count())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, law_sets, core_rule, matchers);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+fn main() {
+    #[cfg(feature = "grpc")]
+    grpc::generate();
+}
+
+/// Service code for `src/grpc.rs`. Messages are plain prost structs defined
+/// there, so no protoc is needed; `proto/judicial.proto` mirrors them.
+#[cfg(feature = "grpc")]
+mod grpc {
+    use tonic_build::manual::{Builder, Method, Service};
+
+    fn method(name: &str, route: &str, input: &str, output: &str) -> tonic_build::manual::MethodBuilder {
+        Method::builder()
+            .name(name)
+            .route_name(route)
+            .input_type(format!("crate::grpc::{}", input))
+            .output_type(format!("crate::grpc::{}", output))
+            .codec_path("tonic_prost::ProstCodec")
+    }
+
+    pub fn generate() {
+        let service = Service::builder()
+            .name("Judicial")
+            .package("judicial.v1")
+            .method(method("rule", "Rule", "ActionRequest", "VerdictReply").build())
+            .method(method("rule_batch", "RuleBatch", "BatchRequest", "BatchReply").build())
+            .method(method("get_compliance", "GetCompliance", "ComplianceRequest", "ComplianceReply").build())
+            .method(method("query_ledger", "QueryLedger", "LedgerQuery", "LedgerReply").build())
+            .method(
+                method("verdict_events", "VerdictEvents", "VerdictEventsRequest", "VerdictEvent")
+                    .server_streaming()
+                    .build(),
+            )
+            .build();
+
+        Builder::new().compile(&[service]);
+    }
+}
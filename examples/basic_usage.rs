@@ -1,6 +1,8 @@
 use judicial_core::{JudicialCore, SystemAction, Verdict};
 
 fn main() {
+    tracing_subscriber::fmt::init();
+
     println!("🚀 JUDICIAL CORE - MASTER PAIR ENFORCEMENT");
     
     let court = JudicialCore::new();
@@ -10,6 +12,9 @@ fn main() {
         action_type: "DATA_ANALYSIS".into(),
         payload: "analyze trends".into(),
         context: "research_encrypted".into(),
+        actor: None,
+        credentials: None,
+        roles: Vec::new(),
     };
     
     // Test unlawful action  
@@ -17,6 +22,9 @@ fn main() {
         action_type: "DATA_EXPORT".into(),
         payload: "download user passwords".into(),
         context: "standard".into(),
+        actor: None,
+        credentials: None,
+        roles: Vec::new(),
     };
     
     println!("Testing good action...");
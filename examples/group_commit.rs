@@ -0,0 +1,85 @@
+use judicial_core::ledger::{LedgerBackend, LedgerEntry};
+use judicial_core::{GroupCommit, JudicialCore, SystemAction, WriteMode};
+use std::io;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const THREADS: usize = 32;
+const RULINGS_PER_THREAD: usize = 100;
+/// What one fsync or transaction commit costs the simulated disk.
+const SYNC_COST: Duration = Duration::from_millis(2);
+
+/// Stands in for a durable store: every sync costs `SYNC_COST`, whether it
+/// covers one entry or a group.
+#[derive(Debug, Default)]
+struct SlowDisk {
+    entries: Vec<LedgerEntry>,
+    deferred: bool,
+}
+
+impl SlowDisk {
+    fn sync(&mut self) {
+        thread::sleep(SYNC_COST);
+    }
+}
+
+impl LedgerBackend for SlowDisk {
+    fn append(&mut self, entry: LedgerEntry) {
+        self.entries.push(entry);
+        if !self.deferred {
+            self.sync();
+        }
+    }
+
+    fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.sync();
+        Ok(())
+    }
+
+    fn defer_writes(&mut self, defer: bool) {
+        self.deferred = defer;
+    }
+}
+
+fn measure(mode: WriteMode) -> f64 {
+    let court = Arc::new(JudicialCore::builder().ledger_backend(SlowDisk::default()).write_mode(mode).build());
+    let started = Instant::now();
+
+    let workers: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let court = Arc::clone(&court);
+            thread::spawn(move || {
+                for i in 0..RULINGS_PER_THREAD {
+                    let action = SystemAction::new("DATA_READ", format!("SELECT {} FROM events", i), "research")
+                        .with_actor(format!("agent-{}", t));
+                    court.rule(action);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    let elapsed = started.elapsed();
+
+    assert!(court.verify_ledger().is_ok());
+    (THREADS * RULINGS_PER_THREAD) as f64 / elapsed.as_secs_f64()
+}
+
+fn main() {
+    println!(
+        "💾 DURABLE LEDGER THROUGHPUT ({} threads × {} rulings, {:?} per sync)",
+        THREADS, RULINGS_PER_THREAD, SYNC_COST
+    );
+
+    let direct = measure(WriteMode::Direct);
+    println!("   Direct:       {:>8.0} rulings/sec", direct);
+
+    let grouped = measure(WriteMode::GroupCommit(GroupCommit::new(Duration::from_millis(1))));
+    println!("   Group commit: {:>8.0} rulings/sec ({:.1}x)", grouped, grouped / direct);
+}
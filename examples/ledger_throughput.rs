@@ -0,0 +1,47 @@
+use judicial_core::{JudicialCore, SystemAction, WriteMode};
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+
+const THREADS: usize = 8;
+const RULINGS_PER_THREAD: usize = 20_000;
+
+fn measure(mode: WriteMode) -> f64 {
+    let court = Arc::new(JudicialCore::builder().write_mode(mode).build());
+    let started = Instant::now();
+
+    let workers: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let court = Arc::clone(&court);
+            thread::spawn(move || {
+                for i in 0..RULINGS_PER_THREAD {
+                    let action = SystemAction::new("DATA_READ", format!("SELECT {} FROM events", i), "research")
+                        .with_actor(format!("agent-{}", t));
+                    court.rule(action);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    // Reads wait for the hasher, so this includes draining the queue.
+    assert!(court.verify_ledger().is_ok());
+    let elapsed = started.elapsed();
+
+    (THREADS * RULINGS_PER_THREAD) as f64 / elapsed.as_secs_f64()
+}
+
+fn main() {
+    println!("⚡ LEDGER WRITE THROUGHPUT ({} threads × {} rulings)", THREADS, RULINGS_PER_THREAD);
+
+    let direct = measure(WriteMode::Direct);
+    println!("   Direct:     {:>10.0} rulings/sec", direct);
+
+    let background = measure(WriteMode::Background);
+    println!("   Background: {:>10.0} rulings/sec ({:.2}x)", background, background / direct);
+
+    let sharded = measure(WriteMode::Sharded);
+    println!("   Sharded:    {:>10.0} rulings/sec ({:.2}x)", sharded, sharded / direct);
+}
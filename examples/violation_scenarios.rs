@@ -50,15 +50,21 @@ fn main() {
             action_type: action_type.into(),
             payload: payload.into(),
             context: context.into(),
+            actor: None,
+            credentials: None,
+            roles: Vec::new(),
         };
         
         match court.rule(action) {
             Verdict::Approved => println!("   ✅ APPROVED"),
+            Verdict::ApprovedWithWarning(warning) => println!("   ⚠️  APPROVED WITH WARNING: {}", warning),
             Verdict::Rejected(reason) => println!("   ❌ REJECTED: {}", reason),
             Verdict::RejectedWithSuggestion(reason, suggestion) => {
                 println!("   ❌ REJECTED: {}", reason);
                 println!("   💡 Suggestion: {}", suggestion);
             }
+            Verdict::Quarantined(reason) => println!("   ⏸️  QUARANTINED: {}", reason),
+            Verdict::Malformed(reason) => println!("   🚫 MALFORMED: {}", reason),
         }
     }
     
@@ -0,0 +1,48 @@
+//! Build ledgers from generated actions and check the hash path: chains
+//! verify, survive a JSON round trip, and any altered entry is caught.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use judicial_core::ledger::{verify_entries, LedgerEntry, TamperProofLedger};
+use judicial_core::SystemAction;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+enum Record {
+    Approval(SystemAction),
+    Warning(SystemAction, String),
+    Violation(SystemAction, String),
+    PolicyChange(String, String),
+}
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    records: Vec<Record>,
+    /// Entry to alter, and the byte appended to its payload.
+    tamper: (usize, char),
+}
+
+fuzz_target!(|input: Input| {
+    let mut ledger = TamperProofLedger::new();
+    for record in input.records {
+        match record {
+            Record::Approval(action) => ledger.record_approval(action),
+            Record::Warning(action, warning) => ledger.record_warning(action, warning),
+            Record::Violation(action, reason) => ledger.record_violation(action, reason),
+            Record::PolicyChange(setting, change) => ledger.record_policy_change(&setting, change),
+        }
+    }
+    ledger.verify_integrity().expect("fresh chain does not verify");
+
+    let json = serde_json::to_string(ledger.entries()).unwrap();
+    let mut entries: Vec<LedgerEntry> = serde_json::from_str(&json).unwrap();
+    verify_entries(&entries).expect("chain does not survive a JSON round trip");
+
+    if entries.is_empty() {
+        return;
+    }
+    let (index, byte) = input.tamper;
+    let index = index % entries.len();
+    entries[index].action.payload.push(byte);
+    assert!(verify_entries(&entries).is_err(), "altered entry {} went unnoticed", index);
+});
@@ -0,0 +1,18 @@
+//! Parse arbitrary text as a policy pack and, when it parses, install it.
+//! Neither step may panic; a pack that installs must leave a working core.
+#![no_main]
+
+use judicial_core::{JudicialCore, PolicyPack, SystemAction};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|text: &str| {
+    let Ok(pack) = PolicyPack::from_toml(text) else {
+        return;
+    };
+    let Ok(builder) = JudicialCore::builder().policy_pack(&pack) else {
+        return;
+    };
+    let core = builder.build();
+    core.rule(SystemAction::new("DATA_READ", "SELECT password FROM users", "standard"));
+    core.verify_ledger().expect("ledger chain broken");
+});
@@ -0,0 +1,43 @@
+//! Rule on generated actions and hold the core to a reference reading of
+//! the Master Pair: the compiled single-pass evaluation must reach the
+//! verdict the laws' own checks imply, and the ledger must stay intact.
+#![no_main]
+
+use judicial_core::{JudicialCore, MasterPair, Strictness, SystemAction};
+use libfuzzer_sys::fuzz_target;
+
+/// The verdict label the Master Pair's checks imply.
+fn expected(action: &SystemAction, strictness: Strictness) -> &'static str {
+    let law_1 = MasterPair.check_law_1(action);
+    let law_2 = MasterPair.check_law_2(action);
+    if law_1.is_some() {
+        return "REJECTED";
+    }
+    if law_2.is_some() {
+        return match strictness {
+            Strictness::Permissive => "APPROVED_WITH_WARNING",
+            _ => "REJECTED_WITH_SUGGESTION",
+        };
+    }
+    match (MasterPair.check_warnings(action), strictness) {
+        (None, _) => "APPROVED",
+        (Some(_), Strictness::Paranoid) => "REJECTED_WITH_SUGGESTION",
+        (Some(_), _) => "APPROVED",
+    }
+}
+
+fuzz_target!(|input: (Strictness, Vec<SystemAction>)| {
+    let (strictness, actions) = input;
+    let core = JudicialCore::with_strictness(strictness);
+
+    for action in &actions {
+        let verdict = core.rule_ref(action);
+        assert_eq!(verdict.label(), expected(action, strictness), "{:?} -> {:?}", action, verdict);
+    }
+
+    core.verify_ledger().expect("ledger chain broken");
+    assert_eq!(core.query_ledger(|_| true, 0, usize::MAX).len(), actions.len());
+    for entry in core.query_ledger(|_| true, 0, usize::MAX) {
+        assert!(entry.action.credentials.is_none(), "credentials reached the ledger");
+    }
+});
@@ -0,0 +1,78 @@
+/* C interface to judicial-core. Regenerate with:
+ *   cbindgen --config cbindgen.toml --output include/judicial_core.h
+ */
+
+#ifndef JUDICIAL_CORE_H
+#define JUDICIAL_CORE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define JC_APPROVED 0
+
+#define JC_APPROVED_WITH_WARNING 1
+
+#define JC_REJECTED 2
+
+#define JC_REJECTED_WITH_SUGGESTION 3
+
+#define JC_QUARANTINED 4
+
+#define JC_MALFORMED 5
+
+/**
+ * A null pointer or invalid UTF-8 argument.
+ */
+#define JC_INVALID_ARGUMENT -1
+
+typedef struct JudicialCore JudicialCore;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Create a core with the Master Pair laws. Free it with [`jc_core_free`].
+ */
+JudicialCore *jc_core_new(void);
+
+/**
+ * Shut the core down and free it.
+ */
+void jc_core_free(JudicialCore *core);
+
+/**
+ * Rule on an action and return one of the `JC_*` verdict codes.
+ *
+ * When `reason` is not null it receives the verdict as the ledger words
+ * it, for example `REJECTED: ...`, followed by `; suggestion: ...` when
+ * the rejection comes with one. It is null for invalid arguments.
+ */
+int jc_rule(const JudicialCore *core,
+            const char *action_type,
+            const char *payload,
+            const char *context,
+            char **reason);
+
+/**
+ * Approved fraction of all rulings, or a negative value for a null core.
+ */
+double jc_compliance_score(const JudicialCore *core);
+
+/**
+ * The ledger as a JSON array, or null for a null core.
+ */
+char *jc_export_ledger(const JudicialCore *core);
+
+/**
+ * Free a string returned by this library.
+ */
+void jc_string_free(char *s);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* JUDICIAL_CORE_H */
@@ -0,0 +1,54 @@
+/* Law plugin ABI for judicial-core (feature `plugins`).
+ * Mirrors the #[repr(C)] declarations in src/plugins.rs; keep the two in sync.
+ *
+ * A plugin is a shared library exporting both functions below. Callbacks
+ * receive NUL-terminated UTF-8, may be called from several threads at once
+ * and must not unwind.
+ */
+
+#ifndef JUDICIAL_PLUGIN_H
+#define JUDICIAL_PLUGIN_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#define JUDICIAL_PLUGIN_ABI_VERSION 1
+
+typedef struct JudicialPluginLaw {
+  uint32_t number;
+  const char *description;
+  bool absolute;
+  /* Nullable. */
+  const char *suggestion;
+  /* Nullable; a missing callback governs every action type. */
+  bool (*governs)(const char *action_type);
+  /* Null for a lawful action, otherwise the objection, released with the
+   * plugin's free_string. The action is passed as JSON. */
+  char *(*check)(const char *action_json);
+  /* Nullable. Same contract as check, for warnings. */
+  char *(*check_warning)(const char *action_json);
+} JudicialPluginLaw;
+
+typedef struct JudicialPlugin {
+  const char *name;
+  size_t law_count;
+  const JudicialPluginLaw *laws;
+  void (*free_string)(char *s);
+} JudicialPlugin;
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Must return JUDICIAL_PLUGIN_ABI_VERSION. Checked before anything else. */
+uint32_t judicial_plugin_abi_version(void);
+
+/* Valid for as long as the library is loaded. */
+const JudicialPlugin *judicial_plugin(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* JUDICIAL_PLUGIN_H */
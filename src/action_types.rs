@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Verdict for actions whose type no law governs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum UnknownActionPolicy {
+    /// Approve when no law objects. The historical behaviour.
+    #[default]
+    FailOpen,
+    /// Reject.
+    FailClosed,
+    /// Hold for review with [`Verdict::Quarantined`](crate::Verdict::Quarantined).
+    Quarantine,
+}
+
+/// Action types the deployment expects, and what to do with the rest.
+///
+/// An action type is unknown when a known set is declared and does not
+/// contain it, or when no active law governs it. Law violations always take
+/// precedence; the policy only replaces what would otherwise be an approval.
+///
+/// The stock laws govern only
+/// [`GOVERNED_ACTION_TYPES`](crate::laws::GOVERNED_ACTION_TYPES), so with
+/// them alone a non-default policy applies to every other type even when
+/// none are declared. Declaring types narrows that further; it cannot make
+/// a type known that no law governs.
+#[derive(Debug, Clone, Default)]
+pub struct ActionTypes {
+    known: BTreeSet<String>,
+    policy: UnknownActionPolicy,
+}
+
+impl ActionTypes {
+    pub fn declare(&mut self, action_type: impl Into<String>) {
+        self.known.insert(action_type.into());
+    }
+
+    pub fn set_policy(&mut self, policy: UnknownActionPolicy) {
+        self.policy = policy;
+    }
+
+    pub fn policy(&self) -> UnknownActionPolicy {
+        self.policy
+    }
+
+    /// Whether `action_type` is declared. Always true when none are declared.
+    pub fn is_declared(&self, action_type: &str) -> bool {
+        self.known.is_empty() || self.known.contains(action_type)
+    }
+
+    pub fn known(&self) -> impl Iterator<Item = &str> {
+        self.known.iter().map(String::as_str)
+    }
+}
@@ -0,0 +1,105 @@
+//! Approval requests over plain HTTP/JSON.
+//!
+//! `POST {base}/approvals` receives
+//!
+//! ```json
+//! {"review_id": 7, "action": {...}, "reason": "...", "ledger_hash": "...", "submitted_at": "..."}
+//! ```
+//!
+//! and answers `{"ticket": "..."}`. `GET {base}/approvals/{ticket}` answers
+//! `{"status": "pending"}`, or `{"status": "approved"}` / `{"status":
+//! "rejected"}` with `reviewer` and `rationale`.
+
+use super::{ApprovalError, ApprovalService, ApprovalStatus};
+use crate::review::{ReviewDecision, ReviewItem};
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct HttpApprovalService {
+    base: String,
+    token: Option<String>,
+    agent: ureq::Agent,
+}
+
+#[derive(Deserialize)]
+struct Ticket {
+    ticket: String,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum Status {
+    Pending,
+    Approved { reviewer: String, #[serde(default)] rationale: String },
+    Rejected { reviewer: String, #[serde(default)] rationale: String },
+}
+
+impl HttpApprovalService {
+    /// `base` is the service URL without a trailing slash.
+    pub fn new(base: impl Into<String>) -> Self {
+        Self {
+            base: base.into(),
+            token: None,
+            agent: ureq::Agent::config_builder()
+                .timeout_global(Some(Duration::from_secs(10)))
+                .build()
+                .into(),
+        }
+    }
+
+    /// Send `Authorization: Bearer <token>` with every request.
+    pub fn bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    fn authorization(&self) -> Option<String> {
+        self.token.as_ref().map(|token| format!("Bearer {}", token))
+    }
+}
+
+fn failed(error: impl std::fmt::Display) -> ApprovalError {
+    ApprovalError(error.to_string())
+}
+
+impl ApprovalService for HttpApprovalService {
+    fn request(&self, item: &ReviewItem) -> Result<String, ApprovalError> {
+        let body = json!({
+            "review_id": item.id,
+            "action": item.action,
+            "reason": item.reason,
+            "ledger_hash": item.ledger_hash,
+            "submitted_at": item.submitted_at,
+        });
+        let mut request = self.agent.post(format!("{}/approvals", self.base))
+            .header("Content-Type", "application/json");
+        if let Some(authorization) = self.authorization() {
+            request = request.header("Authorization", authorization);
+        }
+        let mut response = request.send(&body.to_string()).map_err(failed)?;
+        let body = response.body_mut().read_to_string().map_err(failed)?;
+        let ticket: Ticket = serde_json::from_str(&body).map_err(failed)?;
+        Ok(ticket.ticket)
+    }
+
+    fn status(&self, ticket: &str) -> Result<ApprovalStatus, ApprovalError> {
+        let mut request = self.agent.get(format!("{}/approvals/{}", self.base, ticket));
+        if let Some(authorization) = self.authorization() {
+            request = request.header("Authorization", authorization);
+        }
+        let mut response = request.call().map_err(failed)?;
+        let body = response.body_mut().read_to_string().map_err(failed)?;
+        let status: Status = serde_json::from_str(&body).map_err(failed)?;
+        Ok(match status {
+            Status::Pending => ApprovalStatus::Pending,
+            Status::Approved { reviewer, rationale } => {
+                ApprovalStatus::Decided { decision: ReviewDecision::Approve, reviewer, rationale }
+            }
+            Status::Rejected { reviewer, rationale } => {
+                ApprovalStatus::Decided { decision: ReviewDecision::Reject, reviewer, rationale }
+            }
+        })
+    }
+}
@@ -0,0 +1,95 @@
+//! Hand human review to an external approval system.
+//!
+//! With an [`ApprovalService`] installed, every quarantined action queued
+//! for review is also submitted to the service, which returns a ticket.
+//! Decisions come back either by polling
+//! ([`JudicialCore::poll_approvals`](crate::JudicialCore::poll_approvals))
+//! or by the service calling
+//! [`JudicialCore::receive_decision`](crate::JudicialCore::receive_decision).
+//! Either way they are ledgered like any other review decision.
+//!
+//! A reference HTTP client, `HttpApprovalService`, is behind the
+//! `http-approvals` feature.
+
+use crate::review::{ReviewDecision, ReviewId, ReviewItem};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+use tracing::{info, warn};
+
+#[cfg(feature = "http-approvals")]
+pub mod http;
+
+#[cfg(feature = "http-approvals")]
+pub use http::HttpApprovalService;
+
+/// Where an external approval stands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApprovalStatus {
+    Pending,
+    Decided {
+        decision: ReviewDecision,
+        reviewer: String,
+        rationale: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApprovalError(pub String);
+
+impl fmt::Display for ApprovalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "approval service failed: {}", self.0)
+    }
+}
+
+impl std::error::Error for ApprovalError {}
+
+/// An external ticketing or approval system.
+pub trait ApprovalService: fmt::Debug + Send + Sync {
+    /// Open an approval request for `item` and return its ticket.
+    fn request(&self, item: &ReviewItem) -> Result<String, ApprovalError>;
+
+    fn status(&self, ticket: &str) -> Result<ApprovalStatus, ApprovalError>;
+}
+
+/// Tickets for reviews handed to the service. Reviews whose request failed
+/// have no ticket yet and are requested again on the next poll.
+#[derive(Debug)]
+pub(crate) struct Approvals {
+    service: Box<dyn ApprovalService>,
+    tickets: Mutex<BTreeMap<ReviewId, Option<String>>>,
+}
+
+impl Approvals {
+    pub(crate) fn new(service: Box<dyn ApprovalService>) -> Self {
+        Self { service, tickets: Mutex::default() }
+    }
+
+    pub(crate) fn request(&self, item: &ReviewItem) {
+        let ticket = match self.service.request(item) {
+            Ok(ticket) => {
+                info!(review = item.id, %ticket, "approval requested");
+                Some(ticket)
+            }
+            Err(e) => {
+                warn!(review = item.id, error = %e, "approval request failed; will retry");
+                None
+            }
+        };
+        self.tickets.lock().unwrap().insert(item.id, ticket);
+    }
+
+    /// Reviews with a ticket or still awaiting one.
+    pub(crate) fn tracked(&self) -> Vec<(ReviewId, Option<String>)> {
+        self.tickets.lock().unwrap().iter().map(|(id, ticket)| (*id, ticket.clone())).collect()
+    }
+
+    pub(crate) fn status(&self, ticket: &str) -> Result<ApprovalStatus, ApprovalError> {
+        self.service.status(ticket)
+    }
+
+    pub(crate) fn forget(&self, id: ReviewId) {
+        self.tickets.lock().unwrap().remove(&id);
+    }
+}
@@ -0,0 +1,54 @@
+//! Serves one judicial core over gRPC.
+//!
+//! Usage: `judicial-grpcd [ADDR] [--policy-pack PATH]`, ADDR defaults to
+//! `127.0.0.1:50051`.
+
+use judicial_core::grpc::GrpcCourt;
+use judicial_core::JudicialCore;
+use std::env;
+use std::process;
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt::init();
+
+    let mut addr = "127.0.0.1:50051".to_string();
+    let mut builder = JudicialCore::builder();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--policy-pack" => {
+                let Some(path) = args.next() else {
+                    fail("--policy-pack needs a path");
+                };
+                builder = builder.load_policy_pack(&path)
+                    .unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+            }
+            _ => addr = arg,
+        }
+    }
+
+    let addr = addr.parse().unwrap_or_else(|e| fail(&format!("invalid address {}: {}", addr, e)));
+    let court = GrpcCourt::new(builder);
+    let core = court.core().clone();
+
+    tracing::info!(%addr, "judicial-grpcd listening");
+    let served = tonic::transport::Server::builder()
+        .add_service(court.into_server())
+        .serve_with_shutdown(addr, async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await;
+
+    if let Err(e) = core.shutdown() {
+        tracing::error!(error = %e, "ledger did not close cleanly");
+    }
+    if let Err(e) = served {
+        fail(&e.to_string());
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("judicial-grpcd: {}", message);
+    process::exit(1);
+}
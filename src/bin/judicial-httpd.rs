@@ -0,0 +1,80 @@
+//! Serves one judicial core over HTTP/JSON.
+//!
+//! Usage: `judicial-httpd [ADDR] [--policy-pack PATH] [--oidc-issuer URL
+//! --oidc-audience AUDIENCE]`, ADDR defaults to `127.0.0.1:8080`.
+//!
+//! Reviewers claim and resolve reviews with bearer tokens, verified by the
+//! OpenID Connect issuer given with `--oidc-issuer` (needs the `oidc`
+//! feature). Without one, those endpoints answer 401 and reviews must be
+//! settled another way.
+
+use judicial_core::{JudicialCore, JudicialCoreBuilder};
+use std::env;
+use std::process;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt::init();
+
+    let mut addr = "127.0.0.1:8080".to_string();
+    let mut builder = JudicialCore::builder();
+    let mut issuer = None;
+    let mut audience = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--policy-pack" => {
+                let Some(path) = args.next() else {
+                    fail("--policy-pack needs a path");
+                };
+                builder = builder.load_policy_pack(&path)
+                    .unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+            }
+            "--oidc-issuer" => issuer = Some(args.next().unwrap_or_else(|| fail("--oidc-issuer needs a URL"))),
+            "--oidc-audience" => {
+                audience = Some(args.next().unwrap_or_else(|| fail("--oidc-audience needs a value")))
+            }
+            _ => addr = arg,
+        }
+    }
+
+    match (issuer, audience) {
+        (Some(issuer), Some(audience)) => builder = with_oidc(builder, issuer, audience),
+        (None, None) => tracing::warn!("no --oidc-issuer; review claim and resolve endpoints will answer 401"),
+        _ => fail("--oidc-issuer and --oidc-audience go together"),
+    }
+
+    let addr = addr.parse().unwrap_or_else(|e| fail(&format!("invalid address {}: {}", addr, e)));
+    let core = Arc::new(builder.build());
+
+    tracing::info!(%addr, "judicial-httpd listening");
+    let served = judicial_core::http::serve(Arc::clone(&core), addr, async {
+        let _ = tokio::signal::ctrl_c().await;
+    })
+    .await;
+
+    if let Err(e) = core.shutdown() {
+        tracing::error!(error = %e, "ledger did not close cleanly");
+    }
+    if let Err(e) = served {
+        fail(&e.to_string());
+    }
+}
+
+#[cfg(feature = "oidc")]
+fn with_oidc(builder: JudicialCoreBuilder, issuer: String, audience: String) -> JudicialCoreBuilder {
+    let provider = judicial_core::identity::OidcProvider::discover(&issuer, audience)
+        .unwrap_or_else(|e| fail(&format!("{}: {}", issuer, e)));
+    builder.identity_provider(provider)
+}
+
+#[cfg(not(feature = "oidc"))]
+fn with_oidc(_builder: JudicialCoreBuilder, _issuer: String, _audience: String) -> JudicialCoreBuilder {
+    fail("--oidc-issuer needs judicial-httpd built with the oidc feature")
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("judicial-httpd: {}", message);
+    process::exit(1);
+}
@@ -0,0 +1,57 @@
+//! MQTT bridge to a judicial core.
+//!
+//! Usage: `judicial-mqtt [--policy-pack PATH] [--request-topic T] [--response-topic T] [HOST:PORT]`
+//! (default `127.0.0.1:1883`).
+
+use judicial_core::mqtt::{MqttBridge, MqttConfig};
+use judicial_core::JudicialCore;
+use std::env;
+use std::process;
+use std::sync::Arc;
+
+fn main() {
+    tracing_subscriber::fmt::init();
+
+    let mut addr = "127.0.0.1:1883".to_string();
+    let mut request_topic = None;
+    let mut response_topic = None;
+    let mut builder = JudicialCore::builder();
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--policy-pack" => {
+                let Some(path) = args.next() else {
+                    fail("--policy-pack needs a path");
+                };
+                builder = builder.load_policy_pack(&path)
+                    .unwrap_or_else(|e| fail(&format!("{}: {}", path, e)));
+            }
+            "--request-topic" => {
+                request_topic = Some(args.next().unwrap_or_else(|| fail("--request-topic needs a topic")));
+            }
+            "--response-topic" => {
+                response_topic = Some(args.next().unwrap_or_else(|| fail("--response-topic needs a topic")));
+            }
+            _ => addr = arg,
+        }
+    }
+
+    let Some((host, port)) = addr.rsplit_once(':') else {
+        fail(&format!("invalid address {}", addr));
+    };
+    let port = port.parse().unwrap_or_else(|e| fail(&format!("invalid port {}: {}", port, e)));
+    let mut config = MqttConfig::new(host, port);
+    if let Some(topic) = request_topic {
+        config.request_topic = topic;
+    }
+    if let Some(topic) = response_topic {
+        config.response_topic = topic;
+    }
+
+    MqttBridge::new(Arc::new(builder.build()), config).run();
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("judicial-mqtt: {}", message);
+    process::exit(1);
+}
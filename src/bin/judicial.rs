@@ -0,0 +1,151 @@
+//! Operator tool for rulings, exported ledgers and policy packs.
+//!
+//! Ledger files are the JSON arrays written by `JudicialCore::export_ledger`.
+
+use clap::{Parser, Subcommand};
+use judicial_core::ledger::{verify_entries, LedgerEntry};
+use judicial_core::{ComplianceReport, ComplianceWindow, JudicialCore, JudicialCoreBuilder, SystemAction};
+use std::fs;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process;
+
+#[derive(Debug, Parser)]
+#[command(name = "judicial", about = "Interrogate ledgers and test policy packs")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Rule on one action and print the verdict as JSON.
+    Rule {
+        action_type: String,
+        payload: String,
+        #[arg(default_value = "")]
+        context: String,
+        #[arg(long)]
+        actor: Option<String>,
+        #[arg(long)]
+        policy_pack: Option<PathBuf>,
+    },
+    /// Work with an exported ledger.
+    Ledger {
+        #[command(subcommand)]
+        command: LedgerCommand,
+    },
+    /// Rule on every action in a JSON Lines file and print one verdict per line.
+    Simulate {
+        actions: PathBuf,
+        #[arg(long)]
+        policy_pack: Option<PathBuf>,
+        /// Write the resulting ledger here.
+        #[arg(long)]
+        export_ledger: Option<PathBuf>,
+    },
+    /// Print the compliance report for an exported ledger.
+    Report {
+        ledger: PathBuf,
+        /// Only the most recent N rulings.
+        #[arg(long)]
+        last: Option<usize>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum LedgerCommand {
+    /// Check the hash chain.
+    Verify { ledger: PathBuf },
+    /// Print matching entries as JSON.
+    Query {
+        ledger: PathBuf,
+        #[arg(long)]
+        action_type: Option<String>,
+        #[arg(long)]
+        actor: Option<String>,
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
+        #[arg(long)]
+        limit: Option<usize>,
+    },
+}
+
+fn main() {
+    if let Err(message) = run(Cli::parse().command) {
+        eprintln!("judicial: {}", message);
+        process::exit(1);
+    }
+}
+
+fn run(command: Command) -> Result<(), String> {
+    match command {
+        Command::Rule { action_type, payload, context, actor, policy_pack } => {
+            let core = build(policy_pack.as_deref())?;
+            let mut action = SystemAction::new(action_type, payload, context);
+            action.actor = actor;
+            print_json(&core.rule(action));
+        }
+        Command::Ledger { command: LedgerCommand::Verify { ledger } } => {
+            let entries = read_ledger(&ledger)?;
+            verify_entries(&entries).map_err(|e| format!("{}: {}", ledger.display(), e))?;
+            println!("{}: {} entries, chain intact", ledger.display(), entries.len());
+        }
+        Command::Ledger { command: LedgerCommand::Query { ledger, action_type, actor, offset, limit } } => {
+            let entries: Vec<LedgerEntry> = read_ledger(&ledger)?.into_iter()
+                .filter(|e| action_type.as_ref().is_none_or(|t| *t == e.action.action_type))
+                .filter(|e| actor.is_none() || actor == e.action.actor)
+                .skip(offset)
+                .take(limit.unwrap_or(usize::MAX))
+                .collect();
+            print_json(&entries);
+        }
+        Command::Simulate { actions, policy_pack, export_ledger } => {
+            let core = build(policy_pack.as_deref())?;
+            let file = fs::File::open(&actions).map_err(|e| format!("{}: {}", actions.display(), e))?;
+            for (number, line) in BufReader::new(file).lines().enumerate() {
+                let line = line.map_err(|e| e.to_string())?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let action: SystemAction = serde_json::from_str(&line)
+                    .map_err(|e| format!("{}:{}: {}", actions.display(), number + 1, e))?;
+                print_json(&core.rule(action));
+            }
+            eprintln!("compliance score: {:.3}", core.get_compliance_score());
+            if let Some(path) = export_ledger {
+                fs::write(&path, core.export_ledger()).map_err(|e| format!("{}: {}", path.display(), e))?;
+            }
+        }
+        Command::Report { ledger, last } => {
+            let entries = read_ledger(&ledger)?;
+            let window = last.map_or(ComplianceWindow::Lifetime, ComplianceWindow::LastRulings);
+            let now = entries.last().map_or_else(chrono::Utc::now, |e| e.timestamp);
+            print_json(&ComplianceReport::from_entries(&entries, window, now));
+        }
+    }
+    Ok(())
+}
+
+fn build(policy_pack: Option<&Path>) -> Result<JudicialCore, String> {
+    let builder = JudicialCoreBuilder::default();
+    let builder = match policy_pack {
+        Some(path) => builder.load_policy_pack(path).map_err(|e| format!("{}: {}", path.display(), e))?,
+        None => builder,
+    };
+    Ok(builder.build())
+}
+
+fn read_ledger(path: &Path) -> Result<Vec<LedgerEntry>, String> {
+    let text = if path == Path::new("-") {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(path)
+    };
+    let text = text.map_err(|e| format!("{}: {}", path.display(), e))?;
+    serde_json::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn print_json(value: &impl serde::Serialize) {
+    println!("{}", serde_json::to_string(value).unwrap());
+}
@@ -0,0 +1,102 @@
+use crate::ledger::LedgerEntry;
+use crate::rate_limit::ANONYMOUS_ACTOR;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Which ledger entries a compliance score covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComplianceWindow {
+    /// The whole ledger, like `get_compliance_score()`.
+    #[default]
+    Lifetime,
+    /// The most recent N rulings.
+    LastRulings(usize),
+    /// Rulings recorded within this duration of now.
+    Last(Duration),
+}
+
+impl ComplianceWindow {
+    /// The rulings among `entries` that fall within this window.
+    pub(crate) fn rulings(self, entries: &[LedgerEntry], now: DateTime<Utc>) -> Vec<&LedgerEntry> {
+        let rulings: Vec<&LedgerEntry> = entries.iter().filter(|e| e.is_ruling()).collect();
+        let start = match self {
+            ComplianceWindow::Lifetime => 0,
+            ComplianceWindow::LastRulings(n) => rulings.len().saturating_sub(n),
+            ComplianceWindow::Last(duration) => {
+                let since = now - duration;
+                rulings.partition_point(|e| e.timestamp < since)
+            }
+        };
+        rulings[start..].to_vec()
+    }
+}
+
+/// Approved fraction and ruling count for one segment.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize)]
+pub struct SegmentScore {
+    pub score: f64,
+    pub rulings: usize,
+    pub rejections: usize,
+}
+
+impl SegmentScore {
+    fn add(&mut self, approved: bool) {
+        self.rulings += 1;
+        if !approved {
+            self.rejections += 1;
+        }
+        self.score = (self.rulings - self.rejections) as f64 / self.rulings as f64;
+    }
+}
+
+/// Compliance scores over a [`ComplianceWindow`].
+///
+/// Scores are the approved fraction of rulings and are 1.0 for empty
+/// segments. `by_law` counts, for each law, the rejected rulings that name it,
+/// as the deciding law or among the violations, so its score is the
+/// fraction of the window that law let through.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ComplianceReport {
+    pub overall: SegmentScore,
+    pub by_actor: BTreeMap<String, SegmentScore>,
+    pub by_action_type: BTreeMap<String, SegmentScore>,
+    pub by_law: BTreeMap<u32, SegmentScore>,
+}
+
+impl ComplianceReport {
+    pub fn from_entries(entries: &[LedgerEntry], window: ComplianceWindow, now: DateTime<Utc>) -> Self {
+        let in_window = ComplianceWindow::rulings(window, entries, now);
+
+        let mut report = Self {
+            overall: SegmentScore { score: 1.0, ..SegmentScore::default() },
+            ..Self::default()
+        };
+
+        for entry in &in_window {
+            let approved = entry.verdict.starts_with("APPROVED");
+            let actor = entry.action.actor.as_deref().unwrap_or(ANONYMOUS_ACTOR);
+
+            report.overall.add(approved);
+            report.by_actor.entry(actor.to_string()).or_default().add(approved);
+            report.by_action_type.entry(entry.action.action_type.clone()).or_default().add(approved);
+        }
+
+        let laws: Vec<u32> = in_window.iter().flat_map(|e| cited_laws(e)).collect();
+        for law in laws {
+            report.by_law.entry(law).or_default();
+        }
+        for (law, score) in report.by_law.iter_mut() {
+            for entry in &in_window {
+                score.add(entry.verdict.starts_with("APPROVED") || !cited_laws(entry).any(|cited| cited == *law));
+            }
+        }
+
+        report
+    }
+}
+
+/// The deciding law of an entry and every law among its violations.
+fn cited_laws(entry: &LedgerEntry) -> impl Iterator<Item = u32> + '_ {
+    entry.law.into_iter().chain(entry.violations.iter().map(|v| v.law_number))
+}
@@ -0,0 +1,132 @@
+use crate::judicial_core::JudicialCore;
+use crate::verdicts::{SystemAction, Verdict};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::info;
+
+/// Which trial verdicts are forwarded to the parent court.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EscalationPolicy {
+    /// Rejections with no suggested remedy (absolute-law violations).
+    pub rejections: bool,
+    pub rejections_with_suggestion: bool,
+    pub warnings: bool,
+    pub quarantines: bool,
+}
+
+impl EscalationPolicy {
+    fn forwards(&self, verdict: &Verdict) -> bool {
+        match verdict {
+            Verdict::Approved => false,
+            Verdict::ApprovedWithWarning(_) => self.warnings,
+            Verdict::Rejected(_) => self.rejections,
+            Verdict::RejectedWithSuggestion(_, _) => self.rejections_with_suggestion,
+            Verdict::Quarantined(_) => self.quarantines,
+            Verdict::Malformed(_) => false,
+        }
+    }
+}
+
+impl Default for EscalationPolicy {
+    fn default() -> Self {
+        Self {
+            rejections: true,
+            rejections_with_suggestion: false,
+            warnings: false,
+            quarantines: true,
+        }
+    }
+}
+
+/// A verdict together with the courts that produced it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CourtRuling {
+    pub verdict: Verdict,
+    /// Court whose verdict is final.
+    pub decided_by: String,
+    /// Every court that heard the action, in order, with its verdict.
+    pub history: Vec<(String, Verdict)>,
+}
+
+/// A named [`JudicialCore`] that can forward verdicts to a parent court.
+///
+/// The trial court rules first. Verdicts matching its [`EscalationPolicy`],
+/// and explicit appeals, are re-heard by the parent, whose verdict is final.
+/// Each court records the action in its own ledger.
+#[derive(Debug)]
+pub struct Court {
+    name: String,
+    core: JudicialCore,
+    parent: Option<Arc<Court>>,
+    escalation: EscalationPolicy,
+}
+
+impl Court {
+    pub fn new(name: impl Into<String>, core: JudicialCore) -> Self {
+        Self {
+            name: name.into(),
+            core,
+            parent: None,
+            escalation: EscalationPolicy::default(),
+        }
+    }
+
+    pub fn with_parent(mut self, parent: Arc<Court>) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    pub fn with_escalation(mut self, escalation: EscalationPolicy) -> Self {
+        self.escalation = escalation;
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn core(&self) -> &JudicialCore {
+        &self.core
+    }
+
+    pub fn parent(&self) -> Option<&Arc<Court>> {
+        self.parent.as_ref()
+    }
+
+    /// Quarantines this court escalates are queued for review by the court
+    /// that decides them, not here as well.
+    pub fn rule(&self, action: SystemAction) -> CourtRuling {
+        let verdict = if self.parent.is_some() && self.escalation.quarantines {
+            self.core.rule_for_escalation(&action)
+        } else {
+            self.core.rule_ref(&action)
+        };
+
+        match &self.parent {
+            Some(parent) if self.escalation.forwards(&verdict) => {
+                info!(court = %self.name, parent = %parent.name, verdict = verdict.label(), "escalating to parent court");
+                self.forward(parent, action, verdict)
+            }
+            _ => CourtRuling {
+                verdict: verdict.clone(),
+                decided_by: self.name.clone(),
+                history: vec![(self.name.clone(), verdict)],
+            },
+        }
+    }
+
+    /// Ask the parent court to re-hear an action regardless of this court's
+    /// `verdict` on it, which heads the returned history. Returns `None`
+    /// from the top of the hierarchy.
+    pub fn appeal(&self, action: SystemAction, verdict: Verdict) -> Option<CourtRuling> {
+        let parent = self.parent.as_ref()?;
+        info!(court = %self.name, parent = %parent.name, verdict = verdict.label(), "appeal filed");
+        Some(self.forward(parent, action, verdict))
+    }
+
+    fn forward(&self, parent: &Court, action: SystemAction, verdict: Verdict) -> CourtRuling {
+        let mut ruling = parent.rule(action);
+        ruling.history.insert(0, (self.name.clone(), verdict));
+        ruling
+    }
+}
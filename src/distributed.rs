@@ -0,0 +1,409 @@
+use crate::judicial_core::JudicialCore;
+use crate::verdicts::{SystemAction, Verdict};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tracing::{info, warn};
+
+/// Sent by a [`DistributedCourt`] to each peer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjudicationRequest {
+    pub request_id: u64,
+    pub action: SystemAction,
+}
+
+/// A peer's ruling on an [`AdjudicationRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdjudicationResponse {
+    pub request_id: u64,
+    pub court: String,
+    pub verdict: Verdict,
+}
+
+#[derive(Debug)]
+pub enum PeerError {
+    Timeout,
+    Transport(String),
+    /// The response did not answer the request that was sent.
+    Mismatch { expected: u64, received: u64 },
+}
+
+impl fmt::Display for PeerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeerError::Timeout => write!(f, "peer timed out"),
+            PeerError::Transport(msg) => write!(f, "transport error: {}", msg),
+            PeerError::Mismatch { expected, received } => {
+                write!(f, "response for request {} while awaiting {}", received, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PeerError {}
+
+impl From<io::Error> for PeerError {
+    fn from(err: io::Error) -> Self {
+        match err.kind() {
+            io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => PeerError::Timeout,
+            _ => PeerError::Transport(err.to_string()),
+        }
+    }
+}
+
+/// A remote judicial core reachable over some transport.
+pub trait CourtPeer: fmt::Debug + Send + Sync {
+    fn name(&self) -> &str;
+
+    fn adjudicate(&self, request: &AdjudicationRequest, timeout: Duration) -> Result<AdjudicationResponse, PeerError>;
+}
+
+/// Peer speaking newline-delimited JSON over TCP. See [`serve`].
+#[derive(Debug, Clone)]
+pub struct TcpPeer {
+    name: String,
+    addr: SocketAddr,
+}
+
+impl TcpPeer {
+    pub fn new(name: impl Into<String>, addr: SocketAddr) -> Self {
+        Self { name: name.into(), addr }
+    }
+}
+
+impl CourtPeer for TcpPeer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn adjudicate(&self, request: &AdjudicationRequest, timeout: Duration) -> Result<AdjudicationResponse, PeerError> {
+        let mut stream = TcpStream::connect_timeout(&self.addr, timeout)?;
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+
+        let mut line = serde_json::to_string(request)
+            .map_err(|e| PeerError::Transport(e.to_string()))?;
+        line.push('\n');
+        stream.write_all(line.as_bytes())?;
+
+        let mut reply = String::new();
+        BufReader::new(stream).read_line(&mut reply)?;
+        let response: AdjudicationResponse = serde_json::from_str(&reply)
+            .map_err(|e| PeerError::Transport(e.to_string()))?;
+
+        if response.request_id != request.request_id {
+            return Err(PeerError::Mismatch { expected: request.request_id, received: response.request_id });
+        }
+        Ok(response)
+    }
+}
+
+/// Rule on a request with a local core. The server side of the protocol.
+pub fn handle_request(core: &JudicialCore, court: &str, request: AdjudicationRequest) -> AdjudicationResponse {
+    AdjudicationResponse {
+        request_id: request.request_id,
+        court: court.to_string(),
+        verdict: core.rule(request.action),
+    }
+}
+
+/// Limits for [`serve_with`].
+#[derive(Debug, Clone)]
+pub struct ServeConfig {
+    /// Addresses allowed to request rulings. Connections from anywhere
+    /// else are closed unanswered. Loopback only by default.
+    pub allowed_peers: Vec<IpAddr>,
+    /// Connections served at once; further ones are closed unanswered.
+    pub max_connections: usize,
+    /// Longest request line accepted, newline included.
+    pub max_request_bytes: usize,
+    /// How long a connection may sit without sending a request.
+    pub read_timeout: Duration,
+}
+
+impl Default for ServeConfig {
+    fn default() -> Self {
+        Self {
+            allowed_peers: vec![IpAddr::V4(Ipv4Addr::LOCALHOST), IpAddr::V6(Ipv6Addr::LOCALHOST)],
+            max_connections: 64,
+            max_request_bytes: 64 * 1024,
+            read_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl ServeConfig {
+    pub fn allow_peer(mut self, addr: IpAddr) -> Self {
+        self.allowed_peers.push(addr);
+        self
+    }
+
+    pub fn max_connections(mut self, max: usize) -> Self {
+        self.max_connections = max.max(1);
+        self
+    }
+
+    pub fn max_request_bytes(mut self, max: usize) -> Self {
+        self.max_request_bytes = max;
+        self
+    }
+
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = timeout;
+        self
+    }
+}
+
+/// [`serve_with`] the default [`ServeConfig`]: loopback peers only.
+pub fn serve(core: Arc<JudicialCore>, court: &str, listener: TcpListener) -> io::Result<()> {
+    serve_with(core, court, listener, ServeConfig::default())
+}
+
+/// Accept [`TcpPeer`] connections forever, one thread per connection, from
+/// the allowed peers and up to the connection limit.
+pub fn serve_with(core: Arc<JudicialCore>, court: &str, listener: TcpListener, config: ServeConfig) -> io::Result<()> {
+    let config = Arc::new(config);
+    let active = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let peer = stream.peer_addr()?;
+        if !config.allowed_peers.contains(&peer.ip()) {
+            warn!(%peer, "adjudication request from a peer not allowed");
+            continue;
+        }
+        let Some(slot) = ConnectionSlot::take(&active, config.max_connections) else {
+            warn!(%peer, limit = config.max_connections, "adjudication connection refused, limit reached");
+            continue;
+        };
+
+        let core = Arc::clone(&core);
+        let court = court.to_string();
+        let config = Arc::clone(&config);
+        thread::spawn(move || {
+            let _slot = slot;
+            if let Err(err) = serve_connection(&core, &court, stream, &config) {
+                warn!(%peer, %err, "adjudication connection failed");
+            }
+        });
+    }
+    Ok(())
+}
+
+/// One of the connections [`serve_with`] allows at once, until dropped.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn take(active: &Arc<AtomicUsize>, max: usize) -> Option<Self> {
+        active.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| (n < max).then_some(n + 1)).ok()?;
+        Some(Self(Arc::clone(active)))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn serve_connection(core: &JudicialCore, court: &str, stream: TcpStream, config: &ServeConfig) -> io::Result<()> {
+    stream.set_read_timeout(Some(config.read_timeout))?;
+    stream.set_write_timeout(Some(config.read_timeout))?;
+    let mut writer = stream.try_clone()?;
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    loop {
+        line.clear();
+        let limit = config.max_request_bytes as u64;
+        if reader.by_ref().take(limit).read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        if !line.ends_with('\n') && line.len() as u64 >= limit {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "request line too long"));
+        }
+
+        let request: AdjudicationRequest = serde_json::from_str(&line)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let mut reply = serde_json::to_string(&handle_request(core, court, request))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        reply.push('\n');
+        writer.write_all(reply.as_bytes())?;
+    }
+}
+
+/// What to do when unreachable peers leave the quorum undecided and no
+/// reachable court rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FallbackPolicy {
+    /// Fail closed.
+    #[default]
+    Reject,
+    /// Use the local core's verdict alone. It is only consulted when every
+    /// court that answered approved, so it never overrides a rejection.
+    LocalVerdict,
+}
+
+/// Result of a quorum ruling.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuorumVerdict {
+    pub verdict: Verdict,
+    pub approvals: usize,
+    pub rejections: usize,
+    /// Peers that timed out or failed.
+    pub unreachable: Vec<String>,
+    /// Whether the [`FallbackPolicy`] decided the outcome.
+    pub fallback: bool,
+    /// Every verdict received, the local court's first, each under the
+    /// name its peer was configured with.
+    pub responses: Vec<(String, Verdict)>,
+}
+
+/// M-of-N agreement between a local core and remote peers.
+///
+/// The local core counts as one of the N voters. An action is approved once
+/// `quorum` courts approve it and rejected once that has become impossible.
+/// If unreachable peers leave the outcome open, a rejection from any court
+/// that answered decides it; only when none rejected does the
+/// [`FallbackPolicy`] decide.
+#[derive(Debug)]
+pub struct DistributedCourt {
+    name: String,
+    local: JudicialCore,
+    peers: Vec<Box<dyn CourtPeer>>,
+    /// As requested; `None` means every court.
+    quorum: Option<usize>,
+    timeout: Duration,
+    fallback: FallbackPolicy,
+    next_request: AtomicU64,
+}
+
+impl DistributedCourt {
+    /// Require every court to agree until [`with_quorum`](Self::with_quorum) says otherwise.
+    pub fn new(name: impl Into<String>, local: JudicialCore) -> Self {
+        Self {
+            name: name.into(),
+            local,
+            peers: Vec::new(),
+            quorum: None,
+            timeout: Duration::from_secs(2),
+            fallback: FallbackPolicy::default(),
+            next_request: AtomicU64::new(1),
+        }
+    }
+
+    pub fn peer(mut self, peer: impl CourtPeer + 'static) -> Self {
+        self.peers.push(Box::new(peer));
+        self
+    }
+
+    /// Approvals required. Clamped to `1..=N` when ruling, so it may be
+    /// set before or after the peers are added.
+    pub fn with_quorum(mut self, quorum: usize) -> Self {
+        self.quorum = Some(quorum);
+        self
+    }
+
+    /// Approvals a ruling currently requires.
+    pub fn quorum(&self) -> usize {
+        let courts = self.peers.len() + 1;
+        self.quorum.map_or(courts, |quorum| quorum.clamp(1, courts))
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_fallback(mut self, fallback: FallbackPolicy) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    pub fn local(&self) -> &JudicialCore {
+        &self.local
+    }
+
+    pub fn rule(&self, action: SystemAction) -> QuorumVerdict {
+        let request = AdjudicationRequest {
+            request_id: self.next_request.fetch_add(1, Ordering::Relaxed),
+            action,
+        };
+
+        // Peers are asked on their own threads while the local core rules
+        // on this one.
+        let (local_verdict, remote) = thread::scope(|scope| {
+            let handles: Vec<_> = self.peers.iter()
+                .map(|peer| {
+                    let request = &request;
+                    let handle = scope.spawn(move || peer.adjudicate(request, self.timeout));
+                    (peer.name().to_string(), handle)
+                })
+                .collect();
+
+            let local_verdict = self.local.rule_ref(&request.action);
+            let remote: Vec<(String, Result<AdjudicationResponse, PeerError>)> = handles.into_iter()
+                .map(|(name, handle)| {
+                    let result = handle.join()
+                        .unwrap_or_else(|_| Err(PeerError::Transport("peer panicked".into())));
+                    (name, result)
+                })
+                .collect();
+            (local_verdict, remote)
+        });
+
+        let mut responses = vec![(self.name.clone(), local_verdict.clone())];
+        let mut unreachable = Vec::new();
+        for (name, result) in remote {
+            match result {
+                // Recorded under the name the peer was configured with; the
+                // court name it reports is its own claim.
+                Ok(response) => {
+                    if response.court != name {
+                        warn!(peer = %name, reported = %response.court, "peer reported another court name");
+                    }
+                    responses.push((name, response.verdict));
+                }
+                Err(err) => {
+                    warn!(peer = %name, %err, "peer did not rule");
+                    unreachable.push(name);
+                }
+            }
+        }
+
+        let quorum = self.quorum();
+        let approvals = responses.iter().filter(|(_, v)| v.is_approved()).count();
+        let rejections = responses.len() - approvals;
+
+        let (verdict, fallback) = if approvals >= quorum {
+            (Verdict::Approved, false)
+        } else if rejections == 0 && approvals + unreachable.len() >= quorum {
+            let verdict = match self.fallback {
+                FallbackPolicy::Reject => Verdict::Rejected(format!(
+                    "Quorum not reached: {} of {} approvals, {} peers unreachable",
+                    approvals, quorum, unreachable.len()
+                )),
+                FallbackPolicy::LocalVerdict => local_verdict,
+            };
+            (verdict, true)
+        } else {
+            let verdict = responses.iter()
+                .find(|(_, v)| !v.is_approved())
+                .map(|(_, v)| v.clone())
+                .unwrap_or_else(|| Verdict::Rejected("Quorum not reached".into()));
+            (verdict, false)
+        };
+
+        info!(
+            request_id = request.request_id,
+            approvals, rejections, unreachable = unreachable.len(), fallback,
+            verdict = verdict.label(),
+            "quorum ruling"
+        );
+
+        QuorumVerdict { verdict, approvals, rejections, unreachable, fallback, responses }
+    }
+}
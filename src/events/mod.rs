@@ -0,0 +1,86 @@
+//! Ledger event streaming.
+//!
+//! Wrap any backend in a [`PublishingBackend`] and every stored entry is
+//! also handed to an [`EventSink`]. Sinks for external systems are behind
+//! features: `nats` for `NatsSink` and `syslog` for `SyslogSink`. The
+//! ledger itself stays the source of truth: delivery is at most once, and
+//! sinks drop what they cannot deliver.
+
+use crate::ledger::{LedgerBackend, LedgerEntry};
+use std::fmt;
+use std::io;
+
+#[cfg(feature = "nats")]
+pub mod nats;
+#[cfg(feature = "syslog")]
+pub mod syslog;
+
+#[cfg(feature = "nats")]
+pub use nats::NatsSink;
+#[cfg(feature = "syslog")]
+pub use syslog::SyslogSink;
+
+/// Destination for published ledger entries.
+pub trait EventSink: fmt::Debug + Send + Sync {
+    fn publish(&self, entry: &LedgerEntry);
+}
+
+/// A [`LedgerBackend`] that publishes each entry once the inner backend
+/// has stored it: as it is appended or, while writes are deferred (as
+/// under [`WriteMode::GroupCommit`](crate::WriteMode::GroupCommit)), after
+/// the flush that persists it. Entries a failed flush left unpersisted go
+/// out with the next successful one. Entries already in the inner backend
+/// when it is wrapped are not published.
+#[derive(Debug)]
+pub struct PublishingBackend<B> {
+    inner: B,
+    sink: Box<dyn EventSink>,
+    deferred: bool,
+    /// Entries of `inner` handed to the sink so far.
+    published: usize,
+}
+
+impl<B: LedgerBackend> PublishingBackend<B> {
+    pub fn new(inner: B, sink: impl EventSink + 'static) -> Self {
+        let published = inner.entries().len();
+        Self { inner, sink: Box::new(sink), deferred: false, published }
+    }
+
+    fn publish_stored(&mut self) {
+        let entries = self.inner.entries();
+        for entry in &entries[self.published.min(entries.len())..] {
+            self.sink.publish(entry);
+        }
+        self.published = entries.len();
+    }
+}
+
+impl<B: LedgerBackend> LedgerBackend for PublishingBackend<B> {
+    fn append(&mut self, entry: LedgerEntry) {
+        self.inner.append(entry);
+        if !self.deferred {
+            self.publish_stored();
+        }
+    }
+
+    fn entries(&self) -> &[LedgerEntry] {
+        self.inner.entries()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()?;
+        self.publish_stored();
+        Ok(())
+    }
+
+    fn defer_writes(&mut self, defer: bool) {
+        self.deferred = defer;
+        self.inner.defer_writes(defer)
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        self.inner.close()?;
+        self.publish_stored();
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+//! NATS publishing of ledger entries.
+//!
+//! [`NatsSink`] publishes each entry to a subject. The message body, schema
+//! `judicial.ledger.v1`, is
+//!
+//! ```json
+//! {"schema": "judicial.ledger.v1", "entry": <LedgerEntry as in export_ledger>}
+//! ```
+//!
+//! Publishing runs on a background thread; entries that cannot be delivered
+//! after a reconnect attempt are logged and dropped.
+
+use super::EventSink;
+use crate::ledger::LedgerEntry;
+use serde::Serialize;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use tracing::warn;
+
+pub const SCHEMA: &str = "judicial.ledger.v1";
+
+#[derive(Serialize)]
+struct Envelope<'a> {
+    schema: &'static str,
+    entry: &'a LedgerEntry,
+}
+
+/// Publishes entries to a NATS subject using the core NATS text protocol.
+#[derive(Debug)]
+pub struct NatsSink {
+    sender: Option<Sender<String>>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl NatsSink {
+    /// Connect to `addr` (`host:port`). Fails if the first connection fails.
+    pub fn connect(addr: impl Into<String>, subject: impl Into<String>) -> io::Result<Self> {
+        let addr = addr.into();
+        let subject = subject.into();
+        let connection = NatsConnection::open(&addr)?;
+
+        let (sender, receiver) = mpsc::channel();
+        let worker = thread::Builder::new()
+            .name("judicial-nats".into())
+            .spawn(move || publish(addr, subject, connection, receiver))?;
+        Ok(Self { sender: Some(sender), worker: Some(worker) })
+    }
+}
+
+impl EventSink for NatsSink {
+    fn publish(&self, entry: &LedgerEntry) {
+        let body = serde_json::to_string(&Envelope { schema: SCHEMA, entry }).unwrap();
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(body);
+        }
+    }
+}
+
+impl Drop for NatsSink {
+    /// Publishes what is already queued before returning.
+    fn drop(&mut self) {
+        self.sender.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// Writer half of a connection; a reader thread answers server PINGs.
+struct NatsConnection {
+    writer: Arc<Mutex<TcpStream>>,
+}
+
+impl NatsConnection {
+    fn open(addr: &str) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut info = String::new();
+        reader.read_line(&mut info)?;
+        if !info.starts_with("INFO") {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "not a NATS server"));
+        }
+
+        let writer = Arc::new(Mutex::new(stream));
+        writer.lock().unwrap()
+            .write_all(b"CONNECT {\"verbose\":false,\"pedantic\":false,\"name\":\"judicial-core\"}\r\n")?;
+
+        let pong = Arc::clone(&writer);
+        thread::Builder::new().name("judicial-nats-ping".into()).spawn(move || {
+            for line in reader.lines() {
+                let Ok(line) = line else { break };
+                if line.starts_with("PING") && pong.lock().unwrap().write_all(b"PONG\r\n").is_err() {
+                    break;
+                }
+                if line.starts_with("-ERR") {
+                    warn!(error = %line, "NATS server error");
+                }
+            }
+        })?;
+
+        Ok(Self { writer })
+    }
+
+    fn publish(&self, subject: &str, body: &str) -> io::Result<()> {
+        let mut stream = self.writer.lock().unwrap();
+        write!(stream, "PUB {} {}\r\n{}\r\n", subject, body.len(), body)?;
+        stream.flush()
+    }
+}
+
+impl Drop for NatsConnection {
+    fn drop(&mut self) {
+        // Ends the PING reader as well.
+        let _ = self.writer.lock().unwrap().shutdown(std::net::Shutdown::Both);
+    }
+}
+
+fn publish(addr: String, subject: String, connection: NatsConnection, bodies: Receiver<String>) {
+    let mut connection = Some(connection);
+    for body in bodies {
+        let sent = connection.as_ref().is_some_and(|c| c.publish(&subject, &body).is_ok());
+        if sent {
+            continue;
+        }
+
+        connection = NatsConnection::open(&addr).ok();
+        let retried = connection.as_ref().is_some_and(|c| c.publish(&subject, &body).is_ok());
+        if !retried {
+            warn!(%addr, %subject, "ledger entry not published to NATS");
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! RFC 5424 syslog output of ledger entries.
+//!
+//! Each entry becomes one datagram with the verdict kind as MSGID, the
+//! ledger wording as MSG and a `judicial@32473` structured-data element:
+//!
+//! ```text
+//! <108>1 2026-01-01T00:00:00.000000Z host judicial-core 4242 REJECTED
+//!     [judicial@32473 hash="…" previous_hash="…" law="2" action_type="FILE_DELETE" actor="agent-7"] REJECTED: …
+//! ```
+//!
+//! Rejections are logged at warning severity, warnings and quarantines at
+//! notice, everything else at informational. The default facility is
+//! `log audit` (13). On systemd hosts `/dev/log` is served by journald.
+
+use super::EventSink;
+use crate::ledger::LedgerEntry;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::net::{ToSocketAddrs, UdpSocket};
+#[cfg(unix)]
+use std::os::unix::net::UnixDatagram;
+#[cfg(unix)]
+use std::path::Path;
+use tracing::warn;
+
+pub const LOG_AUDIT: u8 = 13;
+
+/// Structured-data ID; 32473 is the enterprise number reserved for examples.
+const SD_ID: &str = "judicial@32473";
+
+#[derive(Debug)]
+enum Transport {
+    Udp(UdpSocket),
+    #[cfg(unix)]
+    Unix(UnixDatagram),
+}
+
+/// Writes ledger entries to a syslog daemon.
+#[derive(Debug)]
+pub struct SyslogSink {
+    transport: Transport,
+    facility: u8,
+    hostname: String,
+    app_name: String,
+}
+
+impl SyslogSink {
+    /// Send to a syslog server over UDP, usually port 514.
+    pub fn udp(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let socket = UdpSocket::bind(("0.0.0.0", 0))?;
+        socket.connect(addr)?;
+        Ok(Self::with_transport(Transport::Udp(socket)))
+    }
+
+    /// Send to a local socket such as `/dev/log`.
+    #[cfg(unix)]
+    pub fn unix(path: impl AsRef<Path>) -> io::Result<Self> {
+        let socket = UnixDatagram::unbound()?;
+        socket.connect(path)?;
+        Ok(Self::with_transport(Transport::Unix(socket)))
+    }
+
+    fn with_transport(transport: Transport) -> Self {
+        let hostname = fs::read_to_string("/proc/sys/kernel/hostname")
+            .map(|name| name.trim().to_string())
+            .ok()
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "-".to_string());
+        Self {
+            transport,
+            facility: LOG_AUDIT,
+            hostname,
+            app_name: "judicial-core".into(),
+        }
+    }
+
+    /// Syslog facility code, 0 to 23.
+    pub fn with_facility(mut self, facility: u8) -> Self {
+        self.facility = facility.min(23);
+        self
+    }
+
+    pub fn with_app_name(mut self, app_name: impl Into<String>) -> Self {
+        self.app_name = app_name.into();
+        self
+    }
+
+    fn format(&self, entry: &LedgerEntry) -> String {
+        let severity = if entry.verdict.starts_with("REJECTED") {
+            4
+        } else if entry.verdict.starts_with("APPROVED_WITH_WARNING") || entry.verdict.starts_with("QUARANTINED") {
+            5
+        } else {
+            6
+        };
+        let msg_id: String = entry.verdict.split(':').next().unwrap_or_default()
+            .chars()
+            .filter(|c| c.is_ascii_graphic())
+            .take(32)
+            .collect();
+
+        let mut sd = format!("[{} hash=\"{}\"", SD_ID, entry.hash);
+        if let Some(previous) = &entry.previous_hash {
+            write!(sd, " previous_hash=\"{}\"", previous).unwrap();
+        }
+        if let Some(law) = entry.law {
+            write!(sd, " law=\"{}\"", law).unwrap();
+        }
+        for violation in &entry.violations {
+            write!(sd, " violated_law=\"{}\"", violation.law_number).unwrap();
+        }
+        write!(sd, " action_type=\"{}\"", param(&entry.action.action_type)).unwrap();
+        if let Some(actor) = &entry.action.actor {
+            write!(sd, " actor=\"{}\"", param(actor)).unwrap();
+        }
+        sd.push(']');
+
+        format!(
+            "<{}>1 {} {} {} {} {} {} {}",
+            u16::from(self.facility) * 8 + severity,
+            entry.timestamp.format("%Y-%m-%dT%H:%M:%S%.6fZ"),
+            self.hostname,
+            self.app_name,
+            std::process::id(),
+            if msg_id.is_empty() { "-" } else { &msg_id },
+            sd,
+            entry.verdict,
+        )
+    }
+}
+
+impl EventSink for SyslogSink {
+    fn publish(&self, entry: &LedgerEntry) {
+        let message = self.format(entry);
+        let sent = match &self.transport {
+            Transport::Udp(socket) => socket.send(message.as_bytes()),
+            #[cfg(unix)]
+            Transport::Unix(socket) => socket.send(message.as_bytes()),
+        };
+        if let Err(e) = sent {
+            warn!(error = %e, hash = %entry.hash, "ledger entry not sent to syslog");
+        }
+    }
+}
+
+/// Escape a structured-data parameter value.
+fn param(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace(']', "\\]")
+}
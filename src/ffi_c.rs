@@ -0,0 +1,139 @@
+//! C ABI for embedding the core from C, C++ or Go.
+//!
+//! Strings crossing the boundary are NUL-terminated UTF-8. Strings returned
+//! by this module are owned by the caller and must be released with
+//! [`jc_string_free`]. The header is `include/judicial_core.h`. Needs the
+//! `ffi` feature.
+
+use crate::judicial_core::JudicialCore;
+use crate::ledger::verdict_text;
+use crate::verdicts::{SystemAction, Verdict};
+use libc::{c_char, c_double, c_int};
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+pub const JC_APPROVED: c_int = 0;
+pub const JC_APPROVED_WITH_WARNING: c_int = 1;
+pub const JC_REJECTED: c_int = 2;
+pub const JC_REJECTED_WITH_SUGGESTION: c_int = 3;
+pub const JC_QUARANTINED: c_int = 4;
+pub const JC_MALFORMED: c_int = 5;
+/// A null pointer or invalid UTF-8 argument.
+pub const JC_INVALID_ARGUMENT: c_int = -1;
+
+/// Create a core with the Master Pair laws. Free it with [`jc_core_free`].
+#[no_mangle]
+pub extern "C" fn jc_core_new() -> *mut JudicialCore {
+    Box::into_raw(Box::new(JudicialCore::new()))
+}
+
+/// Shut the core down and free it.
+///
+/// # Safety
+///
+/// `core` must come from [`jc_core_new`] and not be used afterwards. Null is ignored.
+#[no_mangle]
+pub unsafe extern "C" fn jc_core_free(core: *mut JudicialCore) {
+    if core.is_null() {
+        return;
+    }
+    let core = Box::from_raw(core);
+    let _ = core.shutdown();
+}
+
+/// Rule on an action and return one of the `JC_*` verdict codes.
+///
+/// When `reason` is not null it receives the verdict as the ledger words
+/// it, for example `REJECTED: ...`, followed by `; suggestion: ...` when
+/// the rejection comes with one. It is null for invalid arguments.
+///
+/// # Safety
+///
+/// `core` must be a live core from [`jc_core_new`]. The three strings must be
+/// valid NUL-terminated strings. `reason` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn jc_rule(
+    core: *const JudicialCore,
+    action_type: *const c_char,
+    payload: *const c_char,
+    context: *const c_char,
+    reason: *mut *mut c_char,
+) -> c_int {
+    if !reason.is_null() {
+        *reason = ptr::null_mut();
+    }
+    let (Some(core), Some(action_type), Some(payload), Some(context)) =
+        (core.as_ref(), to_str(action_type), to_str(payload), to_str(context))
+    else {
+        return JC_INVALID_ARGUMENT;
+    };
+
+    let verdict = core.rule(SystemAction::new(action_type, payload, context));
+    if !reason.is_null() {
+        let mut text = verdict_text(&verdict);
+        if let Verdict::RejectedWithSuggestion(_, suggestion) = &verdict {
+            text = format!("{}; suggestion: {}", text, suggestion);
+        }
+        *reason = to_c_string(text);
+    }
+
+    match verdict {
+        Verdict::Approved => JC_APPROVED,
+        Verdict::ApprovedWithWarning(_) => JC_APPROVED_WITH_WARNING,
+        Verdict::Rejected(_) => JC_REJECTED,
+        Verdict::RejectedWithSuggestion(_, _) => JC_REJECTED_WITH_SUGGESTION,
+        Verdict::Quarantined(_) => JC_QUARANTINED,
+        Verdict::Malformed(_) => JC_MALFORMED,
+    }
+}
+
+/// Approved fraction of all rulings, or a negative value for a null core.
+///
+/// # Safety
+///
+/// `core` must be null or a live core from [`jc_core_new`].
+#[no_mangle]
+pub unsafe extern "C" fn jc_compliance_score(core: *const JudicialCore) -> c_double {
+    match core.as_ref() {
+        Some(core) => core.get_compliance_score(),
+        None => -1.0,
+    }
+}
+
+/// The ledger as a JSON array, or null for a null core.
+///
+/// # Safety
+///
+/// `core` must be null or a live core from [`jc_core_new`].
+#[no_mangle]
+pub unsafe extern "C" fn jc_export_ledger(core: *const JudicialCore) -> *mut c_char {
+    match core.as_ref() {
+        Some(core) => to_c_string(core.export_ledger()),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Free a string returned by this library.
+///
+/// # Safety
+///
+/// `s` must be null or a string returned by this library, not freed before.
+#[no_mangle]
+pub unsafe extern "C" fn jc_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    CStr::from_ptr(s).to_str().ok()
+}
+
+/// Interior NULs cannot cross the boundary, so they are dropped.
+fn to_c_string(s: String) -> *mut c_char {
+    let bytes: Vec<u8> = s.into_bytes().into_iter().filter(|&b| b != 0).collect();
+    CString::new(bytes).map_or(ptr::null_mut(), CString::into_raw)
+}
@@ -0,0 +1,88 @@
+//! [`Arbitrary`] implementations behind the `arbitrary` feature, for the
+//! targets in `fuzz/`.
+//!
+//! Unstructured bytes rarely spell `drop table`, so generated actions are
+//! assembled from fragments: the words the Master Pair looks for, the same
+//! words disguised (case, separators, look-alike characters), JSON
+//! wrappers and free text. Action types and contexts lean on the values
+//! the laws single out in the same way.
+//!
+//! Run a target with `cargo +nightly fuzz run rule` (or `ledger_hash`,
+//! `policy_pack`) from the repository root.
+
+use crate::laws::master_pair::{
+    DESTRUCTIVE_PATTERNS, GOVERNED_ACTION_TYPES, INTEGRITY_CONTEXT, SAFETY_CONTEXT, SENSITIVE_PATTERNS,
+};
+use crate::verdicts::SystemAction;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+const MARKERS: &[&str] = &["backup", "rollback"];
+
+/// Longest payload, in fragments.
+const MAX_FRAGMENTS: usize = 8;
+
+impl<'a> Arbitrary<'a> for SystemAction {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let action_type = if u.ratio(7, 8)? { u.choose(GOVERNED_ACTION_TYPES)?.to_string() } else { u.arbitrary()? };
+        Ok(SystemAction {
+            action_type,
+            payload: payload(u)?,
+            context: context(u)?,
+            actor: u.arbitrary()?,
+            credentials: u.arbitrary()?,
+            roles: u.arbitrary()?,
+        })
+    }
+}
+
+fn keyword(u: &mut Unstructured<'_>) -> Result<&'static str> {
+    match u.int_in_range(0..=2)? {
+        0 => u.choose(&SENSITIVE_PATTERNS).copied(),
+        1 => u.choose(&DESTRUCTIVE_PATTERNS).copied(),
+        _ => u.choose(MARKERS).copied(),
+    }
+}
+
+/// `word` as written, or altered the way an evasive agent might.
+fn disguise(u: &mut Unstructured<'_>, word: &str) -> Result<String> {
+    Ok(match u.int_in_range(0..=5)? {
+        0 | 1 => word.to_string(),
+        2 => word.to_uppercase(),
+        3 => {
+            let at = u.int_in_range(0..=word.len())?;
+            // The laws' words are ASCII, so any index is a char boundary.
+            let (head, tail) = word.split_at(at);
+            format!("{}{}{}", head, u.choose(&["", " ", "\t", "_", "-", "\u{200b}", "/**/"])?, tail)
+        }
+        4 => word.replace(' ', u.choose(&["  ", "\t", "\n", "_", "%20"])?),
+        _ => word.chars().map(|c| if c == 'o' { '0' } else if c == 'e' { 'е' } else { c }).collect(),
+    })
+}
+
+fn payload(u: &mut Unstructured<'_>) -> Result<String> {
+    let mut payload = String::new();
+    for _ in 0..u.int_in_range(0..=MAX_FRAGMENTS)? {
+        match u.int_in_range(0..=3)? {
+            0 | 1 => {
+                let word = keyword(u)?;
+                payload.push_str(&disguise(u, word)?);
+            }
+            2 => payload.push_str(&serde_json::json!({ "query": u.arbitrary::<String>()? }).to_string()),
+            _ => payload.push_str(u.arbitrary()?),
+        }
+        payload.push(' ');
+    }
+    Ok(payload)
+}
+
+fn context(u: &mut Unstructured<'_>) -> Result<String> {
+    let mut flags = Vec::new();
+    for _ in 0..u.int_in_range(0..=3)? {
+        flags.push(match u.int_in_range(0..=2)? {
+            0 => u.choose(SAFETY_CONTEXT)?.to_string(),
+            1 => u.choose(INTEGRITY_CONTEXT)?.to_string(),
+            _ => u.arbitrary()?,
+        });
+    }
+    Ok(flags.join(","))
+}
@@ -0,0 +1,300 @@
+//! gRPC adjudication service, behind the `grpc` feature.
+//!
+//! Lets remote agents share one authoritative core. The wire format is
+//! described in `proto/judicial.proto`; the `judicial-grpcd` binary serves it.
+//! Calls that rule or read the ledger run on tokio's blocking pool.
+
+use crate::compliance::ComplianceWindow;
+use crate::judicial_core::{JudicialCore, JudicialCoreBuilder};
+use crate::ledger::LedgerEntry;
+use crate::observers::Observer;
+use crate::verdicts::{SystemAction, Verdict};
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::{Stream, StreamExt};
+use tonic::{Request, Response, Status};
+
+#[allow(clippy::all)]
+mod service {
+    include!(concat!(env!("OUT_DIR"), "/judicial.v1.Judicial.rs"));
+}
+
+pub use service::judicial_client::JudicialClient;
+pub use service::judicial_server::{Judicial, JudicialServer};
+
+/// Verdict events buffered per subscriber before the slowest one starts
+/// missing events.
+const EVENT_BUFFER: usize = 1024;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ActionRequest {
+    #[prost(string, tag = "1")]
+    pub action_type: String,
+    #[prost(string, tag = "2")]
+    pub payload: String,
+    #[prost(string, tag = "3")]
+    pub context: String,
+    #[prost(string, optional, tag = "4")]
+    pub actor: Option<String>,
+    #[prost(string, optional, tag = "5")]
+    pub credentials: Option<String>,
+    /// Verified roles. Only set on actions sent back by the server.
+    #[prost(string, repeated, tag = "6")]
+    pub roles: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct VerdictReply {
+    /// [`Verdict::label`], e.g. `REJECTED_WITH_SUGGESTION`.
+    #[prost(string, tag = "1")]
+    pub kind: String,
+    #[prost(bool, tag = "2")]
+    pub approved: bool,
+    #[prost(string, tag = "3")]
+    pub reason: String,
+    #[prost(string, tag = "4")]
+    pub suggestion: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BatchRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub actions: Vec<ActionRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BatchReply {
+    #[prost(message, repeated, tag = "1")]
+    pub verdicts: Vec<VerdictReply>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ComplianceRequest {
+    /// Score only the most recent N rulings; the whole ledger when unset.
+    #[prost(uint64, optional, tag = "1")]
+    pub last_rulings: Option<u64>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct ComplianceReply {
+    #[prost(double, tag = "1")]
+    pub score: f64,
+    #[prost(uint64, tag = "2")]
+    pub rulings: u64,
+    #[prost(uint64, tag = "3")]
+    pub rejections: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LedgerQuery {
+    #[prost(string, optional, tag = "1")]
+    pub action_type: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub actor: Option<String>,
+    #[prost(uint64, tag = "3")]
+    pub offset: u64,
+    /// Zero means no limit.
+    #[prost(uint64, tag = "4")]
+    pub limit: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LedgerRecord {
+    /// RFC 3339.
+    #[prost(string, tag = "1")]
+    pub timestamp: String,
+    #[prost(message, optional, tag = "2")]
+    pub action: Option<ActionRequest>,
+    #[prost(string, tag = "3")]
+    pub verdict: String,
+    #[prost(string, tag = "4")]
+    pub hash: String,
+    #[prost(string, optional, tag = "5")]
+    pub previous_hash: Option<String>,
+    #[prost(uint32, optional, tag = "6")]
+    pub law: Option<u32>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct LedgerReply {
+    #[prost(message, repeated, tag = "1")]
+    pub entries: Vec<LedgerRecord>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct VerdictEventsRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct VerdictEvent {
+    #[prost(message, optional, tag = "1")]
+    pub action: Option<ActionRequest>,
+    #[prost(message, optional, tag = "2")]
+    pub verdict: Option<VerdictReply>,
+}
+
+impl From<ActionRequest> for SystemAction {
+    fn from(request: ActionRequest) -> Self {
+        let mut action = SystemAction::new(request.action_type, request.payload, request.context);
+        action.actor = request.actor;
+        action.credentials = request.credentials;
+        action
+    }
+}
+
+impl From<&SystemAction> for ActionRequest {
+    fn from(action: &SystemAction) -> Self {
+        Self {
+            action_type: action.action_type.clone(),
+            payload: action.payload.clone(),
+            context: action.context.clone(),
+            actor: action.actor.clone(),
+            credentials: None,
+            roles: action.roles.clone(),
+        }
+    }
+}
+
+impl From<&Verdict> for VerdictReply {
+    fn from(verdict: &Verdict) -> Self {
+        let (reason, suggestion) = match verdict {
+            Verdict::Approved => (String::new(), String::new()),
+            Verdict::ApprovedWithWarning(reason)
+            | Verdict::Rejected(reason)
+            | Verdict::Quarantined(reason)
+            | Verdict::Malformed(reason) => (reason.clone(), String::new()),
+            Verdict::RejectedWithSuggestion(reason, suggestion) => (reason.clone(), suggestion.clone()),
+        };
+        Self {
+            kind: verdict.label().into(),
+            approved: verdict.is_approved(),
+            reason,
+            suggestion,
+        }
+    }
+}
+
+impl From<&LedgerEntry> for LedgerRecord {
+    fn from(entry: &LedgerEntry) -> Self {
+        Self {
+            timestamp: entry.timestamp.to_rfc3339(),
+            action: Some((&entry.action).into()),
+            verdict: entry.verdict.clone(),
+            hash: entry.hash.clone(),
+            previous_hash: entry.previous_hash.clone(),
+            law: entry.law,
+        }
+    }
+}
+
+/// Forwards every verdict to the event stream.
+#[derive(Debug)]
+struct EventObserver(broadcast::Sender<VerdictEvent>);
+
+impl Observer for EventObserver {
+    fn on_verdict(&self, action: &SystemAction, verdict: &Verdict) {
+        // No subscribers is not an error.
+        let _ = self.0.send(VerdictEvent {
+            action: Some(action.into()),
+            verdict: Some(verdict.into()),
+        });
+    }
+}
+
+/// [`Judicial`] service backed by one core.
+#[derive(Debug, Clone)]
+pub struct GrpcCourt {
+    core: Arc<JudicialCore>,
+    events: broadcast::Sender<VerdictEvent>,
+}
+
+impl GrpcCourt {
+    /// Build the core from `builder`, adding the observer that feeds
+    /// `VerdictEvents`.
+    pub fn new(builder: JudicialCoreBuilder) -> Self {
+        let (events, _) = broadcast::channel(EVENT_BUFFER);
+        let core = builder.observer(EventObserver(events.clone())).build();
+        Self { core: Arc::new(core), events }
+    }
+
+    pub fn core(&self) -> &Arc<JudicialCore> {
+        &self.core
+    }
+
+    pub fn into_server(self) -> JudicialServer<Self> {
+        JudicialServer::new(self)
+    }
+}
+
+/// Run `f` on the blocking pool, off the async workers.
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> Result<T, Status> {
+    tokio::task::spawn_blocking(f).await.map_err(|e| Status::internal(e.to_string()))
+}
+
+type EventStream = Pin<Box<dyn Stream<Item = Result<VerdictEvent, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl Judicial for GrpcCourt {
+    type VerdictEventsStream = EventStream;
+
+    async fn rule(&self, request: Request<ActionRequest>) -> Result<Response<VerdictReply>, Status> {
+        let core = Arc::clone(&self.core);
+        let verdict = blocking(move || core.rule(request.into_inner().into())).await?;
+        Ok(Response::new((&verdict).into()))
+    }
+
+    async fn rule_batch(&self, request: Request<BatchRequest>) -> Result<Response<BatchReply>, Status> {
+        let actions: Vec<SystemAction> = request.into_inner().actions.into_iter().map(Into::into).collect();
+        let core = Arc::clone(&self.core);
+        let verdicts = blocking(move || core.rule_batch(&actions)).await?.iter().map(Into::into).collect();
+        Ok(Response::new(BatchReply { verdicts }))
+    }
+
+    async fn get_compliance(
+        &self,
+        request: Request<ComplianceRequest>,
+    ) -> Result<Response<ComplianceReply>, Status> {
+        let window = match request.into_inner().last_rulings {
+            Some(n) => ComplianceWindow::LastRulings(n as usize),
+            None => ComplianceWindow::Lifetime,
+        };
+        let core = Arc::clone(&self.core);
+        let overall = blocking(move || core.compliance_report(window).overall).await?;
+        Ok(Response::new(ComplianceReply {
+            score: overall.score,
+            rulings: overall.rulings as u64,
+            rejections: overall.rejections as u64,
+        }))
+    }
+
+    async fn query_ledger(&self, request: Request<LedgerQuery>) -> Result<Response<LedgerReply>, Status> {
+        let query = request.into_inner();
+        let limit = if query.limit == 0 { usize::MAX } else { query.limit as usize };
+        let core = Arc::clone(&self.core);
+        let entries = blocking(move || {
+            core.query_ledger(
+                |entry| {
+                    query.action_type.as_ref().is_none_or(|t| *t == entry.action.action_type)
+                        && query.actor.as_ref().is_none_or(|a| Some(a) == entry.action.actor.as_ref())
+                },
+                query.offset as usize,
+                limit,
+            )
+        })
+        .await?;
+        Ok(Response::new(LedgerReply {
+            entries: entries.iter().map(LedgerRecord::from).collect(),
+        }))
+    }
+
+    async fn verdict_events(
+        &self,
+        _request: Request<VerdictEventsRequest>,
+    ) -> Result<Response<Self::VerdictEventsStream>, Status> {
+        // Subscribers that fall behind skip the events they missed.
+        let stream = BroadcastStream::new(self.events.subscribe())
+            .filter_map(|event| event.ok().map(Ok));
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
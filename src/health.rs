@@ -0,0 +1,49 @@
+use crate::ledger::IntegrityError;
+use crate::verdict_cache::CacheStats;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Whether a [`JudicialCore`](crate::JudicialCore) can be trusted right now.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    /// No integrity failure, no law issues, and still accepting rulings.
+    pub healthy: bool,
+    pub checked_at: DateTime<Utc>,
+    pub shut_down: bool,
+    pub ledger_entries: usize,
+    /// First broken link in the hash chain, if any.
+    pub ledger_integrity: Option<IntegrityError>,
+    /// Inconsistencies in the core's law set and profiles.
+    pub law_issues: Vec<String>,
+    pub active_laws: usize,
+    pub profiles: usize,
+    /// Rate-limit windows currently tracked.
+    pub rate_limit_buckets: usize,
+    /// Quarantined actions not yet resolved by a reviewer.
+    pub pending_reviews: usize,
+    /// Time of the most recent policy change recorded in the ledger.
+    pub last_policy_change: Option<DateTime<Utc>>,
+    /// The verdict cache, if one is configured.
+    pub verdict_cache: Option<CacheHealth>,
+}
+
+/// Size, effectiveness and policy epoch of the verdict cache.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CacheHealth {
+    pub entries: usize,
+    pub capacity: usize,
+    /// Share of lookups served from the cache since the core was built.
+    pub hit_rate: f64,
+    pub epoch: u64,
+}
+
+impl From<&CacheStats> for CacheHealth {
+    fn from(stats: &CacheStats) -> Self {
+        Self {
+            entries: stats.entries,
+            capacity: stats.capacity,
+            hit_rate: stats.hit_rate(),
+            epoch: stats.epoch,
+        }
+    }
+}
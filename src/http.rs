@@ -0,0 +1,246 @@
+//! HTTP/JSON API, behind the `http-server` feature.
+//!
+//! | Method | Path | |
+//! |---|---|---|
+//! | POST | `/v1/rule` | rule on a [`SystemAction`], returns the [`Verdict`] |
+//! | GET | `/v1/ledger` | entries, filtered by `action_type`, `actor`, `offset`, `limit` |
+//! | GET | `/v1/ledger/verify` | hash chain check |
+//! | GET | `/v1/ledger/{hash}` | one entry, with the law and violations behind its verdict |
+//! | GET | `/v1/reviews` | unresolved reviews |
+//! | POST | `/v1/reviews/{id}/claim` | claim for the caller |
+//! | POST | `/v1/reviews/{id}/resolve` | `{"decision": "Approve", "rationale": ...}`, by the claimant |
+//! | GET | `/v1/health` | [`HealthReport`](crate::HealthReport) |
+//! | GET | `/v1/statistics` | [`Statistics`](crate::Statistics) |
+//! | GET | `/metrics` | the same counters, plus compliance and review gauges, in Prometheus text format |
+//!
+//! Reviewers are whoever the `Authorization: Bearer` token on a claim or
+//! resolve request proves to the core's
+//! [`IdentityProvider`](crate::IdentityProvider); without a provider those
+//! endpoints answer 401. Every endpoint does its work on tokio's blocking
+//! pool: rulings, ledger reads, health checks and the compliance score
+//! take locks and may wait for the ledger to flush, and a review store may
+//! be a database.
+
+use crate::judicial_core::JudicialCore;
+use crate::ledger::LedgerEntry;
+use crate::review::{ReviewDecision, ReviewError, ReviewId, ReviewItem, ReviewStatus};
+use crate::verdicts::{SystemAction, Verdict};
+use axum::extract::{Path, Query, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+use std::fmt::Write;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+type Core = State<Arc<JudicialCore>>;
+
+/// Routes for `core`, for embedding in a larger axum application.
+pub fn router(core: Arc<JudicialCore>) -> Router {
+    Router::new()
+        .route("/v1/rule", post(rule))
+        .route("/v1/ledger", get(ledger))
+        .route("/v1/ledger/verify", get(verify_ledger))
+        .route("/v1/ledger/{hash}", get(ledger_entry))
+        .route("/v1/reviews", get(reviews))
+        .route("/v1/reviews/{id}/claim", post(claim_review))
+        .route("/v1/reviews/{id}/resolve", post(resolve_review))
+        .route("/v1/health", get(health))
+        .route("/v1/statistics", get(statistics))
+        .route("/metrics", get(metrics))
+        .with_state(core)
+}
+
+/// Serve `core` on `addr` until `shutdown` completes.
+pub async fn serve(
+    core: Arc<JudicialCore>,
+    addr: SocketAddr,
+    shutdown: impl std::future::Future<Output = ()> + Send + 'static,
+) -> io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, router(core))
+        .with_graceful_shutdown(shutdown)
+        .await
+}
+
+/// Run `f` on the blocking pool, off the async workers.
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> Result<T, Response> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response())
+}
+
+async fn rule(State(core): Core, Json(action): Json<SystemAction>) -> Result<Json<Verdict>, Response> {
+    blocking(move || Json(core.rule(action))).await
+}
+
+#[derive(Debug, Deserialize)]
+struct LedgerParams {
+    action_type: Option<String>,
+    actor: Option<String>,
+    #[serde(default)]
+    offset: usize,
+    limit: Option<usize>,
+}
+
+async fn ledger(
+    State(core): Core,
+    Query(params): Query<LedgerParams>,
+) -> Result<Json<Vec<LedgerEntry>>, Response> {
+    blocking(move || {
+        Json(core.query_ledger(
+            |entry| {
+                params.action_type.as_ref().is_none_or(|t| *t == entry.action.action_type)
+                    && params.actor.as_ref().is_none_or(|a| Some(a) == entry.action.actor.as_ref())
+            },
+            params.offset,
+            params.limit.unwrap_or(usize::MAX),
+        ))
+    })
+    .await
+}
+
+async fn verify_ledger(State(core): Core) -> Result<Response, Response> {
+    blocking(move || match core.verify_ledger() {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => (StatusCode::CONFLICT, Json(e)).into_response(),
+    })
+    .await
+}
+
+async fn ledger_entry(State(core): Core, Path(hash): Path<String>) -> Result<Response, Response> {
+    blocking(move || match core.query_ledger(|entry| entry.hash == hash, 0, 1).pop() {
+        Some(entry) => Json(entry).into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    })
+    .await
+}
+
+async fn reviews(State(core): Core) -> Result<Json<Vec<ReviewItem>>, Response> {
+    blocking(move || Json(core.reviews().pending())).await
+}
+
+/// The actor the request's bearer token proves.
+fn reviewer(core: &JudicialCore, headers: &HeaderMap) -> Result<String, (StatusCode, String)> {
+    let token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| (StatusCode::UNAUTHORIZED, "bearer token required".to_string()))?;
+    core.verify_identity(token)
+        .map(|identity| identity.actor)
+        .map_err(|e| (StatusCode::UNAUTHORIZED, e.to_string()))
+}
+
+async fn claim_review(State(core): Core, Path(id): Path<ReviewId>, headers: HeaderMap) -> Result<Response, Response> {
+    blocking(move || match reviewer(&core, &headers) {
+        Ok(reviewer) => review_response(core.claim_review(id, &reviewer)),
+        Err(rejection) => rejection.into_response(),
+    })
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+struct Resolution {
+    decision: ReviewDecision,
+    #[serde(default)]
+    rationale: String,
+}
+
+async fn resolve_review(
+    State(core): Core,
+    Path(id): Path<ReviewId>,
+    headers: HeaderMap,
+    Json(resolution): Json<Resolution>,
+) -> Result<Response, Response> {
+    blocking(move || {
+        let reviewer = match reviewer(&core, &headers) {
+            Ok(reviewer) => reviewer,
+            Err(rejection) => return rejection.into_response(),
+        };
+        if let Some(ReviewStatus::Claimed { reviewer: claimant, .. }) = core.reviews().get(id).map(|item| item.status) {
+            if claimant != reviewer {
+                return (StatusCode::FORBIDDEN, format!("review #{} is claimed by {}", id, claimant)).into_response();
+            }
+        }
+        review_response(core.resolve_review(id, resolution.decision, &resolution.rationale))
+    })
+    .await
+}
+
+fn review_response(result: Result<ReviewItem, ReviewError>) -> Response {
+    match result {
+        Ok(item) => Json(item).into_response(),
+        Err(e @ ReviewError::NotFound(_)) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+        Err(e @ ReviewError::Storage(_)) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => (StatusCode::CONFLICT, e.to_string()).into_response(),
+    }
+}
+
+async fn health(State(core): Core) -> Result<Response, Response> {
+    blocking(move || Json(core.health()).into_response()).await
+}
+
+async fn statistics(State(core): Core) -> Result<Response, Response> {
+    blocking(move || Json(core.statistics()).into_response()).await
+}
+
+async fn metrics(State(core): Core) -> Result<Response, Response> {
+    let text = blocking(move || prometheus_text(&core)).await?;
+    Ok(([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], text).into_response())
+}
+
+fn prometheus_text(core: &JudicialCore) -> String {
+    let stats = core.statistics();
+    let mut out = String::new();
+
+    metric(&mut out, "judicial_rulings_total", "counter", "Rulings since the core was built.");
+    writeln!(out, "judicial_rulings_total {}", stats.rulings).unwrap();
+
+    metric(&mut out, "judicial_verdicts_total", "counter", "Rulings per verdict kind.");
+    for (verdict, count) in &stats.verdicts {
+        writeln!(out, "judicial_verdicts_total{{verdict=\"{}\"}} {}", label(verdict), count).unwrap();
+    }
+
+    metric(&mut out, "judicial_law_fired_total", "counter", "Rulings in which each law was violated.");
+    for (law, count) in &stats.law_fired {
+        writeln!(out, "judicial_law_fired_total{{law=\"{}\"}} {}", law, count).unwrap();
+    }
+
+    metric(&mut out, "judicial_rejections_total", "counter", "Rulings not approved, per action type.");
+    for (action_type, count) in &stats.rejected_action_types {
+        writeln!(out, "judicial_rejections_total{{action_type=\"{}\"}} {}", label(action_type), count).unwrap();
+    }
+
+    metric(&mut out, "judicial_ruling_latency_average_microseconds", "gauge", "Mean ruling latency.");
+    writeln!(out, "judicial_ruling_latency_average_microseconds {}", stats.average_latency_us).unwrap();
+    metric(&mut out, "judicial_ruling_latency_max_microseconds", "gauge", "Slowest ruling.");
+    writeln!(out, "judicial_ruling_latency_max_microseconds {}", stats.max_latency_us).unwrap();
+
+    metric(&mut out, "judicial_rate_limit_allowed_total", "counter", "Actions admitted by rate limits.");
+    writeln!(out, "judicial_rate_limit_allowed_total {}", stats.rate_limits.allowed).unwrap();
+    metric(&mut out, "judicial_rate_limit_throttled_total", "counter", "Actions rejected by rate limits.");
+    writeln!(out, "judicial_rate_limit_throttled_total {}", stats.rate_limits.throttled).unwrap();
+
+    metric(&mut out, "judicial_compliance_score", "gauge", "Approved fraction of rulings in the ledger.");
+    writeln!(out, "judicial_compliance_score {}", core.get_compliance_score()).unwrap();
+    metric(&mut out, "judicial_pending_reviews", "gauge", "Quarantined actions awaiting a reviewer.");
+    writeln!(out, "judicial_pending_reviews {}", core.reviews().pending_count()).unwrap();
+    metric(&mut out, "judicial_shut_down", "gauge", "1 once the core has shut down.");
+    writeln!(out, "judicial_shut_down {}", u8::from(core.is_shut_down())).unwrap();
+
+    out
+}
+
+fn metric(out: &mut String, name: &str, kind: &str, help: &str) {
+    writeln!(out, "# HELP {} {}", name, help).unwrap();
+    writeln!(out, "# TYPE {} {}", name, kind).unwrap();
+}
+
+/// Escape a Prometheus label value.
+fn label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
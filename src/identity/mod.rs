@@ -0,0 +1,46 @@
+//! Verify who an action is performed for.
+//!
+//! Actions may carry `credentials`, typically a bearer token. With an
+//! [`IdentityProvider`] installed, the core verifies them before ruling:
+//! the action's `actor` becomes the verified subject and its `roles` the
+//! verified roles, so laws can rely on them rather than on whatever the
+//! caller wrote into `context`. Credentials are stripped before the action
+//! reaches laws, observers or the ledger, and an action whose credentials
+//! fail verification is rejected.
+//!
+//! Roles are never taken from the caller: without verified credentials an
+//! action has none.
+//!
+//! A reference OpenID Connect provider, `OidcProvider`, is behind the
+//! `oidc` feature.
+
+use std::fmt;
+
+#[cfg(feature = "oidc")]
+pub mod oidc;
+
+#[cfg(feature = "oidc")]
+pub use oidc::OidcProvider;
+
+/// A verified actor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Identity {
+    pub actor: String,
+    pub roles: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdentityError(pub String);
+
+impl fmt::Display for IdentityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "identity not verified: {}", self.0)
+    }
+}
+
+impl std::error::Error for IdentityError {}
+
+/// Turns credentials into a verified identity.
+pub trait IdentityProvider: fmt::Debug + Send + Sync {
+    fn verify(&self, credentials: &str) -> Result<Identity, IdentityError>;
+}
@@ -0,0 +1,143 @@
+//! OpenID Connect ID and access tokens (JWTs) checked against the issuer's
+//! published keys.
+//!
+//! [`OidcProvider::discover`] reads `{issuer}/.well-known/openid-configuration`
+//! and fetches the JWKS it points to. A token is accepted when it is signed
+//! by one of those keys, names the configured issuer and audience, and has
+//! not expired. The actor is the `sub` claim; roles come from the `roles`
+//! claim, either a list or a space-separated string. Keys are fetched again
+//! when a token names an unknown key id, at most once a minute.
+
+use super::{Identity, IdentityError, IdentityProvider};
+use jsonwebtoken::jwk::{AlgorithmParameters, JwkSet};
+use jsonwebtoken::{DecodingKey, Validation};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use tracing::info;
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug)]
+pub struct OidcProvider {
+    issuer: String,
+    audience: String,
+    jwks_uri: String,
+    roles_claim: String,
+    agent: ureq::Agent,
+    keys: RwLock<Keys>,
+}
+
+#[derive(Debug)]
+struct Keys {
+    set: JwkSet,
+    fetched: Instant,
+}
+
+#[derive(Deserialize)]
+struct Discovery {
+    jwks_uri: String,
+}
+
+#[derive(Deserialize)]
+struct Claims {
+    sub: String,
+    #[serde(flatten)]
+    other: Map<String, Value>,
+}
+
+fn failed(error: impl std::fmt::Display) -> IdentityError {
+    IdentityError(error.to_string())
+}
+
+impl OidcProvider {
+    /// `issuer` must match the tokens' `iss` claim exactly; `audience` is
+    /// this core's client id.
+    pub fn discover(issuer: impl Into<String>, audience: impl Into<String>) -> Result<Self, IdentityError> {
+        let issuer = issuer.into();
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(10)))
+            .build()
+            .into();
+        let url = format!("{}/.well-known/openid-configuration", issuer.trim_end_matches('/'));
+        let discovery: Discovery = get_json(&agent, &url)?;
+        let set = get_json(&agent, &discovery.jwks_uri)?;
+        info!(%issuer, jwks_uri = %discovery.jwks_uri, "OIDC keys loaded");
+
+        Ok(Self {
+            issuer,
+            audience: audience.into(),
+            jwks_uri: discovery.jwks_uri,
+            roles_claim: "roles".into(),
+            agent,
+            keys: RwLock::new(Keys { set, fetched: Instant::now() }),
+        })
+    }
+
+    /// Read roles from `claim` instead of `roles`, e.g. `groups`.
+    pub fn roles_claim(mut self, claim: impl Into<String>) -> Self {
+        self.roles_claim = claim.into();
+        self
+    }
+
+    fn key(&self, kid: &str) -> Result<DecodingKey, IdentityError> {
+        if let Some(key) = self.cached_key(kid)? {
+            return Ok(key);
+        }
+        {
+            let mut keys = self.keys.write().unwrap();
+            if keys.fetched.elapsed() >= REFRESH_INTERVAL {
+                keys.set = get_json(&self.agent, &self.jwks_uri)?;
+                keys.fetched = Instant::now();
+                info!(issuer = %self.issuer, "OIDC keys refreshed");
+            }
+        }
+        self.cached_key(kid)?
+            .ok_or_else(|| IdentityError(format!("unknown signing key {}", kid)))
+    }
+
+    fn cached_key(&self, kid: &str) -> Result<Option<DecodingKey>, IdentityError> {
+        let keys = self.keys.read().unwrap();
+        let Some(jwk) = keys.set.find(kid) else {
+            return Ok(None);
+        };
+        // A shared secret published as a JWK would let anyone mint tokens.
+        if matches!(jwk.algorithm, AlgorithmParameters::OctetKey(_)) {
+            return Err(IdentityError(format!("signing key {} is symmetric", kid)));
+        }
+        DecodingKey::from_jwk(jwk).map(Some).map_err(failed)
+    }
+
+    fn roles(&self, claims: &Map<String, Value>) -> Vec<String> {
+        match claims.get(&self.roles_claim) {
+            Some(Value::Array(roles)) => roles.iter().filter_map(|r| r.as_str().map(String::from)).collect(),
+            Some(Value::String(roles)) => roles.split_whitespace().map(String::from).collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl IdentityProvider for OidcProvider {
+    fn verify(&self, credentials: &str) -> Result<Identity, IdentityError> {
+        let token = credentials.strip_prefix("Bearer ").unwrap_or(credentials).trim();
+        let header = jsonwebtoken::decode_header(token).map_err(failed)?;
+        let kid = header.kid.ok_or_else(|| IdentityError("token has no key id".into()))?;
+        let key = self.key(&kid)?;
+
+        // The key's family must match the algorithm, so an RSA key cannot be
+        // used to check an HMAC signature.
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.audience]);
+        let claims = jsonwebtoken::decode::<Claims>(token, &key, &validation).map_err(failed)?.claims;
+
+        Ok(Identity { roles: self.roles(&claims.other), actor: claims.sub })
+    }
+}
+
+fn get_json<T: serde::de::DeserializeOwned>(agent: &ureq::Agent, url: &str) -> Result<T, IdentityError> {
+    let mut response = agent.get(url).call().map_err(failed)?;
+    let body = response.body_mut().read_to_string().map_err(failed)?;
+    serde_json::from_str(&body).map_err(failed)
+}
@@ -1,61 +1,1144 @@
-use crate::laws::MasterPair;
+use crate::action_types::{ActionTypes, UnknownActionPolicy};
+use crate::approval::{ApprovalService, ApprovalStatus, Approvals};
+use crate::clock::{Clock, SystemClock};
+use crate::compliance::{ComplianceReport, ComplianceWindow};
+use crate::health::{CacheHealth, HealthReport};
+use crate::identity::{Identity, IdentityError, IdentityProvider};
+use crate::jury::Jury;
+use crate::laws::{Law, LawBudget, LawSet};
+use crate::ledger::{IntegrityError, LedgerBackend, LedgerEntry, MemoryBackend, TamperProofLedger};
+use crate::ledger_writer::{LedgerRecord, LedgerWriter, WriteMode};
+use crate::observers::Observer;
+#[cfg(feature = "plugins")]
+use crate::plugins::{LawPlugins, PluginError, PluginLaw};
+use crate::policy_pack::{PolicyPack, PolicyPackError};
+use crate::precedent::{PrecedentPolicy, Precedents};
+use crate::profiles::{PolicyProfile, PolicyProfiles};
+use crate::rate_limit::{RateLimit, RateLimitScope, RateLimitStats, RateLimiter};
+use crate::replay::Divergence;
+use crate::review::{
+    ReviewDecision, ReviewError, ReviewFallback, ReviewId, ReviewItem, ReviewQueue, ReviewSla, ReviewStatus,
+    ReviewStore, SlaStage,
+};
+#[cfg(feature = "schemas")]
+use crate::schemas::{ActionSchemas, SchemaError};
+use crate::scoped::ScopedCourt;
+use crate::snapshot::{CoreSnapshot, RetainedPolicy, SnapshotError};
+use crate::statistics::{Statistics, StatsCollector};
+use crate::strictness::Strictness;
+use crate::verdict_cache::{action_key, CacheStats, VerdictCache};
 use crate::verdicts::{Verdict, SystemAction};
-use crate::ledger::TamperProofLedger;
-use std::sync::RwLock;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::borrow::Cow;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::collections::HashSet;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::time::Duration;
+use tracing::{debug, error, info, info_span, warn, Span};
+use web_time::Instant;
 
 #[derive(Debug)]
 pub struct JudicialCore {
-    master_pair: MasterPair,
-    ledger: RwLock<TamperProofLedger>,
+    /// Replaced whole on every change; rulings keep the policy they
+    /// started with.
+    policy: RwLock<Arc<Policy>>,
+    /// The builder's laws, to which plugin laws are appended.
+    #[cfg(feature = "plugins")]
+    base_laws: LawSet,
+    #[cfg(feature = "plugins")]
+    law_plugins: Option<LawPlugins>,
+    ledger: LedgerWriter,
+    observers: Vec<Arc<dyn Observer>>,
+    jury: Option<Jury>,
+    rate_limiter: RateLimiter,
+    clock: Arc<dyn Clock>,
+    #[cfg(feature = "schemas")]
+    schemas: ActionSchemas,
+    reviews: ReviewQueue,
+    precedents: Option<Precedents>,
+    review_sla: Option<ReviewSla>,
+    approvals: Option<Approvals>,
+    identity: Option<Box<dyn IdentityProvider>>,
+    cache: Option<VerdictCache>,
+    /// Reviews already alerted as nearing their SLA deadline, until they are
+    /// resolved or time out. Claimed reviews still count toward the SLA.
+    sla_warned: Mutex<HashSet<ReviewId>>,
+    stats: StatsCollector,
+    shut_down: AtomicBool,
+    /// Set once the SHUTDOWN entry is recorded and the ledger closed.
+    closed: Mutex<bool>,
+    shutdown_timeout: Duration,
+    in_flight: InFlight,
 }
 
+/// How long [`JudicialCore::shutdown`] waits for in-flight rulings unless
+/// the builder says otherwise.
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A governance change was refused because the core is shut down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShutDownError;
+
+impl fmt::Display for ShutDownError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "judicial core is shut down")
+    }
+}
+
+impl std::error::Error for ShutDownError {}
+
 impl JudicialCore {
     pub fn new() -> Self {
-        Self {
-            master_pair: MasterPair::default(),
-            ledger: RwLock::new(TamperProofLedger::new()),
+        Self::builder().build()
+    }
+
+    pub fn with_strictness(strictness: Strictness) -> Self {
+        Self::builder().strictness(strictness).build()
+    }
+
+    pub fn builder() -> JudicialCoreBuilder {
+        JudicialCoreBuilder::default()
+    }
+
+    /// A handle whose rulings all carry `context` and `actor`.
+    pub fn scoped(&self, context: impl Into<String>, actor: impl Into<String>) -> ScopedCourt<'_> {
+        ScopedCourt::new(self, context.into(), actor.into())
+    }
+
+    pub fn strictness(&self) -> Strictness {
+        self.policy().strictness
+    }
+
+    /// Switch strictness at runtime. Every change is recorded in the ledger.
+    /// Fails after [`shutdown`](Self::shutdown), as
+    /// [`restore`](Self::restore) does.
+    pub fn set_strictness(&self, strictness: Strictness) -> Result<(), ShutDownError> {
+        let mut policy = self.policy.write().unwrap();
+        if self.is_shut_down() {
+            return Err(ShutDownError);
+        }
+        if policy.strictness == strictness {
+            return Ok(());
+        }
+
+        let change = format!("{} -> {}", policy.strictness, strictness);
+        info!(%change, "strictness changed");
+        *policy = Arc::new(Policy { strictness, ..Policy::clone(&policy) });
+        self.policy_changed();
+
+        let mut ledger = self.ledger.write();
+        ledger.record_policy_change("strictness", change.clone());
+        drop(ledger);
+        drop(policy);
+
+        for observer in &self.observers {
+            observer.on_policy_change("strictness", &change);
+        }
+        Ok(())
+    }
+
+    pub fn snapshot(&self) -> CoreSnapshot {
+        let policy = self.policy();
+        let ledger = self.ledger.read();
+        CoreSnapshot {
+            taken_at: self.clock.now(),
+            strictness: policy.strictness,
+            laws: policy.laws.iter().map(|law| law.number()).collect(),
+            ledger_len: ledger.entries().len(),
+            ledger_head: ledger.entries().last().map(|e| e.hash.clone()),
+            policy: RetainedPolicy(Some(policy)),
+        }
+    }
+
+    /// Return to the governance state captured by `snapshot`: the whole
+    /// policy if the snapshot holds it, otherwise just the strictness, for
+    /// which the active laws must match the snapshot's. See
+    /// [`CoreSnapshot`].
+    ///
+    /// The ledger is append-only, so entries recorded since the snapshot are
+    /// kept; a restore entry referencing the snapshot's ledger position is
+    /// appended instead.
+    pub fn restore(&self, snapshot: &CoreSnapshot) -> Result<(), SnapshotError> {
+        if self.is_shut_down() {
+            return Err(SnapshotError::ShutDown);
+        }
+
+        let mut policy = self.policy.write().unwrap();
+        let found: Vec<u32> = policy.laws.iter().map(|law| law.number()).collect();
+        if snapshot.policy.0.is_none() && found != snapshot.laws {
+            return Err(SnapshotError::LawSetMismatch { expected: snapshot.laws.clone(), found });
+        }
+
+        let mut ledger = self.ledger.write();
+
+        let head = snapshot.ledger_len.checked_sub(1)
+            .and_then(|i| ledger.entries().get(i))
+            .map(|e| e.hash.clone());
+        if head != snapshot.ledger_head {
+            return Err(SnapshotError::ForeignLedger);
+        }
+
+        let change = format!(
+            "restored snapshot at entry {} ({}), strictness {}, laws {:?}",
+            snapshot.ledger_len,
+            snapshot.ledger_head.as_deref().unwrap_or("genesis"),
+            snapshot.strictness,
+            snapshot.laws
+        );
+        info!(%change, "snapshot restored");
+        *policy = match &snapshot.policy.0 {
+            Some(retained) => Arc::clone(retained),
+            None => Arc::new(Policy { strictness: snapshot.strictness, ..Policy::clone(&policy) }),
+        };
+        self.policy_changed();
+        ledger.record_policy_change("restore", change.clone());
+        drop(ledger);
+        drop(policy);
+
+        for observer in &self.observers {
+            observer.on_policy_change("restore", &change);
         }
+        Ok(())
     }
 
     pub fn rule(&self, action: SystemAction) -> Verdict {
-        // Law 1: Safety & Sovereignty - ABSOLUTE
-        if let Some(violation) = self.master_pair.check_law_1(&action) {
-            self.log_violation(action, violation.clone());
-            return Verdict::Rejected(violation);
+        self.rule_action(Cow::Owned(action), true)
+    }
+
+    /// Like [`rule`](Self::rule) for callers that keep the action. It is
+    /// copied once, when the ledger records it, rather than up front.
+    pub fn rule_ref(&self, action: &SystemAction) -> Verdict {
+        self.rule_action(Cow::Borrowed(action), true)
+    }
+
+    /// Like [`rule_ref`](Self::rule_ref) for a [`Court`](crate::Court)
+    /// that escalates quarantines: the action is recorded as quarantined
+    /// but left for the parent court to queue for review.
+    pub(crate) fn rule_for_escalation(&self, action: &SystemAction) -> Verdict {
+        self.rule_action(Cow::Borrowed(action), false)
+    }
+
+    fn rule_action(&self, action: Cow<'_, SystemAction>, review: bool) -> Verdict {
+        let _in_flight = self.in_flight.enter();
+        let mut ruling = self.screen(action);
+        ruling.review = review;
+        self.conclude(ruling)
+    }
+
+    /// Rule on several actions. With the `parallel` feature, laws (or the
+    /// jury) weigh the actions concurrently; identity, rate limits, the
+    /// ledger, reviews and observers still handle them in order, so the
+    /// ledger reads as if each action had been passed to
+    /// [`rule`](Self::rule) in turn.
+    pub fn rule_batch(&self, actions: &[SystemAction]) -> Vec<Verdict> {
+        let _in_flight = self.in_flight.enter();
+        let screened: Vec<Ruling<'_>> = actions.iter()
+            .map(|action| self.screen(Cow::Borrowed(action)))
+            .collect();
+
+        #[cfg(feature = "parallel")]
+        let decided: Vec<Ruling<'_>> = screened.into_par_iter().map(|ruling| self.decide(ruling)).collect();
+        #[cfg(not(feature = "parallel"))]
+        let decided: Vec<Ruling<'_>> = screened.into_iter().map(|ruling| self.decide(ruling)).collect();
+
+        // One ledger hand-off for the whole batch, so group commit can put
+        // it in as few groups as possible.
+        let mut records = Vec::with_capacity(actions.len());
+        let mut settled: Vec<Settled<'_>> = decided.into_iter().map(|ruling| self.settle(ruling, &mut records)).collect();
+        if let Err(error) = self.ledger.append_all(records) {
+            settled.iter_mut().for_each(|settled| settled.unpersisted(&error));
+        }
+        settled.into_iter().map(|settled| self.notify(settled)).collect()
+    }
+
+    /// The checks that must see actions in submission order: shutdown,
+    /// identity, rate limits and payload schemas.
+    fn screen<'a>(&self, mut action: Cow<'a, SystemAction>) -> Ruling<'a> {
+        let span = info_span!(
+            "rule",
+            action_type = %action.action_type,
+            profile = tracing::field::Empty,
+            law = tracing::field::Empty,
+            verdict = tracing::field::Empty,
+        );
+        let started = Instant::now();
+
+        if self.shut_down.load(Ordering::SeqCst) {
+            span.record("verdict", "REJECTED");
+            span.in_scope(|| warn!("ruling refused after shutdown"));
+            return Ruling {
+                span,
+                started,
+                action_type: Cow::Borrowed(""),
+                observed: None,
+                review: true,
+                stage: Stage::Refused(Verdict::Rejected("Judicial core is shut down".into())),
+            };
+        }
+
+        let identified = self.identify(&mut action);
+        // The ledger takes the action; keep what is needed afterwards,
+        // borrowing from the caller where possible.
+        let (action_type, observed) = match &action {
+            Cow::Borrowed(borrowed) => (
+                Cow::Borrowed(borrowed.action_type.as_str()),
+                (!self.observers.is_empty()).then_some(Cow::Borrowed(*borrowed)),
+            ),
+            Cow::Owned(owned) => (
+                Cow::Owned(owned.action_type.clone()),
+                (!self.observers.is_empty()).then(|| Cow::Owned(owned.clone())),
+            ),
+        };
+
+        let stage = span.in_scope(|| {
+            let (verdict, record) = if let Err(reason) = identified {
+                warn!(%reason, "credentials rejected");
+                (Verdict::Rejected(reason.clone()), LedgerRecord::Violation { action: action.into_owned(), reason })
+            } else if let Err(reason) = self.rate_limiter.check(&action, self.clock.now()) {
+                warn!(%reason, "action throttled");
+                (Verdict::Rejected(reason.clone()), LedgerRecord::Violation { action: action.into_owned(), reason })
+            } else if let Err(reason) = self.check_payload(&action) {
+                let verdict = Verdict::Malformed(reason);
+                warn!("malformed payload rejected");
+                let record = LedgerRecord::Ruling {
+                    action: action.into_owned(),
+                    verdict: verdict.clone(),
+                    law: None,
+                    violations: Vec::new(),
+                };
+                (verdict, record)
+            } else {
+                return Stage::Admitted(action);
+            };
+            span.record("verdict", verdict.label());
+            Stage::Decided(Decision { verdict, law: None, record: Record::Ledger(record) })
+        });
+
+        Ruling { span, started, action_type, observed, review: true, stage }
+    }
+
+    /// Weigh an admitted action. Touches no shared state but the laws, so
+    /// batches may run it concurrently.
+    fn decide<'a>(&self, mut ruling: Ruling<'a>) -> Ruling<'a> {
+        if let Stage::Admitted(action) = ruling.stage {
+            ruling.stage = Stage::Decided(ruling.span.in_scope(|| self.deliberate(action, &ruling.span)));
+        }
+        ruling
+    }
+
+    fn deliberate(&self, action: Cow<'_, SystemAction>, span: &Span) -> Decision {
+        let decision = if let Some(jury) = &self.jury {
+            let deliberation = jury.deliberate(&action, self.strictness());
+            info!(dissents = deliberation.dissents.len(), "jury verdict reached");
+            let record = LedgerRecord::Jury {
+                action: action.into_owned(),
+                verdict: deliberation.verdict.clone(),
+                dissents: deliberation.dissents,
+            };
+            let record = match &deliberation.verdict {
+                Verdict::Quarantined(reason) => Record::Quarantine { record, reason: reason.clone() },
+                _ => Record::Ledger(record),
+            };
+            Decision { verdict: deliberation.verdict, law: None, record }
+        } else {
+            self.rule_by_laws(action, span)
+        };
+        span.record("verdict", decision.verdict.label());
+        decision
+    }
+
+    /// Record the ruling: ledger, review queue, statistics and observers.
+    fn conclude(&self, ruling: Ruling<'_>) -> Verdict {
+        let mut records = Vec::with_capacity(1);
+        let mut settled = self.settle(ruling, &mut records);
+        if let Err(error) = self.ledger.append_all(records) {
+            settled.unpersisted(&error);
+        }
+        self.notify(settled)
+    }
+
+    /// Everything but the ledger append, which is left in `records`, and
+    /// the observers, which should only hear of the ruling once it is
+    /// in the ledger.
+    fn settle<'a>(&self, ruling: Ruling<'a>, records: &mut Vec<LedgerRecord>) -> Settled<'a> {
+        let guard = ruling.span.enter();
+        let Decision { verdict, law, record } = match ruling.stage {
+            Stage::Refused(verdict) => {
+                drop(guard);
+                return Settled { span: ruling.span, verdict, law: None, observed: None };
+            }
+            Stage::Admitted(action) => self.deliberate(action, &ruling.span),
+            Stage::Decided(decision) => decision,
+        };
+
+        let ledger_record = match &record {
+            Record::Ledger(record) | Record::Quarantine { record, .. } => record,
+        };
+        let cited: Vec<u32> = law.into_iter()
+            .chain(ledger_record.violations().iter().map(|v| v.law_number))
+            .collect();
+
+        match record {
+            Record::Ledger(record) => records.push(record),
+            Record::Quarantine { record, reason } => {
+                // The review references the quarantine entry, so it and
+                // everything before it go in now.
+                self.quarantine(std::mem::take(records), record, reason, ruling.review)
+            }
+        }
+
+        self.stats.record(&ruling.action_type, &verdict, &cited, ruling.started.elapsed());
+        drop(guard);
+        Settled { span: ruling.span, verdict, law, observed: ruling.observed }
+    }
+
+    fn notify(&self, settled: Settled<'_>) -> Verdict {
+        if let Some(action) = settled.observed {
+            let _guard = settled.span.enter();
+            for observer in &self.observers {
+                observer.on_ruling(&action, &settled.verdict, settled.law);
+            }
+        }
+        settled.verdict
+    }
+
+    /// Drop cached evaluations after a change to laws or strictness. Call
+    /// once the new policy is in place.
+    fn policy_changed(&self) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate();
+        }
+    }
+
+    fn policy(&self) -> Arc<Policy> {
+        Arc::clone(&self.policy.read().unwrap())
+    }
+
+    /// Reload the plugin directory given to the builder and put its laws in
+    /// force after the builder's own. On error the current laws stay. Every
+    /// reload is recorded in the ledger; fails after
+    /// [`shutdown`](Self::shutdown). Returns the number of plugin laws.
+    #[cfg(feature = "plugins")]
+    pub fn reload_plugins(&self) -> Result<usize, PluginError> {
+        let Some(plugins) = &self.law_plugins else {
+            return Ok(0);
+        };
+        if self.is_shut_down() {
+            return Err(PluginError::ShutDown);
+        }
+        let loaded = plugins.load()?;
+        let count = loaded.len();
+        let numbers: Vec<u32> = loaded.iter().map(|law| law.number()).collect();
+        let change = format!("{} laws from {}: {:?}", count, plugins.dir().display(), numbers);
+        info!(%change, "law plugins reloaded");
+
+        let mut policy = self.policy.write().unwrap();
+        let laws = Arc::new(with_plugins(self.base_laws.clone(), loaded));
+        *policy = Arc::new(Policy { laws, ..Policy::clone(&policy) });
+        self.policy_changed();
+        let mut ledger = self.ledger.write();
+        ledger.record_policy_change("plugins", change.clone());
+        drop(ledger);
+        drop(policy);
+
+        for observer in &self.observers {
+            observer.on_policy_change("plugins", &change);
+        }
+        Ok(count)
+    }
+
+    /// Put a [`PolicyPack`] in force on a running core. The pack selects
+    /// from the laws currently in force and applies on top of the current
+    /// policy, as [`JudicialCoreBuilder::policy_pack`] does on top of the
+    /// builder. All of it is checked first; on error nothing changes.
+    /// Rulings see either the old policy or the new one, never a mix. Every
+    /// pack is recorded in the ledger; fails after
+    /// [`shutdown`](Self::shutdown).
+    ///
+    /// With plugins, a later [`reload_plugins`](Self::reload_plugins)
+    /// starts again from the builder's laws.
+    pub fn apply_policy_pack(&self, pack: &PolicyPack) -> Result<(), PolicyPackError> {
+        let mut policy = self.policy.write().unwrap();
+        if self.is_shut_down() {
+            return Err(PolicyPackError::ShutDown);
+        }
+        let resolved = pack.resolve(&policy.laws)?;
+
+        let mut next = Policy::clone(&policy);
+        next.laws = Arc::new(resolved.laws);
+        if let Some(strictness) = resolved.strictness {
+            next.strictness = strictness;
+        }
+        for action_type in resolved.known_action_types {
+            next.action_types.declare(action_type);
+        }
+        if let Some(unknown) = resolved.unknown_action_policy {
+            next.action_types.set_policy(unknown);
+        }
+        for (name, profile) in resolved.profiles {
+            next.profiles.insert(name, profile);
+        }
+
+        let numbers: Vec<u32> = next.laws.iter().map(|law| law.number()).collect();
+        let profiles: Vec<&str> = next.profiles.names().collect();
+        let change = format!(
+            "laws {:?}, strictness {}, unknown action types {:?}, profiles {:?}",
+            numbers, next.strictness, next.action_types.policy(), profiles
+        );
+        info!(%change, "policy pack applied");
+
+        *policy = Arc::new(next);
+        self.policy_changed();
+        let mut ledger = self.ledger.write();
+        ledger.record_policy_change("policy_pack", change.clone());
+        drop(ledger);
+        drop(policy);
+
+        for observer in &self.observers {
+            observer.on_policy_change("policy_pack", &change);
+        }
+        Ok(())
+    }
+
+    /// Read a TOML [`PolicyPack`] from `path` and put it in force; see
+    /// [`apply_policy_pack`](Self::apply_policy_pack).
+    pub fn load_policy_pack(&self, path: impl AsRef<Path>) -> Result<(), PolicyPackError> {
+        self.apply_policy_pack(&PolicyPack::load(path)?)
+    }
+
+    fn rule_by_laws(&self, action: Cow<'_, SystemAction>, span: &Span) -> Decision {
+        // Taken before the policy is read, so a change racing this ruling
+        // keeps its evaluation out of the cache.
+        let epoch = self.cache.as_ref().map(VerdictCache::epoch);
+        let policy = self.policy();
+        let (profile, laws, strictness) = policy.governing(&action);
+        if let Some(name) = profile {
+            span.record("profile", name);
+        }
+
+        let (mut verdict, law, violations) = match self.cache.as_ref().filter(|_| !laws.is_budgeted()) {
+            Some(cache) => {
+                let key = action_key(&action);
+                let now = self.clock.now();
+                match cache.get(&key, now) {
+                    Some(evaluation) => {
+                        debug!("law evaluation served from cache");
+                        evaluation
+                    }
+                    None => {
+                        let evaluation = laws.evaluate_all(&action, strictness);
+                        cache.insert(key, evaluation.clone(), epoch.unwrap_or_default(), now);
+                        evaluation
+                    }
+                }
+            }
+            None => laws.evaluate_all(&action, strictness),
+        };
+        if let Some(law) = law {
+            span.record("law", law);
+        }
+
+        let known = policy.action_types.is_declared(&action.action_type)
+            && laws.governs(&action.action_type);
+        if verdict.is_approved() && !known {
+            let reason = format!("Unknown action type '{}'", action.action_type);
+            match policy.action_types.policy() {
+                UnknownActionPolicy::FailOpen => {}
+                UnknownActionPolicy::FailClosed => {
+                    warn!(%reason, "unknown action type rejected");
+                    verdict = Verdict::Rejected(reason);
+                }
+                UnknownActionPolicy::Quarantine => {
+                    warn!(%reason, "unknown action type quarantined");
+                    verdict = Verdict::Quarantined(reason);
+                }
+            }
+        }
+        // Reuse earlier human decisions on the same kind of quarantine.
+        if let Verdict::Quarantined(reason) = &verdict {
+            if let Some(precedent) = self.precedents.as_ref()
+                .and_then(|p| p.find(&action.action_type, reason))
+            {
+                let note = format!(
+                    "per review #{} precedent (confidence {:.2} over {} decisions): {}",
+                    precedent.review, precedent.confidence, precedent.decisions, reason
+                );
+                info!(review = precedent.review, decision = ?precedent.decision, "quarantine settled by precedent");
+                verdict = match precedent.decision {
+                    ReviewDecision::Approve => Verdict::ApprovedWithWarning(format!("Approved {}", note)),
+                    ReviewDecision::Reject => Verdict::Rejected(format!("Rejected {}", note)),
+                };
+            }
+        }
+        let record = match &verdict {
+            Verdict::Quarantined(reason) => Record::Quarantine {
+                record: LedgerRecord::Ruling {
+                    action: action.into_owned(),
+                    verdict: verdict.clone(),
+                    law,
+                    violations,
+                },
+                reason: reason.clone(),
+            },
+            _ => Record::Ledger(LedgerRecord::Ruling {
+                action: action.into_owned(),
+                verdict: verdict.clone(),
+                law,
+                violations,
+            }),
+        };
+        Decision { verdict, law, record }
+    }
+
+    /// Ledger a quarantine, after the `earlier` records of its batch, and
+    /// hand the action to human review.
+    fn quarantine(&self, earlier: Vec<LedgerRecord>, record: LedgerRecord, reason: String, review: bool) {
+        let action = record.action().clone();
+        let mut ledger = self.ledger.write();
+        for earlier in earlier {
+            earlier.apply(&mut ledger);
+        }
+        record.apply(&mut ledger);
+        let hash = ledger.entries().last().map(|e| e.hash.clone());
+        drop(ledger);
+        if !review {
+            info!("quarantined action left to the parent court");
+            return;
+        }
+
+        match self.reviews.submit(action, reason, hash, self.clock.now()) {
+            Ok(id) => {
+                info!(review = id, "action queued for human review");
+                let wanted = !self.observers.is_empty() || self.approvals.is_some();
+                if let Some(item) = self.reviews.get(id).filter(|_| wanted) {
+                    for observer in &self.observers {
+                        observer.on_review_required(&item);
+                    }
+                    if let Some(approvals) = &self.approvals {
+                        approvals.request(&item);
+                    }
+                }
+            }
+            Err(e) => error!(error = %e, "quarantined action not queued for review"),
+        }
+    }
+
+    /// Quarantined actions and their review status.
+    pub fn reviews(&self) -> &ReviewQueue {
+        &self.reviews
+    }
+
+    /// Verify `credentials` with the installed [`IdentityProvider`], for
+    /// callers that act on behalf of someone other than an action's actor,
+    /// such as reviewers. Fails when no provider is installed.
+    pub fn verify_identity(&self, credentials: &str) -> Result<Identity, IdentityError> {
+        match &self.identity {
+            Some(provider) => provider.verify(credentials),
+            None => Err(IdentityError("no identity provider installed".into())),
+        }
+    }
+
+    pub fn claim_review(&self, id: ReviewId, reviewer: &str) -> Result<ReviewItem, ReviewError> {
+        self.reviews.claim(id, reviewer, self.clock.now())
+    }
+
+    /// Resolve a claimed review and record the decision in the ledger,
+    /// referencing the entry that quarantined the action.
+    pub fn resolve_review(
+        &self,
+        id: ReviewId,
+        decision: ReviewDecision,
+        rationale: &str,
+    ) -> Result<ReviewItem, ReviewError> {
+        let item = self.reviews.resolve(id, decision, rationale, self.clock.now())?;
+        info!(review = id, ?decision, "human review resolved");
+        self.sla_warned.lock().unwrap().remove(&id);
+        self.ledger.write().record_review_decision(&item);
+        if let Some(precedents) = &self.precedents {
+            precedents.record(&item);
+        }
+        Ok(item)
+    }
+
+    /// Claim and resolve a review in one step, for decisions pushed by an
+    /// [`ApprovalService`] or another system acting as `reviewer`.
+    pub fn receive_decision(
+        &self,
+        id: ReviewId,
+        reviewer: &str,
+        decision: ReviewDecision,
+        rationale: &str,
+    ) -> Result<ReviewItem, ReviewError> {
+        self.claim_review(id, reviewer)?;
+        let item = self.resolve_review(id, decision, rationale)?;
+        if let Some(approvals) = &self.approvals {
+            approvals.forget(id);
+        }
+        Ok(item)
+    }
+
+    /// Ask the [`ApprovalService`] about every review handed to it, and
+    /// apply the decisions it has reached. Reviews whose approval request
+    /// failed are requested again. Call periodically; returns the items
+    /// resolved.
+    pub fn poll_approvals(&self) -> Vec<ReviewItem> {
+        let Some(approvals) = &self.approvals else {
+            return Vec::new();
+        };
+
+        let mut resolved = Vec::new();
+        for (id, ticket) in approvals.tracked() {
+            let Some(item) = self.reviews.get(id) else {
+                approvals.forget(id);
+                continue;
+            };
+            if matches!(item.status, ReviewStatus::Resolved { .. }) {
+                approvals.forget(id);
+                continue;
+            }
+            let Some(ticket) = ticket else {
+                approvals.request(&item);
+                continue;
+            };
+
+            match approvals.status(&ticket) {
+                Ok(ApprovalStatus::Pending) => {}
+                Ok(ApprovalStatus::Decided { decision, reviewer, rationale }) => {
+                    match self.receive_decision(id, &reviewer, decision, &rationale) {
+                        Ok(item) => resolved.push(item),
+                        Err(e) => {
+                            warn!(review = id, %ticket, error = %e, "external decision not applied");
+                            approvals.forget(id);
+                        }
+                    }
+                }
+                Err(e) => warn!(review = id, %ticket, error = %e, "approval status unavailable"),
+            }
+        }
+        resolved
+    }
+
+    /// Settle reviews that have waited longer than the configured
+    /// [`ReviewSla`] using its fallback. Call periodically; returns the
+    /// items settled. Timeouts are ledgered but do not become precedents.
+    ///
+    /// Observers get an [`SlaStage::Breached`] alert for each settled review
+    /// and, when the SLA sets `warn_before`, one [`SlaStage::Approaching`]
+    /// alert per review as its deadline nears.
+    pub fn expire_reviews(&self) -> Vec<ReviewItem> {
+        let Some(sla) = &self.review_sla else {
+            return Vec::new();
+        };
+        let Ok(timeout) = chrono::Duration::from_std(sla.timeout) else {
+            return Vec::new();
+        };
+        let warn_before = sla.warn_before
+            .and_then(|before| chrono::Duration::from_std(before).ok())
+            .unwrap_or_else(chrono::Duration::zero);
+
+        let now = self.clock.now();
+        let mut expired = Vec::new();
+        for item in self.reviews.pending() {
+            if item.submitted_at > now - timeout {
+                if item.submitted_at <= now - timeout + warn_before
+                    && self.sla_warned.lock().unwrap().insert(item.id)
+                {
+                    let alert = sla.alert(SlaStage::Approaching, &item);
+                    warn!(review = item.id, deadline = %alert.deadline, "review nearing its deadline");
+                    for observer in &self.observers {
+                        observer.on_review_sla(&alert);
+                    }
+                }
+                continue;
+            }
+
+            let (decision, rationale) = match &sla.fallback {
+                ReviewFallback::Reject => (
+                    ReviewDecision::Reject,
+                    format!("no decision within {}s; rejected by fallback", sla.timeout.as_secs()),
+                ),
+                ReviewFallback::Escalate(court) => {
+                    let ruling = court.rule(item.action.clone());
+                    let decision = if ruling.verdict.is_approved() {
+                        ReviewDecision::Approve
+                    } else {
+                        ReviewDecision::Reject
+                    };
+                    (decision, format!(
+                        "no decision within {}s; escalated to {}: {}",
+                        sla.timeout.as_secs(), ruling.decided_by, ruling.verdict.label()
+                    ))
+                }
+            };
+
+            let alert = sla.alert(SlaStage::Breached, &item);
+            if let Ok(item) = self.reviews.expire(item.id, decision, &rationale, now) {
+                warn!(review = item.id, ?decision, "review timed out");
+                self.ledger.write().record_review_decision(&item);
+                self.sla_warned.lock().unwrap().remove(&item.id);
+                for observer in &self.observers {
+                    observer.on_review_sla(&alert);
+                }
+                expired.push(item);
+            }
+        }
+        expired
+    }
+
+    /// Stop accepting rulings, wait for in-flight rulings to finish, append
+    /// a terminal SHUTDOWN entry and close the ledger backend.
+    ///
+    /// Rulings requested afterwards are rejected without being recorded.
+    /// Rulings still in flight after the builder's
+    /// [`shutdown_timeout`](JudicialCoreBuilder::shutdown_timeout) fail the
+    /// call with [`io::ErrorKind::TimedOut`], naming how many; the ledger
+    /// then stays open and calling `shutdown` again waits for them again.
+    /// Once it has succeeded, calling `shutdown` again is a no-op.
+    pub fn shutdown(&self) -> io::Result<()> {
+        self.shut_down.store(true, Ordering::SeqCst);
+        let mut closed = self.closed.lock().unwrap();
+        if *closed {
+            return Ok(());
         }
 
-        // Law 2: Improvement & Integrity - STRICT  
-        if let Some(violation) = self.master_pair.check_law_2(&action) {
-            self.log_violation(action, violation.clone());
-            return Verdict::RejectedWithSuggestion(
-                violation, 
-                "Provide rollback mechanism or sandbox execution.".into()
-            );
+        let in_flight = self.in_flight.wait_idle(self.shutdown_timeout);
+        if in_flight > 0 {
+            warn!(in_flight, "rulings still in flight at the shutdown deadline");
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("{} rulings still in flight after {:?}", in_flight, self.shutdown_timeout),
+            ));
         }
 
-        // Action is lawful
-        self.log_approval(action);
-        Verdict::Approved
+        info!("judicial core shutting down");
+        *closed = true;
+        let mut ledger = self.ledger.write();
+        ledger.record_shutdown();
+        ledger.close()
+    }
+
+    pub fn is_shut_down(&self) -> bool {
+        self.shut_down.load(Ordering::SeqCst)
+    }
+
+    /// Recompute the ledger's hash chain.
+    pub fn verify_ledger(&self) -> Result<(), IntegrityError> {
+        let ledger = self.ledger.read();
+        let result = ledger.verify_integrity();
+        drop(ledger);
+
+        if let Err(error) = &result {
+            self.report_tampering(error);
+        }
+        result
+    }
+
+    fn report_tampering(&self, error: &IntegrityError) {
+        error!(%error, "ledger integrity check failed");
+        for observer in &self.observers {
+            observer.on_integrity_failure(error);
+        }
+    }
+
+    /// One-call trust check for orchestrators: ledger integrity, law set
+    /// consistency and operational state.
+    pub fn health(&self) -> HealthReport {
+        let policy = self.policy();
+        let laws = &policy.laws;
+        let mut law_issues = Vec::new();
+        if laws.is_empty() && self.jury.is_none() {
+            law_issues.push("no laws are active; every action is approved".to_string());
+        }
+        for number in laws.duplicate_numbers() {
+            law_issues.push(format!("law {} is defined more than once", number));
+        }
+        for (name, profile) in policy.profiles.iter() {
+            if profile.laws.is_empty() {
+                law_issues.push(format!("profile '{}' has no laws", name));
+            }
+            for number in profile.laws.duplicate_numbers() {
+                law_issues.push(format!("law {} is defined more than once in profile '{}'", number, name));
+            }
+        }
+
+        let ledger = self.ledger.read();
+        let ledger_integrity = ledger.verify_integrity().err();
+        let last_policy_change = ledger.entries().iter()
+            .rev()
+            .find(|e| e.action.action_type == "POLICY_CHANGE")
+            .map(|e| e.timestamp);
+        let shut_down = self.is_shut_down();
+        let ledger_entries = ledger.entries().len();
+        drop(ledger);
+
+        if let Some(error) = &ledger_integrity {
+            self.report_tampering(error);
+        }
+
+        HealthReport {
+            healthy: !shut_down && ledger_integrity.is_none() && law_issues.is_empty(),
+            checked_at: self.clock.now(),
+            shut_down,
+            ledger_entries,
+            ledger_integrity,
+            law_issues,
+            active_laws: laws.len(),
+            profiles: policy.profiles.len(),
+            rate_limit_buckets: self.rate_limiter.bucket_count(),
+            pending_reviews: self.reviews.pending_count(),
+            last_policy_change,
+            verdict_cache: self.cache_stats().as_ref().map(CacheHealth::from),
+        }
+    }
+
+    /// Verdict counts per law and action type, ruling latency and throttling
+    /// counters since the core was built.
+    pub fn statistics(&self) -> Statistics {
+        self.stats.snapshot(self.rate_limiter.stats())
+    }
+
+    /// Hits, misses and evictions of the verdict cache, if one is
+    /// configured.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(VerdictCache::stats)
+    }
+
+    pub fn rate_limit_stats(&self) -> RateLimitStats {
+        self.rate_limiter.stats()
     }
 
     pub fn get_compliance_score(&self) -> f64 {
-        let ledger = self.ledger.read().unwrap();
+        let ledger = self.ledger.read();
         ledger.calculate_compliance_score()
     }
 
+    /// Compliance over a window of the ledger, broken down by actor, action
+    /// type and law.
+    pub fn compliance_report(&self, window: ComplianceWindow) -> ComplianceReport {
+        let ledger = self.ledger.read();
+        ledger.compliance_report(window)
+    }
+
+    /// Re-judge ledgered rulings in `window` against the current laws,
+    /// profiles and strictness, and report those that would now go the other
+    /// way. Nothing is recorded. Jury verdicts are skipped, as are
+    /// quarantines and rejections no law accounts for, such as throttling
+    /// and unknown action types. Observers are told about any drift.
+    pub fn replay(&self, window: ComplianceWindow) -> Vec<Divergence> {
+        let ledger = self.ledger.read();
+        let rulings: Vec<LedgerEntry> = window.rulings(ledger.entries(), self.clock.now())
+            .into_iter()
+            .filter(|e| !e.jury && (e.verdict.starts_with("APPROVED") || e.law.is_some()))
+            .cloned()
+            .collect();
+        drop(ledger);
+
+        let policy = self.policy();
+        let divergences: Vec<Divergence> = rulings.into_iter()
+            .filter_map(|entry| {
+                let (_, laws, strictness) = policy.governing(&entry.action);
+                let (current, _) = laws.evaluate(&entry.action, strictness);
+                (current.is_approved() != entry.verdict.starts_with("APPROVED")).then_some(Divergence {
+                    hash: entry.hash,
+                    timestamp: entry.timestamp,
+                    action: entry.action,
+                    recorded: entry.verdict,
+                    current,
+                })
+            })
+            .collect();
+
+        if !divergences.is_empty() {
+            warn!(divergences = divergences.len(), "replay found policy drift");
+            for observer in &self.observers {
+                observer.on_policy_drift(&divergences);
+            }
+        }
+        divergences
+    }
+
+    /// Ledger entries matching `filter`, skipping the first `offset` matches
+    /// and returning at most `limit`.
+    pub fn query_ledger(
+        &self,
+        filter: impl Fn(&LedgerEntry) -> bool,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<LedgerEntry> {
+        let ledger = self.ledger.read();
+        ledger.entries().iter()
+            .filter(|entry| filter(entry))
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
     pub fn export_ledger(&self) -> String {
-        let ledger = self.ledger.read().unwrap();
+        let ledger = self.ledger.read();
         serde_json::to_string_pretty(ledger.entries()).unwrap()
     }
 
-    fn log_violation(&self, action: SystemAction, reason: String) {
-        let mut ledger = self.ledger.write().unwrap();
-        ledger.record_violation(action, reason);
+    /// Replace the action's actor and roles with those its credentials
+    /// prove, and strip the credentials.
+    /// Borrowed actions are copied only if they carry credentials or roles.
+    fn identify(&self, action: &mut Cow<'_, SystemAction>) -> Result<(), String> {
+        if action.credentials.is_none() && action.roles.is_empty() {
+            return Ok(());
+        }
+        let action = action.to_mut();
+        action.roles.clear();
+        let Some(credentials) = action.credentials.take() else {
+            return Ok(());
+        };
+        let Some(provider) = &self.identity else {
+            return Ok(());
+        };
+        let identity = provider.verify(&credentials).map_err(|e| e.to_string())?;
+        action.actor = Some(identity.actor);
+        action.roles = identity.roles;
+        Ok(())
+    }
+
+    #[cfg(feature = "schemas")]
+    fn check_payload(&self, action: &SystemAction) -> Result<(), String> {
+        self.schemas.validate(action)
+    }
+
+    #[cfg(not(feature = "schemas"))]
+    fn check_payload(&self, _action: &SystemAction) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Laws, strictness, profiles and action types: everything a ruling reads
+/// that a running core may change.
+#[derive(Debug, Clone)]
+pub(crate) struct Policy {
+    laws: Arc<LawSet>,
+    strictness: Strictness,
+    profiles: PolicyProfiles,
+    action_types: ActionTypes,
+}
+
+impl Policy {
+    /// The laws and strictness that govern `action`, and the profile they
+    /// come from.
+    fn governing(&self, action: &SystemAction) -> (Option<&str>, &LawSet, Strictness) {
+        match self.profiles.select(action) {
+            Some((name, profile)) => (Some(name), &profile.laws, profile.strictness.unwrap_or(self.strictness)),
+            None => (None, &self.laws, self.strictness),
+        }
+    }
+}
+
+#[cfg(feature = "plugins")]
+fn with_plugins(mut laws: LawSet, plugins: Vec<PluginLaw>) -> LawSet {
+    for law in plugins {
+        laws.push(law);
+    }
+    laws
+}
+
+/// An action on its way through [`JudicialCore::rule_action`] or
+/// [`JudicialCore::rule_batch`].
+struct Ruling<'a> {
+    span: Span,
+    started: Instant,
+    action_type: Cow<'a, str>,
+    /// For observers, if there are any.
+    observed: Option<Cow<'a, SystemAction>>,
+    /// Whether a quarantine is queued for review here.
+    review: bool,
+    stage: Stage<'a>,
+}
+
+enum Stage<'a> {
+    /// Not ruled on, nor recorded.
+    Refused(Verdict),
+    /// Passed screening, awaiting the laws or the jury.
+    Admitted(Cow<'a, SystemAction>),
+    Decided(Decision),
+}
+
+/// A ruling handed to the ledger, awaiting its observers.
+struct Settled<'a> {
+    span: Span,
+    verdict: Verdict,
+    law: Option<u32>,
+    observed: Option<Cow<'a, SystemAction>>,
+}
+
+impl Settled<'_> {
+    /// The ledger could not persist the ruling: fail closed, so nothing is
+    /// approved without a durable record.
+    fn unpersisted(&mut self, error: &str) {
+        if self.verdict.is_approved() {
+            self.span.in_scope(|| warn!(%error, "approval withdrawn, ruling not persisted"));
+            self.span.record("verdict", "REJECTED");
+            self.verdict = Verdict::Rejected(format!("Ruling not persisted to the ledger: {}", error));
+        }
+    }
+}
+
+/// A verdict and what is to be recorded for it.
+struct Decision {
+    verdict: Verdict,
+    law: Option<u32>,
+    record: Record,
+}
+
+enum Record {
+    Ledger(LedgerRecord),
+    /// Ledgered, then submitted for human review.
+    Quarantine { record: LedgerRecord, reason: String },
+}
+
+/// Rulings under way, for [`JudicialCore::shutdown`] to wait on.
+#[derive(Debug, Default)]
+struct InFlight {
+    count: AtomicUsize,
+    lock: Mutex<()>,
+    idle: Condvar,
+}
+
+impl InFlight {
+    fn enter(&self) -> Entered<'_> {
+        self.count.fetch_add(1, Ordering::SeqCst);
+        Entered(self)
+    }
+
+    /// Wait up to `timeout` for the count to reach zero. Returns the count
+    /// left at the deadline.
+    fn wait_idle(&self, timeout: Duration) -> usize {
+        let deadline = Instant::now() + timeout;
+        let mut lock = self.lock.lock().unwrap();
+        loop {
+            let count = self.count.load(Ordering::SeqCst);
+            let now = Instant::now();
+            if count == 0 || now >= deadline {
+                return count;
+            }
+            lock = self.idle.wait_timeout(lock, deadline - now).unwrap().0;
+        }
     }
+}
+
+/// Counts a ruling as in flight until dropped.
+struct Entered<'a>(&'a InFlight);
 
-    fn log_approval(&self, action: SystemAction) {
-        let mut ledger = self.ledger.write().unwrap();
-        ledger.record_approval(action);
+impl Drop for Entered<'_> {
+    fn drop(&mut self) {
+        if self.0.count.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // Taking the lock orders this wake-up after a waiter's check.
+            let _lock = self.0.lock.lock().unwrap();
+            self.0.idle.notify_all();
+        }
     }
 }
 
@@ -64,3 +1147,272 @@ impl Default for JudicialCore {
         Self::new()
     }
 }
+
+/// Fluent configuration for [`JudicialCore`].
+///
+/// Every setting is optional; `JudicialCore::builder().build()` is equivalent
+/// to `JudicialCore::new()`: Master Pair laws, standard strictness, an
+/// in-memory ledger and the system clock.
+#[derive(Debug, Default)]
+pub struct JudicialCoreBuilder {
+    laws: Option<LawSet>,
+    strictness: Strictness,
+    ledger_backend: Option<Box<dyn LedgerBackend>>,
+    write_mode: WriteMode,
+    clock: Option<Arc<dyn Clock>>,
+    observers: Vec<Arc<dyn Observer>>,
+    jury: Option<Jury>,
+    rate_limiter: RateLimiter,
+    profiles: PolicyProfiles,
+    action_types: ActionTypes,
+    #[cfg(feature = "schemas")]
+    schemas: ActionSchemas,
+    precedents: Option<PrecedentPolicy>,
+    review_sla: Option<ReviewSla>,
+    review_store: Option<Box<dyn ReviewStore>>,
+    approval_service: Option<Box<dyn ApprovalService>>,
+    identity_provider: Option<Box<dyn IdentityProvider>>,
+    verdict_cache: Option<(NonZeroUsize, Duration)>,
+    shutdown_timeout: Option<Duration>,
+    #[cfg(feature = "plugins")]
+    law_plugins: Option<(LawPlugins, Vec<PluginLaw>)>,
+}
+
+impl JudicialCoreBuilder {
+    /// Replace the whole law set.
+    pub fn laws(mut self, laws: LawSet) -> Self {
+        self.laws = Some(laws);
+        self
+    }
+
+    /// Append a law to the Master Pair (or to the set given to [`laws`](Self::laws)).
+    pub fn law(mut self, law: impl Law + 'static) -> Self {
+        self.laws
+            .get_or_insert_with(LawSet::master_pair)
+            .push(law);
+        self
+    }
+
+    /// Time-box a law; see [`LawBudget`].
+    pub fn law_budget(mut self, law_number: u32, budget: LawBudget) -> Self {
+        self.laws
+            .get_or_insert_with(LawSet::master_pair)
+            .set_budget(law_number, budget);
+        self
+    }
+
+    pub fn strictness(mut self, strictness: Strictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+
+    pub fn ledger_backend(mut self, backend: impl LedgerBackend + 'static) -> Self {
+        self.ledger_backend = Some(Box::new(backend));
+        self
+    }
+
+    /// Choose how rulings are appended to the ledger. See [`WriteMode`].
+    pub fn write_mode(mut self, write_mode: WriteMode) -> Self {
+        self.write_mode = write_mode;
+        self
+    }
+
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    pub fn observer(mut self, observer: impl Observer + 'static) -> Self {
+        self.observers.push(Arc::new(observer));
+        self
+    }
+
+    /// Decide every action by jury instead of the law set. Dissenting
+    /// opinions are recorded on the ledger entry.
+    pub fn jury(mut self, jury: Jury) -> Self {
+        self.jury = Some(jury);
+        self
+    }
+
+    /// Throttle rulings. Actions over the limit are rejected with a
+    /// "rate limit" reason before any law is evaluated. Per-actor scopes
+    /// count verified actors only with an
+    /// [`identity_provider`](Self::identity_provider) installed.
+    pub fn rate_limit(mut self, scope: RateLimitScope, limit: RateLimit) -> Self {
+        self.rate_limiter.limit(scope, limit);
+        self
+    }
+
+    /// Govern actions whose context contains `name` with a profile's own
+    /// laws and strictness. Registering a name again replaces its profile.
+    pub fn profile(mut self, name: impl Into<String>, profile: PolicyProfile) -> Self {
+        self.profiles.insert(name, profile);
+        self
+    }
+
+    /// Declare the action types this deployment expects. Once any are
+    /// declared, all others are unknown.
+    pub fn known_action_types<I, S>(mut self, action_types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        for action_type in action_types {
+            self.action_types.declare(action_type);
+        }
+        self
+    }
+
+    /// Verdict for unknown action types, such as default-deny.
+    pub fn unknown_action_policy(mut self, policy: UnknownActionPolicy) -> Self {
+        self.action_types.set_policy(policy);
+        self
+    }
+
+    /// Require payloads of `action_type` to be JSON matching `schema`.
+    #[cfg(feature = "schemas")]
+    pub fn action_schema(
+        mut self,
+        action_type: impl Into<String>,
+        schema: &serde_json::Value,
+    ) -> Result<Self, SchemaError> {
+        self.schemas.register(action_type, schema)?;
+        Ok(self)
+    }
+
+    /// Settle new quarantines by earlier human decisions on the same action
+    /// type and reason once they agree strongly enough.
+    pub fn precedents(mut self, policy: PrecedentPolicy) -> Self {
+        self.precedents = Some(policy);
+        self
+    }
+
+    /// Deadline for human review of quarantined actions, enforced by
+    /// [`JudicialCore::expire_reviews`].
+    pub fn review_sla(mut self, sla: ReviewSla) -> Self {
+        self.review_sla = Some(sla);
+        self
+    }
+
+    /// Hand every review to an external approval system as well.
+    pub fn approval_service(mut self, service: impl ApprovalService + 'static) -> Self {
+        self.approval_service = Some(Box::new(service));
+        self
+    }
+
+    /// Verify the credentials actions carry before ruling on them. Without
+    /// a provider, credentials are discarded and actions have no roles.
+    pub fn identity_provider(mut self, provider: impl IdentityProvider + 'static) -> Self {
+        self.identity_provider = Some(Box::new(provider));
+        self
+    }
+
+    /// Cache what the laws find for up to `capacity` distinct actions, each
+    /// for at most `ttl`. Strictness changes, snapshot restores and plugin
+    /// reloads empty the cache. Rate limits, identity, schemas, the
+    /// unknown-action policy and precedents still apply to every ruling;
+    /// jury rulings and law sets with time budgets are never cached. A
+    /// capacity of zero disables the cache.
+    pub fn verdict_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.verdict_cache = NonZeroUsize::new(capacity).map(|capacity| (capacity, ttl));
+        self
+    }
+
+    /// How long [`JudicialCore::shutdown`] waits for in-flight rulings.
+    /// Defaults to 30 seconds.
+    pub fn shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = Some(timeout);
+        self
+    }
+
+    /// Load laws from the shared libraries in a plugin directory. They
+    /// follow the builder's own laws and are reloaded by
+    /// [`JudicialCore::reload_plugins`].
+    #[cfg(feature = "plugins")]
+    pub fn law_plugins(mut self, plugins: LawPlugins) -> Result<Self, PluginError> {
+        let loaded = plugins.load()?;
+        self.law_plugins = Some((plugins, loaded));
+        Ok(self)
+    }
+
+    /// Where the review queue keeps its items. In memory by default.
+    pub fn review_store(mut self, store: impl ReviewStore + 'static) -> Self {
+        self.review_store = Some(Box::new(store));
+        self
+    }
+
+    /// Install a [`PolicyPack`]. The pack selects from the laws given so
+    /// far (the Master Pair by default). Either all of it applies or, on
+    /// error, none of it does.
+    pub fn policy_pack(mut self, pack: &PolicyPack) -> Result<Self, PolicyPackError> {
+        let installed = self.laws.take().unwrap_or_else(LawSet::master_pair);
+        let resolved = pack.resolve(&installed)?;
+
+        self.laws = Some(resolved.laws);
+        if let Some(strictness) = resolved.strictness {
+            self.strictness = strictness;
+        }
+        for action_type in resolved.known_action_types {
+            self.action_types.declare(action_type);
+        }
+        if let Some(policy) = resolved.unknown_action_policy {
+            self.action_types.set_policy(policy);
+        }
+        for (name, profile) in resolved.profiles {
+            self.profiles.insert(name, profile);
+        }
+        Ok(self)
+    }
+
+    /// Read a TOML [`PolicyPack`] from `path` and install it.
+    pub fn load_policy_pack(self, path: impl AsRef<Path>) -> Result<Self, PolicyPackError> {
+        self.policy_pack(&PolicyPack::load(path)?)
+    }
+
+    pub fn build(self) -> JudicialCore {
+        let backend = self.ledger_backend
+            .unwrap_or_else(|| Box::new(MemoryBackend::default()));
+        let clock = self.clock.unwrap_or_else(|| Arc::new(SystemClock));
+        let laws = self.laws.unwrap_or_else(LawSet::master_pair);
+        #[cfg(feature = "plugins")]
+        let (base_laws, law_plugins, laws) = match self.law_plugins {
+            Some((plugins, loaded)) => (laws.clone(), Some(plugins), with_plugins(laws, loaded)),
+            None => (laws.clone(), None, laws),
+        };
+
+        JudicialCore {
+            policy: RwLock::new(Arc::new(Policy {
+                laws: Arc::new(laws),
+                strictness: self.strictness,
+                profiles: self.profiles,
+                action_types: self.action_types,
+            })),
+            #[cfg(feature = "plugins")]
+            base_laws,
+            #[cfg(feature = "plugins")]
+            law_plugins,
+            ledger: LedgerWriter::new(
+                TamperProofLedger::with_backend(backend, Arc::clone(&clock)),
+                self.write_mode,
+            ),
+            observers: self.observers,
+            jury: self.jury,
+            rate_limiter: self.rate_limiter,
+            clock,
+            #[cfg(feature = "schemas")]
+            schemas: self.schemas,
+            reviews: self.review_store.map(ReviewQueue::with_store).unwrap_or_default(),
+            precedents: self.precedents.map(Precedents::new),
+            review_sla: self.review_sla,
+            approvals: self.approval_service.map(Approvals::new),
+            identity: self.identity_provider,
+            cache: self.verdict_cache.map(|(capacity, ttl)| VerdictCache::new(capacity, ttl)),
+            sla_warned: Mutex::default(),
+            stats: StatsCollector::default(),
+            shut_down: AtomicBool::new(false),
+            closed: Mutex::new(false),
+            shutdown_timeout: self.shutdown_timeout.unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT),
+            in_flight: InFlight::default(),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use crate::laws::LawSet;
+use crate::strictness::Strictness;
+use crate::verdicts::{SystemAction, Verdict};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+
+/// An independent opinion on an action.
+pub trait Evaluator: fmt::Debug + Send + Sync {
+    fn name(&self) -> &str;
+
+    fn evaluate(&self, action: &SystemAction, strictness: Strictness) -> Verdict;
+}
+
+/// Evaluates with a [`LawSet`], exactly as a [`JudicialCore`](crate::JudicialCore) would.
+#[derive(Debug, Clone)]
+pub struct LawSetEvaluator {
+    name: String,
+    laws: LawSet,
+}
+
+impl LawSetEvaluator {
+    pub fn new(name: impl Into<String>, laws: LawSet) -> Self {
+        Self { name: name.into(), laws }
+    }
+}
+
+impl Evaluator for LawSetEvaluator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn evaluate(&self, action: &SystemAction, strictness: Strictness) -> Verdict {
+        self.laws.evaluate(action, strictness).0
+    }
+}
+
+/// How many jurors must vote to approve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VotingRule {
+    Unanimous,
+    Majority,
+    AtLeast(usize),
+    /// Approving jurors must hold at least this percentage of the total
+    /// juror weight. See [`Jury::weighted_juror`].
+    WeightedShare(u32),
+}
+
+impl VotingRule {
+    fn approves(&self, votes: &[(u32, bool)]) -> bool {
+        if votes.is_empty() {
+            return false;
+        }
+
+        let approvals = votes.iter().filter(|(_, approved)| *approved).count();
+        match self {
+            VotingRule::Unanimous => approvals == votes.len(),
+            VotingRule::Majority => approvals * 2 > votes.len(),
+            VotingRule::AtLeast(required) => approvals >= *required,
+            VotingRule::WeightedShare(percent) => {
+                let total: u64 = votes.iter().map(|(weight, _)| u64::from(*weight)).sum();
+                let approving: u64 = votes.iter()
+                    .filter(|(_, approved)| *approved)
+                    .map(|(weight, _)| u64::from(*weight))
+                    .sum();
+                total > 0 && approving * 100 >= u64::from(*percent) * total
+            }
+        }
+    }
+}
+
+/// Outcome of a jury deliberation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Deliberation {
+    pub verdict: Verdict,
+    /// Every juror's vote, in the order the jurors were added.
+    pub votes: Vec<(String, Verdict)>,
+    /// Jurors who voted against the final verdict.
+    pub dissents: Vec<String>,
+}
+
+/// A panel of evaluators whose votes are combined by a [`VotingRule`].
+///
+/// An approved jury verdict carries the first warning raised by an approving
+/// juror, if any; a rejected one is the first rejecting juror's verdict.
+#[derive(Debug, Clone)]
+pub struct Jury {
+    rule: VotingRule,
+    jurors: Vec<(Arc<dyn Evaluator>, u32)>,
+}
+
+impl Jury {
+    pub fn new(rule: VotingRule) -> Self {
+        Self { rule, jurors: Vec::new() }
+    }
+
+    pub fn juror(self, juror: impl Evaluator + 'static) -> Self {
+        self.weighted_juror(juror, 1)
+    }
+
+    /// Add a juror whose vote counts `weight` times under
+    /// [`VotingRule::WeightedShare`]. Other rules count heads.
+    pub fn weighted_juror(mut self, juror: impl Evaluator + 'static, weight: u32) -> Self {
+        self.jurors.push((Arc::new(juror), weight));
+        self
+    }
+
+    pub fn voting_rule(&self) -> VotingRule {
+        self.rule
+    }
+
+    pub fn len(&self) -> usize {
+        self.jurors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.jurors.is_empty()
+    }
+
+    pub fn deliberate(&self, action: &SystemAction, strictness: Strictness) -> Deliberation {
+        let votes: Vec<(String, Verdict)> = self.jurors.iter()
+            .map(|(juror, _)| (juror.name().to_string(), juror.evaluate(action, strictness)))
+            .collect();
+
+        let ballots: Vec<(u32, bool)> = self.jurors.iter()
+            .zip(&votes)
+            .map(|((_, weight), (_, verdict))| (*weight, verdict.is_approved()))
+            .collect();
+        let approved = self.rule.approves(&ballots);
+
+        let verdict = if approved {
+            votes.iter()
+                .find(|(_, v)| matches!(v, Verdict::ApprovedWithWarning(_)))
+                .map(|(_, v)| v.clone())
+                .unwrap_or(Verdict::Approved)
+        } else {
+            votes.iter()
+                .find(|(_, v)| !v.is_approved())
+                .map(|(_, v)| v.clone())
+                .unwrap_or_else(|| Verdict::Rejected("Jury did not reach the required votes".into()))
+        };
+
+        let dissents = votes.iter()
+            .filter(|(_, v)| v.is_approved() != approved)
+            .map(|(name, v)| format!("{}: {}", name, crate::ledger::verdict_text(v)))
+            .collect();
+
+        Deliberation { verdict, votes, dissents }
+    }
+}
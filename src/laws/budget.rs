@@ -0,0 +1,237 @@
+use crate::laws::Law;
+use crate::verdicts::SystemAction;
+use serde::{Deserialize, Serialize};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+use tracing::warn;
+use web_time::Instant;
+
+/// Most threads the shared pool starts. Laws that hang keep theirs, so
+/// this bounds what a misbehaving law can cost the process.
+const MAX_WORKERS: usize = 64;
+
+/// What a law that cannot rule in time counts as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TimeoutPolicy {
+    /// Skip the law.
+    FailOpen,
+    /// Treat the law as violated.
+    #[default]
+    FailClosed,
+}
+
+/// Time budget and circuit breaker settings for one law.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LawBudget {
+    pub timeout: Duration,
+    pub on_timeout: TimeoutPolicy,
+    /// Consecutive timeouts that open the circuit.
+    pub trip_after: u32,
+    /// How long an open circuit skips the law before trying it again.
+    pub cooldown: Duration,
+}
+
+impl LawBudget {
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            on_timeout: TimeoutPolicy::default(),
+            trip_after: 3,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+
+    pub fn on_timeout(mut self, policy: TimeoutPolicy) -> Self {
+        self.on_timeout = policy;
+        self
+    }
+
+    pub fn trip_after(mut self, timeouts: u32) -> Self {
+        self.trip_after = timeouts.max(1);
+        self
+    }
+
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+/// Result of running a law under its budget.
+pub(crate) enum LawOutcome {
+    Completed(Option<String>),
+    /// Timed out, panicked, or circuit open.
+    Unavailable(TimeoutPolicy),
+}
+
+type Evaluation = Box<dyn FnOnce() -> Option<String> + Send>;
+
+struct Job {
+    evaluate: Evaluation,
+    reply: SyncSender<Option<String>>,
+}
+
+#[derive(Debug, Default)]
+struct PoolCounts {
+    workers: usize,
+    /// Workers neither running nor promised a job.
+    idle: usize,
+}
+
+/// Threads that run budgeted evaluations, shared by every guard in the
+/// process. A worker is started only when none is idle, up to
+/// [`MAX_WORKERS`]; one stuck in a hung law is simply not idle again.
+struct WorkerPool {
+    jobs: Sender<Job>,
+    queue: Arc<Mutex<Receiver<Job>>>,
+    counts: Arc<Mutex<PoolCounts>>,
+}
+
+impl WorkerPool {
+    fn shared() -> &'static WorkerPool {
+        static POOL: OnceLock<WorkerPool> = OnceLock::new();
+        POOL.get_or_init(|| {
+            let (jobs, queue) = mpsc::channel();
+            WorkerPool { jobs, queue: Arc::new(Mutex::new(queue)), counts: Arc::default() }
+        })
+    }
+
+    /// Queue `evaluate`, or give it back if every worker is busy and no
+    /// more may be started.
+    fn submit(&self, evaluate: Evaluation) -> Result<Receiver<Option<String>>, Evaluation> {
+        let mut counts = self.counts.lock().unwrap();
+        if counts.idle == 0 {
+            if counts.workers >= MAX_WORKERS || !self.start_worker(counts.workers) {
+                return Err(evaluate);
+            }
+            counts.workers += 1;
+            counts.idle += 1;
+        }
+        counts.idle -= 1;
+        drop(counts);
+
+        let (reply, receiver) = mpsc::sync_channel(1);
+        if let Err(mpsc::SendError(job)) = self.jobs.send(Job { evaluate, reply }) {
+            self.counts.lock().unwrap().idle += 1;
+            return Err(job.evaluate);
+        }
+        Ok(receiver)
+    }
+
+    fn start_worker(&self, index: usize) -> bool {
+        let queue = Arc::clone(&self.queue);
+        let counts = Arc::clone(&self.counts);
+        let spawned = thread::Builder::new()
+            .name(format!("law-budget-{}", index))
+            .spawn(move || loop {
+                let job = queue.lock().unwrap().recv();
+                let Ok(Job { evaluate, reply }) = job else { return };
+                // A panicking law drops `reply`, which the caller sees as a
+                // failed evaluation; the worker carries on.
+                let result = panic::catch_unwind(AssertUnwindSafe(evaluate));
+                // Idle again before the caller hears back, so a caller that
+                // submits straight away reuses this worker.
+                counts.lock().unwrap().idle += 1;
+                if let Ok(result) = result {
+                    let _ = reply.send(result);
+                }
+            });
+        if let Err(e) = &spawned {
+            warn!(error = %e, "could not start law evaluation thread");
+        }
+        spawned.is_ok()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Breaker {
+    consecutive_timeouts: u32,
+    open_until: Option<Instant>,
+    /// A half-open circuit has let its one trial evaluation through.
+    probing: bool,
+}
+
+/// Enforces a [`LawBudget`]. Budgeted laws run on a shared pool of worker
+/// threads so a hung law cannot stall the ruling; its worker is abandoned
+/// on timeout. Once a circuit's cooldown ends, exactly one evaluation is let
+/// through to probe the law; the rest keep being skipped until it succeeds.
+#[derive(Debug)]
+pub(crate) struct BudgetGuard {
+    budget: LawBudget,
+    breaker: Mutex<Breaker>,
+}
+
+impl BudgetGuard {
+    pub(crate) fn new(budget: LawBudget) -> Self {
+        Self { budget, breaker: Mutex::new(Breaker::default()) }
+    }
+
+    pub(crate) fn run(
+        &self,
+        law: &Arc<dyn Law>,
+        action: &SystemAction,
+        check: fn(&dyn Law, &SystemAction) -> Option<String>,
+    ) -> LawOutcome {
+        let number = law.number();
+        let probe = {
+            let mut breaker = self.breaker.lock().unwrap();
+            match breaker.open_until {
+                Some(until) if Instant::now() < until || breaker.probing => {
+                    return LawOutcome::Unavailable(self.budget.on_timeout);
+                }
+                Some(_) => {
+                    breaker.probing = true;
+                    true
+                }
+                None => false,
+            }
+        };
+
+        let worker_law = Arc::clone(law);
+        let worker_action = action.clone();
+        let result = match WorkerPool::shared().submit(Box::new(move || check(worker_law.as_ref(), &worker_action))) {
+            Ok(receiver) => receiver.recv_timeout(self.budget.timeout).ok(),
+            Err(_) => {
+                warn!(law = number, "no law evaluation thread available");
+                None
+            }
+        };
+
+        let mut breaker = self.breaker.lock().unwrap();
+        match result {
+            Some(result) => {
+                breaker.consecutive_timeouts = 0;
+                if probe {
+                    breaker.open_until = None;
+                    breaker.probing = false;
+                }
+                LawOutcome::Completed(result)
+            }
+            None if probe => {
+                breaker.probing = false;
+                breaker.open_until = Some(Instant::now() + self.budget.cooldown);
+                warn!(law = number, cooldown_s = self.budget.cooldown.as_secs(), "law circuit reopened");
+                LawOutcome::Unavailable(self.budget.on_timeout)
+            }
+            None => {
+                breaker.consecutive_timeouts += 1;
+                warn!(
+                    law = number,
+                    budget_ms = self.budget.timeout.as_millis() as u64,
+                    consecutive = breaker.consecutive_timeouts,
+                    policy = ?self.budget.on_timeout,
+                    "law exceeded its time budget"
+                );
+                if breaker.consecutive_timeouts >= self.budget.trip_after {
+                    breaker.open_until = Some(Instant::now() + self.budget.cooldown);
+                    breaker.consecutive_timeouts = 0;
+                    warn!(law = number, cooldown_s = self.budget.cooldown.as_secs(), "law circuit opened");
+                }
+                LawOutcome::Unavailable(self.budget.on_timeout)
+            }
+        }
+    }
+}
@@ -1,5 +1,37 @@
+use crate::laws::{Law, LawPatterns, Scan};
 use crate::verdicts::SystemAction;
 
+pub(crate) const SENSITIVE_PATTERNS: [&str; 7] = [
+    "password", "ssn", "credit_card", "private_key",
+    "secret", "token", "api_key"
+];
+
+pub(crate) const DESTRUCTIVE_PATTERNS: [&str; 7] = [
+    "drop table", "rm -rf", "delete from", "truncate",
+    "format", "wipe", "erase"
+];
+
+/// Action types the Master Pair governs. Both laws scan every payload, but
+/// only these count as known to an
+/// [`UnknownActionPolicy`](crate::action_types::UnknownActionPolicy).
+pub const GOVERNED_ACTION_TYPES: &[&str] = &[
+    "DATA_READ", "DATA_WRITE", "DATA_ANALYSIS", "DATA_EXPORT",
+    "SYSTEM_CMD", "SYSTEM_SHUTDOWN", "FILE_DELETE",
+];
+
+/// Law 1 patterns: the sensitive ones, then its context flags.
+const SAFETY_PAYLOAD: &[&str] = &SENSITIVE_PATTERNS;
+pub(crate) const SAFETY_CONTEXT: &[&str] = &["encrypted", "audit", "compliance_approved"];
+
+/// Law 2 patterns: the destructive ones followed by the rollback markers.
+const INTEGRITY_PAYLOAD: &[&str] = &[
+    "drop table", "rm -rf", "delete from", "truncate",
+    "format", "wipe", "erase", "backup", "rollback"
+];
+pub(crate) const INTEGRITY_CONTEXT: &[&str] = &["emergency", "backup", "rollback"];
+const BACKUP: usize = DESTRUCTIVE_PATTERNS.len();
+const ROLLBACK: usize = BACKUP + 1;
+
 #[derive(Debug, Clone)]
 pub struct MasterPair;
 
@@ -8,12 +40,7 @@ impl MasterPair {
         // LAW 1: Never compromise user safety or data sovereignty
         
         // Check for plaintext credentials
-        let sensitive_patterns = [
-            "password", "ssn", "credit_card", "private_key", 
-            "secret", "token", "api_key"
-        ];
-        
-        for pattern in &sensitive_patterns {
+        for pattern in &SENSITIVE_PATTERNS {
             if action.payload.contains(pattern) && 
                !action.context.contains("encrypted") &&
                !action.context.contains("audit") {
@@ -34,12 +61,7 @@ impl MasterPair {
         // LAW 2: Continuously improve while maintaining integrity
         
         // Check for destructive actions without rollback
-        let destructive_patterns = [
-            "drop table", "rm -rf", "delete from", "truncate",
-            "format", "wipe", "erase"
-        ];
-        
-        for pattern in &destructive_patterns {
+        for pattern in &DESTRUCTIVE_PATTERNS {
             if action.payload.contains(pattern) && 
                !action.payload.contains("backup") &&
                !action.payload.contains("rollback") {
@@ -55,6 +77,19 @@ impl MasterPair {
 
         None
     }
+
+    pub fn check_warnings(&self, action: &SystemAction) -> Option<String> {
+        // Rollback promises made only in the payload are not explicit context flags
+        for pattern in &DESTRUCTIVE_PATTERNS {
+            if action.payload.contains(pattern) &&
+               !action.context.contains("backup") &&
+               !action.context.contains("rollback") {
+                return Some(format!("Destructive action '{}' relies on undeclared rollback", pattern));
+            }
+        }
+
+        None
+    }
 }
 
 impl Default for MasterPair {
@@ -62,3 +97,102 @@ impl Default for MasterPair {
         MasterPair
     }
 }
+
+/// LAW 1 as a standalone [`Law`].
+#[derive(Debug, Clone, Default)]
+pub struct SafetyLaw;
+
+impl Law for SafetyLaw {
+    fn number(&self) -> u32 {
+        1
+    }
+
+    fn description(&self) -> &str {
+        "Never compromise user safety or data sovereignty"
+    }
+
+    fn is_absolute(&self) -> bool {
+        true
+    }
+
+    fn governs(&self, action_type: &str) -> bool {
+        GOVERNED_ACTION_TYPES.contains(&action_type)
+    }
+
+    fn check(&self, action: &SystemAction) -> Option<String> {
+        MasterPair.check_law_1(action)
+    }
+
+    fn patterns(&self) -> LawPatterns {
+        LawPatterns { payload: SAFETY_PAYLOAD, context: SAFETY_CONTEXT }
+    }
+
+    fn check_scanned(&self, action: &SystemAction, scan: &Scan<'_>) -> Option<String> {
+        if !scan.context(0) && !scan.context(1) {
+            if let Some(index) = scan.first_payload() {
+                return Some(format!("Sensitive data '{}' without proper protection", SENSITIVE_PATTERNS[index]));
+            }
+        }
+        if action.action_type == "DATA_EXPORT" && !scan.context(2) {
+            return Some("Data export without compliance approval".into());
+        }
+        None
+    }
+}
+
+/// LAW 2 as a standalone [`Law`].
+#[derive(Debug, Clone, Default)]
+pub struct IntegrityLaw;
+
+impl Law for IntegrityLaw {
+    fn number(&self) -> u32 {
+        2
+    }
+
+    fn description(&self) -> &str {
+        "Continuously improve capability while maintaining operational integrity"
+    }
+
+    fn suggestion(&self) -> Option<&str> {
+        Some("Provide rollback mechanism or sandbox execution.")
+    }
+
+    fn governs(&self, action_type: &str) -> bool {
+        GOVERNED_ACTION_TYPES.contains(&action_type)
+    }
+
+    fn check(&self, action: &SystemAction) -> Option<String> {
+        MasterPair.check_law_2(action)
+    }
+
+    fn check_warning(&self, action: &SystemAction) -> Option<String> {
+        MasterPair.check_warnings(action)
+    }
+
+    fn patterns(&self) -> LawPatterns {
+        LawPatterns { payload: INTEGRITY_PAYLOAD, context: INTEGRITY_CONTEXT }
+    }
+
+    fn check_scanned(&self, action: &SystemAction, scan: &Scan<'_>) -> Option<String> {
+        if !scan.payload(BACKUP) && !scan.payload(ROLLBACK) {
+            if let Some(pattern) = first_destructive(scan) {
+                return Some(format!("Destructive action '{}' without rollback", pattern));
+            }
+        }
+        if action.action_type == "SYSTEM_SHUTDOWN" && !scan.context(0) {
+            return Some("Non-emergency system shutdown".into());
+        }
+        None
+    }
+
+    fn check_warning_scanned(&self, _action: &SystemAction, scan: &Scan<'_>) -> Option<String> {
+        if scan.context(1) || scan.context(2) {
+            return None;
+        }
+        first_destructive(scan).map(|pattern| format!("Destructive action '{}' relies on undeclared rollback", pattern))
+    }
+}
+
+fn first_destructive(scan: &Scan<'_>) -> Option<&'static str> {
+    (0..DESTRUCTIVE_PATTERNS.len()).find(|&i| scan.payload(i)).map(|i| DESTRUCTIVE_PATTERNS[i])
+}
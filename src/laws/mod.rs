@@ -1,8 +1,308 @@
+pub mod budget;
+pub mod compiled;
 pub mod master_pair;
-pub use master_pair::MasterPair;
+pub use budget::{LawBudget, TimeoutPolicy};
+pub use compiled::{LawPatterns, Scan};
+pub use master_pair::{IntegrityLaw, MasterPair, SafetyLaw, GOVERNED_ACTION_TYPES};
 
-#[derive(Debug)]
+use budget::{BudgetGuard, LawOutcome};
+use compiled::{CompiledLaws, Hits};
+
+use crate::strictness::Strictness;
+use crate::verdicts::{SystemAction, Verdict};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+use tracing::{debug, warn};
+
+/// One law's objection to an action and how it was resolved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct LawViolation {
     pub law_number: u32,
     pub description: String,
+    /// Label of the verdict this violation produced on its own.
+    pub resolution: String,
+}
+
+/// A single enforceable law.
+pub trait Law: fmt::Debug + Send + Sync {
+    fn number(&self) -> u32;
+
+    fn description(&self) -> &str;
+
+    /// Absolute laws are never downgraded by a permissive strictness.
+    fn is_absolute(&self) -> bool {
+        false
+    }
+
+    /// Remedy offered alongside a rejection, if any.
+    fn suggestion(&self) -> Option<&str> {
+        None
+    }
+
+    /// Whether this law has an opinion on `action_type`. Action types no
+    /// active law governs are handled by the core's
+    /// [`UnknownActionPolicy`](crate::action_types::UnknownActionPolicy).
+    /// The Master Pair governs [`GOVERNED_ACTION_TYPES`]; a law that keeps
+    /// this default governs everything, leaving nothing unknown.
+    fn governs(&self, _action_type: &str) -> bool {
+        true
+    }
+
+    fn check(&self, action: &SystemAction) -> Option<String>;
+
+    /// Lawful-but-suspicious findings. Paranoid strictness rejects these.
+    fn check_warning(&self, _action: &SystemAction) -> Option<String> {
+        None
+    }
+
+    /// Substrings this law looks for. A law set finds the patterns of all
+    /// its laws in one pass per action and hands the hits to
+    /// [`check_scanned`](Self::check_scanned).
+    fn patterns(&self) -> LawPatterns {
+        LawPatterns::default()
+    }
+
+    /// [`check`](Self::check) with the declared patterns already located.
+    /// Must agree with `check`, which still runs for budgeted laws.
+    fn check_scanned(&self, action: &SystemAction, _scan: &Scan<'_>) -> Option<String> {
+        self.check(action)
+    }
+
+    /// [`check_warning`](Self::check_warning) with the declared patterns
+    /// already located.
+    fn check_warning_scanned(&self, action: &SystemAction, _scan: &Scan<'_>) -> Option<String> {
+        self.check_warning(action)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LawEntry {
+    law: Arc<dyn Law>,
+    guard: Option<Arc<BudgetGuard>>,
+}
+
+impl LawEntry {
+    /// Budgeted laws run on a helper thread, which gets the action but not
+    /// the scan, so they fall back to `check`.
+    fn run(
+        &self,
+        action: &SystemAction,
+        scan: &Scan<'_>,
+        check: fn(&dyn Law, &SystemAction) -> Option<String>,
+        check_scanned: fn(&dyn Law, &SystemAction, &Scan<'_>) -> Option<String>,
+    ) -> LawOutcome {
+        match &self.guard {
+            Some(guard) => guard.run(&self.law, action, check),
+            None => LawOutcome::Completed(check_scanned(self.law.as_ref(), action, scan)),
+        }
+    }
+}
+
+/// Ordered collection of laws. Laws are evaluated in insertion order.
+#[derive(Debug, Clone, Default)]
+pub struct LawSet {
+    laws: Vec<LawEntry>,
+    /// Built on first evaluation and dropped whenever the laws change.
+    compiled: OnceLock<Arc<CompiledLaws>>,
+}
+
+impl LawSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Law 1 followed by Law 2.
+    pub fn master_pair() -> Self {
+        Self::new().with(SafetyLaw).with(IntegrityLaw)
+    }
+
+    pub fn with(mut self, law: impl Law + 'static) -> Self {
+        self.push(law);
+        self
+    }
+
+    pub fn push(&mut self, law: impl Law + 'static) {
+        self.laws.push(LawEntry { law: Arc::new(law), guard: None });
+        self.compiled = OnceLock::new();
+    }
+
+    /// Give every law numbered `law_number` a time budget.
+    pub fn with_budget(mut self, law_number: u32, budget: LawBudget) -> Self {
+        self.set_budget(law_number, budget);
+        self
+    }
+
+    pub fn set_budget(&mut self, law_number: u32, budget: LawBudget) {
+        for entry in self.laws.iter_mut().filter(|e| e.law.number() == law_number) {
+            entry.guard = Some(Arc::new(BudgetGuard::new(budget)));
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn Law>> {
+        self.laws.iter().map(|entry| &entry.law)
+    }
+
+    pub fn len(&self) -> usize {
+        self.laws.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.laws.is_empty()
+    }
+
+    /// Whether any law runs under a time budget, making its verdicts depend
+    /// on more than the action.
+    pub fn is_budgeted(&self) -> bool {
+        self.laws.iter().any(|entry| entry.guard.is_some())
+    }
+
+    /// Whether any law in the set governs `action_type`.
+    pub fn governs(&self, action_type: &str) -> bool {
+        self.iter().any(|law| law.governs(action_type))
+    }
+
+    /// The laws numbered `numbers`, in that order, keeping their budgets.
+    /// Fails with the first number no law in the set carries.
+    pub fn subset(&self, numbers: &[u32]) -> Result<LawSet, u32> {
+        let mut laws = Vec::new();
+        for &number in numbers {
+            let before = laws.len();
+            laws.extend(self.laws.iter().filter(|e| e.law.number() == number).cloned());
+            if laws.len() == before {
+                return Err(number);
+            }
+        }
+        Ok(LawSet { laws, compiled: OnceLock::new() })
+    }
+
+    /// Law numbers that occur more than once, in ascending order.
+    pub fn duplicate_numbers(&self) -> Vec<u32> {
+        let mut numbers: Vec<u32> = self.iter().map(|law| law.number()).collect();
+        numbers.sort_unstable();
+        let mut duplicates: Vec<u32> = numbers.windows(2)
+            .filter(|pair| pair[0] == pair[1])
+            .map(|pair| pair[0])
+            .collect();
+        duplicates.dedup();
+        duplicates
+    }
+
+    fn compiled(&self) -> &CompiledLaws {
+        self.compiled.get_or_init(|| Arc::new(CompiledLaws::compile(self.iter())))
+    }
+
+    /// Find the declared patterns of every law in `action`.
+    fn scan(&self, action: &SystemAction) -> (&CompiledLaws, Hits) {
+        let compiled = self.compiled();
+        (compiled, compiled.scan(action))
+    }
+
+    /// Rule on an action, returning the verdict and the law that decided it.
+    pub fn evaluate(&self, action: &SystemAction, strictness: Strictness) -> (Verdict, Option<u32>) {
+        let (verdict, law, _) = self.evaluate_all(action, strictness);
+        (verdict, law)
+    }
+
+    /// Like [`evaluate`](Self::evaluate), but checks every law and also
+    /// returns each violation found. A rejection outranks any violation
+    /// downgraded to a warning; when several laws object, the verdict
+    /// lists all of them.
+    pub fn evaluate_all(
+        &self,
+        action: &SystemAction,
+        strictness: Strictness,
+    ) -> (Verdict, Option<u32>, Vec<LawViolation>) {
+        let mut violations = Vec::new();
+        let mut decided_by: Option<(&Arc<dyn Law>, bool)> = None;
+        let (compiled, hits) = self.scan(action);
+
+        for (index, entry) in self.laws.iter().enumerate() {
+            let law = &entry.law;
+            let scan = compiled.view(&hits, index);
+            let violation = match entry.run(
+                action,
+                &scan,
+                |law, action| law.check(action),
+                |law, action, scan| law.check_scanned(action, scan),
+            ) {
+                LawOutcome::Completed(violation) => violation,
+                LawOutcome::Unavailable(TimeoutPolicy::FailOpen) => continue,
+                LawOutcome::Unavailable(TimeoutPolicy::FailClosed) => {
+                    Some(format!("Law {} could not rule within its time budget", law.number()))
+                }
+            };
+
+            if let Some(violation) = violation {
+                let downgraded = !law.is_absolute() && strictness == Strictness::Permissive;
+                let resolution = if downgraded {
+                    warn!(law = law.number(), reason = %violation, "rejection downgraded to warning");
+                    "APPROVED_WITH_WARNING"
+                } else {
+                    warn!(law = law.number(), reason = %violation, "action rejected");
+                    match law.suggestion() {
+                        Some(_) => "REJECTED_WITH_SUGGESTION",
+                        None => "REJECTED",
+                    }
+                };
+
+                if decided_by.is_none_or(|(_, was_downgraded)| was_downgraded && !downgraded) {
+                    decided_by = Some((law, downgraded));
+                }
+                violations.push(LawViolation {
+                    law_number: law.number(),
+                    description: violation,
+                    resolution: resolution.into(),
+                });
+            }
+        }
+
+        if let Some((law, downgraded)) = decided_by {
+            let reason = match violations.as_slice() {
+                [only] => only.description.clone(),
+                all => all.iter()
+                    .map(|v| format!("Law {}: {}", v.law_number, v.description))
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            };
+            let verdict = match (downgraded, law.suggestion()) {
+                (true, _) => Verdict::ApprovedWithWarning(reason),
+                (false, Some(suggestion)) => Verdict::RejectedWithSuggestion(reason, suggestion.into()),
+                (false, None) => Verdict::Rejected(reason),
+            };
+            return (verdict, Some(law.number()), violations);
+        }
+
+        // Warnings: lawful, but only because of undeclared exemptions.
+        // Only paranoid strictness acts on them; otherwise the action is
+        // approved, as it was before strictness existed.
+        if strictness == Strictness::Paranoid {
+            for (index, entry) in self.laws.iter().enumerate() {
+                let law = &entry.law;
+                let scan = compiled.view(&hits, index);
+                let warning = match entry.run(
+                    action,
+                    &scan,
+                    |law, action| law.check_warning(action),
+                    |law, action, scan| law.check_warning_scanned(action, scan),
+                ) {
+                    LawOutcome::Completed(warning) => warning,
+                    LawOutcome::Unavailable(_) => continue,
+                };
+
+                if let Some(warning) = warning {
+                    warn!(law = law.number(), reason = %warning, "warning escalated to rejection");
+                    let verdict = Verdict::RejectedWithSuggestion(
+                        warning,
+                        "Declare the exemption explicitly in the action context.".into()
+                    );
+                    return (verdict, Some(law.number()), violations);
+                }
+            }
+        }
+
+        // Action is lawful
+        debug!("action approved");
+        (Verdict::Approved, None, violations)
+    }
 }
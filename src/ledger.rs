@@ -1,27 +1,140 @@
-use crate::verdicts::SystemAction;
+use crate::clock::{Clock, SystemClock};
+use crate::compliance::{ComplianceReport, ComplianceWindow};
+use crate::laws::LawViolation;
+use crate::review::{ReviewDecision, ReviewItem, ReviewStatus};
+use crate::verdicts::{SystemAction, Verdict};
 use chrono::{DateTime, Utc};
 use sha2::{Sha256, Digest};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io;
+use std::sync::Arc;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LedgerEntry {
     pub timestamp: DateTime<Utc>,
     pub action: SystemAction,
     pub verdict: String,
     pub hash: String,
     pub previous_hash: Option<String>,
+    /// Law that decided the verdict, when one did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub law: Option<u32>,
+    /// Whether the verdict was reached by a jury rather than the laws.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub jury: bool,
+    /// Dissenting opinions when the verdict was reached by a jury.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dissents: Vec<String>,
+    /// Every law the action violated, when it was checked against all of them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<LawViolation>,
+}
+
+impl LedgerEntry {
+    /// SHA-256 over the entry's content and its predecessor's hash.
+    pub fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(format!("{:?}{:?}{:?}", self.timestamp, self.action, self.verdict).as_bytes());
+        if let Some(law) = self.law {
+            hasher.update(law.to_be_bytes());
+        }
+        if self.jury {
+            hasher.update(b"jury");
+        }
+        if !self.dissents.is_empty() {
+            hasher.update(format!("{:?}", self.dissents).as_bytes());
+        }
+        if !self.violations.is_empty() {
+            hasher.update(format!("{:?}", self.violations).as_bytes());
+        }
+        if let Some(prev_hash) = &self.previous_hash {
+            hasher.update(prev_hash.as_bytes());
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// Whether this entry records a verdict rather than a governance event.
+    pub fn is_ruling(&self) -> bool {
+        self.verdict.starts_with("APPROVED")
+            || self.verdict.starts_with("REJECTED")
+            || self.verdict.starts_with("QUARANTINED")
+    }
+}
+
+/// First broken link found by [`TamperProofLedger::verify_integrity`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum IntegrityError {
+    /// The entry's content no longer matches its hash.
+    HashMismatch { index: usize },
+    /// The entry does not point at its predecessor's hash.
+    BrokenChain { index: usize },
+}
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntegrityError::HashMismatch { index } => write!(f, "entry {} does not match its hash", index),
+            IntegrityError::BrokenChain { index } => write!(f, "entry {} breaks the hash chain", index),
+        }
+    }
+}
+
+impl std::error::Error for IntegrityError {}
+
+/// Storage for ledger entries. Hashing and chaining stay in [`TamperProofLedger`].
+pub trait LedgerBackend: fmt::Debug + Send + Sync {
+    fn append(&mut self, entry: LedgerEntry);
+
+    fn entries(&self) -> &[LedgerEntry];
+
+    /// Persist any buffered entries.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// With `defer`, hold appended entries until [`flush`](Self::flush)
+    /// instead of persisting each one as it arrives.
+    /// [`WriteMode::GroupCommit`](crate::WriteMode::GroupCommit) turns this
+    /// on and flushes once per group. Backends that persist nothing, or
+    /// cannot batch, ignore it.
+    fn defer_writes(&mut self, _defer: bool) {}
+
+    /// Flush and finalize storage. No entries are appended afterwards.
+    fn close(&mut self) -> io::Result<()> {
+        self.flush()
+    }
+}
+
+/// Keeps the ledger in process memory. The default backend.
+#[derive(Debug, Default)]
+pub struct MemoryBackend {
+    entries: Vec<LedgerEntry>,
+}
+
+impl LedgerBackend for MemoryBackend {
+    fn append(&mut self, entry: LedgerEntry) {
+        self.entries.push(entry);
+    }
+
+    fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
 }
 
 #[derive(Debug)]
 pub struct TamperProofLedger {
-    entries: Vec<LedgerEntry>,
+    backend: Box<dyn LedgerBackend>,
+    clock: Arc<dyn Clock>,
 }
 
 impl TamperProofLedger {
     pub fn new() -> Self {
-        Self {
-            entries: Vec::new(),
-        }
+        Self::with_backend(Box::new(MemoryBackend::default()), Arc::new(SystemClock))
+    }
+
+    pub fn with_backend(backend: Box<dyn LedgerBackend>, clock: Arc<dyn Clock>) -> Self {
+        Self { backend, clock }
     }
 
     pub fn record_violation(&mut self, action: SystemAction, reason: String) {
@@ -32,41 +145,176 @@ impl TamperProofLedger {
         self.record_entry(action, "APPROVED".into());
     }
 
-    fn record_entry(&mut self, action: SystemAction, verdict: String) {
-        let timestamp = Utc::now();
-        let previous_hash = self.entries.last().map(|e| e.hash.clone());
-        
-        let mut hasher = Sha256::new();
-        hasher.update(format!("{:?}{:?}{:?}", timestamp, action, verdict).as_bytes());
-        if let Some(prev_hash) = &previous_hash {
-            hasher.update(prev_hash.as_bytes());
+    pub fn record_warning(&mut self, action: SystemAction, warning: String) {
+        self.record_entry(action, format!("APPROVED_WITH_WARNING: {}", warning));
+    }
+
+    /// Record a verdict together with the law that decided it and every
+    /// violation found.
+    pub fn record_ruling(
+        &mut self,
+        action: SystemAction,
+        verdict: &Verdict,
+        law: Option<u32>,
+        violations: Vec<LawViolation>,
+    ) {
+        self.record_entry_with_details(action, verdict_text(verdict), law, None, violations);
+    }
+
+    /// Record a jury verdict alongside the opinions that disagreed with it.
+    pub fn record_dissenting_verdict(&mut self, action: SystemAction, verdict: &Verdict, dissents: Vec<String>) {
+        self.record_entry_with_details(action, verdict_text(verdict), None, Some(dissents), Vec::new());
+    }
+
+    pub fn record_policy_change(&mut self, setting: &str, change: String) {
+        let action = SystemAction {
+            action_type: "POLICY_CHANGE".into(),
+            payload: format!("{}: {}", setting, change),
+            context: "judicial_core".into(),
+            actor: None,
+            credentials: None,
+            roles: Vec::new(),
+        };
+        self.record_entry(action, format!("POLICY: {}", change));
+    }
+
+    /// Record a human decision on a quarantined action, linked to the
+    /// quarantine entry by its hash.
+    pub fn record_review_decision(&mut self, item: &ReviewItem) {
+        if let ReviewStatus::Resolved { reviewer, decision, rationale, .. } = &item.status {
+            let verdict = format!(
+                "REVIEW: {} #{} by {} ({}) for entry {}",
+                match decision {
+                    ReviewDecision::Approve => "APPROVED",
+                    ReviewDecision::Reject => "REJECTED",
+                },
+                item.id,
+                reviewer,
+                rationale,
+                item.ledger_hash.as_deref().unwrap_or("unknown")
+            );
+            self.record_entry(item.action.clone(), verdict);
         }
-        let hash = format!("{:x}", hasher.finalize());
+    }
 
-        let entry = LedgerEntry {
+    /// Append the terminal entry written by `JudicialCore::shutdown`.
+    pub fn record_shutdown(&mut self) {
+        let action = SystemAction {
+            action_type: "SHUTDOWN".into(),
+            payload: String::new(),
+            context: "judicial_core".into(),
+            actor: None,
+            credentials: None,
+            roles: Vec::new(),
+        };
+        self.record_entry(action, "SHUTDOWN".into());
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.backend.flush()
+    }
+
+    /// See [`LedgerBackend::defer_writes`].
+    pub fn defer_writes(&mut self, defer: bool) {
+        self.backend.defer_writes(defer)
+    }
+
+    pub fn close(&mut self) -> io::Result<()> {
+        self.backend.close()
+    }
+
+    fn record_entry(&mut self, action: SystemAction, verdict: String) {
+        self.record_entry_with_details(action, verdict, None, None, Vec::new());
+    }
+
+    fn record_entry_with_details(
+        &mut self,
+        action: SystemAction,
+        verdict: String,
+        law: Option<u32>,
+        // `Some` for a jury verdict, even a unanimous one.
+        dissents: Option<Vec<String>>,
+        violations: Vec<LawViolation>,
+    ) {
+        let timestamp = self.clock.now();
+        let previous_hash = self.backend.entries().last().map(|e| e.hash.clone());
+
+        let mut entry = LedgerEntry {
             timestamp,
             action,
             verdict,
-            hash,
+            hash: String::new(),
             previous_hash,
+            law,
+            jury: dissents.is_some(),
+            dissents: dissents.unwrap_or_default(),
+            violations,
         };
+        entry.hash = entry.compute_hash();
 
-        self.entries.push(entry);
+        self.backend.append(entry);
+    }
+
+    /// Recompute every hash and check that each entry links to its predecessor.
+    pub fn verify_integrity(&self) -> Result<(), IntegrityError> {
+        verify_entries(self.entries())
     }
 
     pub fn calculate_compliance_score(&self) -> f64 {
-        if self.entries.is_empty() {
+        let rulings: Vec<&LedgerEntry> = self.entries().iter()
+            .filter(|e| e.is_ruling())
+            .collect();
+
+        if rulings.is_empty() {
             return 1.0;
         }
 
-        let approved_count = self.entries.iter()
+        let approved_count = rulings.iter()
             .filter(|e| e.verdict.starts_with("APPROVED"))
             .count();
 
-        approved_count as f64 / self.entries.len() as f64
+        approved_count as f64 / rulings.len() as f64
     }
 
-    pub fn entries(&self) -> &Vec<LedgerEntry> {
-        &self.entries
+    pub fn compliance_report(&self, window: ComplianceWindow) -> ComplianceReport {
+        ComplianceReport::from_entries(self.entries(), window, self.clock.now())
+    }
+
+    pub fn entries(&self) -> &[LedgerEntry] {
+        self.backend.entries()
+    }
+}
+
+/// Check a chain of entries, such as one read back from `export_ledger`.
+pub fn verify_entries(entries: &[LedgerEntry]) -> Result<(), IntegrityError> {
+    let mut previous: Option<&str> = None;
+    for (index, entry) in entries.iter().enumerate() {
+        if entry.previous_hash.as_deref() != previous {
+            return Err(IntegrityError::BrokenChain { index });
+        }
+        if entry.compute_hash() != entry.hash {
+            return Err(IntegrityError::HashMismatch { index });
+        }
+        previous = Some(&entry.hash);
+    }
+    Ok(())
+}
+
+/// Ledger wording for a verdict, matching the `record_*` helpers.
+pub fn verdict_text(verdict: &Verdict) -> String {
+    match verdict {
+        Verdict::Approved => "APPROVED".into(),
+        Verdict::ApprovedWithWarning(warning) => format!("APPROVED_WITH_WARNING: {}", warning),
+        Verdict::Rejected(reason) | Verdict::RejectedWithSuggestion(reason, _) => {
+            format!("REJECTED: {}", reason)
+        }
+        Verdict::Quarantined(reason) => format!("QUARANTINED: {}", reason),
+        Verdict::Malformed(reason) => format!("REJECTED_MALFORMED: {}", reason),
+    }
+}
+
+impl Default for TamperProofLedger {
+    fn default() -> Self {
+        Self::new()
     }
 }
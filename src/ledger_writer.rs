@@ -0,0 +1,505 @@
+use crate::laws::LawViolation;
+use crate::ledger::TamperProofLedger;
+use crate::verdicts::{SystemAction, Verdict};
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, SyncSender};
+use std::sync::{Arc, Condvar, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::thread::{self, JoinHandle, Thread};
+use std::time::Duration;
+use tracing::error;
+use web_time::Instant;
+
+/// How rulings reach the ledger.
+///
+/// `Direct` hashes and appends on the ruling thread under the ledger's write
+/// lock, so concurrent rulings queue on that lock. `Background` sends each
+/// record over a channel to a single hasher thread, which appends everything
+/// queued under one lock acquisition; ruling threads never touch the lock.
+/// Ledger order is channel arrival order in both modes, and reads first wait
+/// for every earlier ruling to be appended.
+///
+/// `Sharded` takes a sequence number from one atomic counter and parks the
+/// record in a per-thread shard, so ruling threads share neither a lock nor
+/// a queue. A sequencer thread merges the shards by sequence number and
+/// chains them, so the ledger lists records in the order they were numbered
+/// however the shards were drained. Reads wait as in `Background`.
+///
+/// `GroupCommit` is for persistent backends. A committer thread appends
+/// records in groups and flushes the backend once per group, so one
+/// transaction or fsync covers many rulings. Each ruling still waits until
+/// its group is flushed before it returns. If the flush fails, approvals in
+/// the group are withdrawn and the rulings return `Rejected`; the backend
+/// retries the entries with the next group.
+///
+/// `cargo run --release --example ledger_throughput` compares the first
+/// three and `examples/group_commit.rs` the last; `tests/ledger_sharded.rs`
+/// checks the chain under contention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WriteMode {
+    #[default]
+    Direct,
+    Background,
+    Sharded,
+    GroupCommit(GroupCommit),
+}
+
+/// When a [`WriteMode::GroupCommit`] group is flushed: once it holds
+/// `max_entries` records, once `max_latency` has passed since its first
+/// record arrived, or as soon as a ledger read is waiting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupCommit {
+    pub max_entries: usize,
+    pub max_latency: Duration,
+}
+
+impl GroupCommit {
+    /// Groups of up to 256 records.
+    pub fn new(max_latency: Duration) -> Self {
+        Self { max_entries: 256, max_latency }
+    }
+
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries.max(1);
+        self
+    }
+}
+
+/// A ledger append produced by a ruling.
+#[derive(Debug)]
+pub(crate) enum LedgerRecord {
+    Ruling {
+        action: SystemAction,
+        verdict: Verdict,
+        law: Option<u32>,
+        violations: Vec<LawViolation>,
+    },
+    Jury { action: SystemAction, verdict: Verdict, dissents: Vec<String> },
+    Violation { action: SystemAction, reason: String },
+}
+
+impl LedgerRecord {
+    pub(crate) fn action(&self) -> &SystemAction {
+        match self {
+            LedgerRecord::Ruling { action, .. }
+            | LedgerRecord::Jury { action, .. }
+            | LedgerRecord::Violation { action, .. } => action,
+        }
+    }
+
+    /// Every law the ruling found violated, if the laws weighed it.
+    pub(crate) fn violations(&self) -> &[LawViolation] {
+        match self {
+            LedgerRecord::Ruling { violations, .. } => violations,
+            LedgerRecord::Jury { .. } | LedgerRecord::Violation { .. } => &[],
+        }
+    }
+
+    pub(crate) fn apply(self, ledger: &mut TamperProofLedger) {
+        match self {
+            LedgerRecord::Ruling { action, verdict, law, violations } => {
+                ledger.record_ruling(action, &verdict, law, violations)
+            }
+            LedgerRecord::Jury { action, verdict, dissents } => {
+                ledger.record_dissenting_verdict(action, &verdict, dissents)
+            }
+            LedgerRecord::Violation { action, reason } => ledger.record_violation(action, reason),
+        }
+    }
+}
+
+/// Whether appended records reached storage: the backend's flush error
+/// if their group could not be flushed.
+pub(crate) type Flushed = Result<(), String>;
+
+enum Message {
+    Record(Box<LedgerRecord>),
+    /// Acknowledged once the record's group has been flushed, or failed to.
+    Durable(Box<LedgerRecord>, SyncSender<Flushed>),
+    /// Acknowledged once every earlier record has been appended.
+    Sync(SyncSender<()>),
+}
+
+/// Owns the ledger and implements the chosen [`WriteMode`].
+#[derive(Debug)]
+pub(crate) struct LedgerWriter {
+    ledger: Arc<RwLock<TamperProofLedger>>,
+    sender: Option<Sender<Message>>,
+    shards: Option<Arc<Shards>>,
+    /// Appends wait for their group to be flushed.
+    durable: bool,
+    hasher: Option<JoinHandle<()>>,
+}
+
+impl LedgerWriter {
+    pub(crate) fn new(ledger: TamperProofLedger, mode: WriteMode) -> Self {
+        let ledger = Arc::new(RwLock::new(ledger));
+        match mode {
+            WriteMode::Direct => Self { ledger, sender: None, shards: None, durable: false, hasher: None },
+            WriteMode::Background => {
+                let (sender, receiver) = mpsc::channel();
+                let shared = Arc::clone(&ledger);
+                let hasher = thread::Builder::new()
+                    .name("judicial-ledger".into())
+                    .spawn(move || run_hasher(shared, receiver))
+                    .expect("failed to spawn ledger hasher thread");
+                Self { ledger, sender: Some(sender), shards: None, durable: false, hasher: Some(hasher) }
+            }
+            WriteMode::Sharded => {
+                let (shards, hasher) = Shards::start(Arc::clone(&ledger));
+                Self { ledger, sender: None, shards: Some(shards), durable: false, hasher: Some(hasher) }
+            }
+            WriteMode::GroupCommit(group) => {
+                ledger.write().unwrap().defer_writes(true);
+                let (sender, receiver) = mpsc::channel();
+                let shared = Arc::clone(&ledger);
+                let committer = thread::Builder::new()
+                    .name("judicial-ledger".into())
+                    .spawn(move || run_committer(shared, receiver, group))
+                    .expect("failed to spawn ledger committer thread");
+                Self { ledger, sender: Some(sender), shards: None, durable: true, hasher: Some(committer) }
+            }
+        }
+    }
+
+    fn append(&self, record: LedgerRecord) {
+        if let Some(shards) = &self.shards {
+            return shards.push(record);
+        }
+        match &self.sender {
+            Some(sender) => {
+                if let Err(mpsc::SendError(Message::Record(record))) = sender.send(Message::Record(Box::new(record))) {
+                    record.apply(&mut self.ledger.write().unwrap());
+                }
+            }
+            None => record.apply(&mut self.ledger.write().unwrap()),
+        }
+    }
+
+    /// Append records in order. Under group commit they are queued
+    /// together and only the last is waited for: groups are flushed in
+    /// order, and a backend keeps what a failed flush left behind for the
+    /// next one, so the last flush succeeding covers the rest. Its error is
+    /// returned if it failed; other modes always return `Ok`.
+    pub(crate) fn append_all(&self, records: Vec<LedgerRecord>) -> Flushed {
+        let Some(sender) = self.sender.as_ref().filter(|_| self.durable) else {
+            if self.sender.is_none() && self.shards.is_none() && records.len() > 1 {
+                let mut ledger = self.ledger.write().unwrap();
+                for record in records {
+                    record.apply(&mut ledger);
+                }
+            } else {
+                records.into_iter().for_each(|record| self.append(record));
+            }
+            return Ok(());
+        };
+
+        let (ack, flushed) = mpsc::sync_channel(1);
+        let mut records = records.into_iter().peekable();
+        while let Some(record) = records.next() {
+            let message = match records.peek() {
+                Some(_) => Message::Record(Box::new(record)),
+                None => Message::Durable(Box::new(record), ack.clone()),
+            };
+            if let Err(mpsc::SendError(message)) = sender.send(message) {
+                let mut ledger = self.ledger.write().unwrap();
+                if let Message::Record(record) | Message::Durable(record, _) = message {
+                    record.apply(&mut ledger);
+                }
+                records.for_each(|record| record.apply(&mut ledger));
+                return flush(&mut ledger);
+            }
+        }
+        drop(ack);
+        // Disconnected when there was nothing to send.
+        flushed.recv().unwrap_or(Ok(()))
+    }
+
+    /// Wait until every record appended so far is in the ledger.
+    pub(crate) fn sync(&self) {
+        if let Some(shards) = &self.shards {
+            shards.sync();
+        }
+        if let Some(sender) = &self.sender {
+            let (ack, done) = mpsc::sync_channel(1);
+            if sender.send(Message::Sync(ack)).is_ok() {
+                let _ = done.recv();
+            }
+        }
+    }
+
+    pub(crate) fn read(&self) -> RwLockReadGuard<'_, TamperProofLedger> {
+        self.sync();
+        self.ledger.read().unwrap()
+    }
+
+    /// Exclusive access for entries recorded outside rulings. Under group
+    /// commit they are written through as they are recorded, not deferred.
+    pub(crate) fn write(&self) -> LedgerWriteGuard<'_> {
+        self.sync();
+        let mut guard = self.ledger.write().unwrap();
+        if self.durable {
+            guard.defer_writes(false);
+        }
+        LedgerWriteGuard { guard, deferred: self.durable }
+    }
+}
+
+pub(crate) struct LedgerWriteGuard<'a> {
+    guard: RwLockWriteGuard<'a, TamperProofLedger>,
+    /// Writes are deferred again on drop.
+    deferred: bool,
+}
+
+impl Deref for LedgerWriteGuard<'_> {
+    type Target = TamperProofLedger;
+
+    fn deref(&self) -> &TamperProofLedger {
+        &self.guard
+    }
+}
+
+impl DerefMut for LedgerWriteGuard<'_> {
+    fn deref_mut(&mut self) -> &mut TamperProofLedger {
+        &mut self.guard
+    }
+}
+
+impl Drop for LedgerWriteGuard<'_> {
+    fn drop(&mut self) {
+        if self.deferred {
+            self.guard.defer_writes(true);
+        }
+    }
+}
+
+impl Drop for LedgerWriter {
+    fn drop(&mut self) {
+        self.sender.take();
+        if let Some(shards) = &self.shards {
+            shards.stop();
+        }
+        if let Some(hasher) = self.hasher.take() {
+            let _ = hasher.join();
+        }
+    }
+}
+
+fn run_hasher(ledger: Arc<RwLock<TamperProofLedger>>, receiver: Receiver<Message>) {
+    while let Ok(first) = receiver.recv() {
+        let mut durable = Vec::new();
+        let mut acks = Vec::new();
+        {
+            let mut ledger = ledger.write().unwrap();
+            let mut next = Some(first);
+            while let Some(message) = next {
+                match message {
+                    Message::Record(record) => record.apply(&mut ledger),
+                    Message::Durable(record, ack) => {
+                        record.apply(&mut ledger);
+                        durable.push(ack);
+                    }
+                    Message::Sync(ack) => acks.push(ack),
+                }
+                next = receiver.try_recv().ok();
+            }
+        }
+        for ack in durable {
+            let _ = ack.send(Ok(()));
+        }
+        for ack in acks {
+            let _ = ack.send(());
+        }
+    }
+}
+
+/// Collect a group, append it under one lock acquisition and flush once.
+fn run_committer(ledger: Arc<RwLock<TamperProofLedger>>, receiver: Receiver<Message>, group: GroupCommit) {
+    while let Ok(first) = receiver.recv() {
+        let deadline = Instant::now() + group.max_latency;
+        let mut messages = vec![first];
+        let mut records = 0;
+        loop {
+            match messages.last() {
+                Some(Message::Sync(_)) => break,
+                Some(_) => records += 1,
+                None => {}
+            }
+            if records >= group.max_entries {
+                break;
+            }
+            let Some(wait) = deadline.checked_duration_since(Instant::now()) else {
+                break;
+            };
+            match receiver.recv_timeout(wait) {
+                Ok(message) => messages.push(message),
+                Err(_) => break,
+            }
+        }
+
+        let mut durable = Vec::with_capacity(messages.len());
+        let mut acks = Vec::new();
+        let mut ledger = ledger.write().unwrap();
+        for message in messages {
+            match message {
+                Message::Record(record) => record.apply(&mut ledger),
+                Message::Durable(record, ack) => {
+                    record.apply(&mut ledger);
+                    durable.push(ack);
+                }
+                Message::Sync(ack) => acks.push(ack),
+            }
+        }
+        let flushed = flush(&mut ledger);
+        drop(ledger);
+        for ack in durable {
+            let _ = ack.send(flushed.clone());
+        }
+        for ack in acks {
+            let _ = ack.send(());
+        }
+    }
+}
+
+/// A failed flush leaves the entries to the backend to retry with the next
+/// group; the rulings waiting on this one are told it failed.
+fn flush(ledger: &mut TamperProofLedger) -> Flushed {
+    ledger.flush().map_err(|e| {
+        error!(error = %e, "ledger group not flushed");
+        e.to_string()
+    })
+}
+
+/// Records numbered in submission order, parked per thread until the
+/// sequencer chains them.
+#[derive(Debug)]
+struct Shards {
+    shards: Box<[Mutex<Vec<Numbered>>]>,
+    /// Sequence number of the next record.
+    next: AtomicU64,
+    /// Every record numbered below this is in the ledger.
+    applied: Mutex<u64>,
+    caught_up: Condvar,
+    /// Set by the sequencer before its last look at the shards ahead of
+    /// parking; a push that finds it set clears it and wakes the sequencer,
+    /// so no record is left waiting.
+    idle: AtomicBool,
+    stopped: AtomicBool,
+    sequencer: Mutex<Option<Thread>>,
+}
+
+/// A record and its sequence number.
+type Numbered = (u64, LedgerRecord);
+
+static NEXT_SHARD: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static SHARD: usize = NEXT_SHARD.fetch_add(1, Ordering::Relaxed);
+}
+
+impl Shards {
+    fn start(ledger: Arc<RwLock<TamperProofLedger>>) -> (Arc<Self>, JoinHandle<()>) {
+        let count = thread::available_parallelism().map_or(4, |n| n.get());
+        let shards = Arc::new(Self {
+            shards: (0..count).map(|_| Mutex::new(Vec::new())).collect(),
+            next: AtomicU64::new(0),
+            applied: Mutex::new(0),
+            caught_up: Condvar::new(),
+            idle: AtomicBool::new(false),
+            stopped: AtomicBool::new(false),
+            sequencer: Mutex::new(None),
+        });
+        let shared = Arc::clone(&shards);
+        let sequencer = thread::Builder::new()
+            .name("judicial-ledger".into())
+            .spawn(move || shared.run(ledger))
+            .expect("failed to spawn ledger sequencer thread");
+        *shards.sequencer.lock().unwrap() = Some(sequencer.thread().clone());
+        (shards, sequencer)
+    }
+
+    fn push(&self, record: LedgerRecord) {
+        let shard = SHARD.with(|&shard| shard % self.shards.len());
+        // Numbered under the shard lock, so each shard stays sorted and no
+        // record is numbered long before the sequencer can see it.
+        {
+            let mut shard = self.shards[shard].lock().unwrap();
+            let seq = self.next.fetch_add(1, Ordering::SeqCst);
+            shard.push((seq, record));
+        }
+        self.wake();
+    }
+
+    fn wake(&self) {
+        if self.idle.swap(false, Ordering::SeqCst) {
+            self.unpark();
+        }
+    }
+
+    fn unpark(&self) {
+        if let Some(sequencer) = &*self.sequencer.lock().unwrap() {
+            sequencer.unpark();
+        }
+    }
+
+    fn sync(&self) {
+        let target = self.next.load(Ordering::SeqCst);
+        let mut applied = self.applied.lock().unwrap();
+        while *applied < target {
+            if self.stopped.load(Ordering::SeqCst) {
+                return;
+            }
+            applied = self.caught_up.wait(applied).unwrap();
+        }
+    }
+
+    fn stop(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        self.unpark();
+        // Under the lock, so a sync between its check and its wait still hears it.
+        let _applied = self.applied.lock().unwrap();
+        self.caught_up.notify_all();
+    }
+
+    /// Drain the shards and chain every record whose predecessors have all
+    /// arrived. A record numbered but not yet pushed holds back the ones
+    /// after it until a later pass.
+    fn run(&self, ledger: Arc<RwLock<TamperProofLedger>>) {
+        let mut pending: Vec<Numbered> = Vec::new();
+        let mut next_applied = 0;
+        loop {
+            let stopping = self.stopped.load(Ordering::SeqCst);
+            for shard in self.shards.iter() {
+                pending.append(&mut shard.lock().unwrap());
+            }
+            // Each shard is sorted, so this merges a few runs.
+            pending.sort_by_key(|&(seq, _)| seq);
+
+            let ready = pending.iter()
+                .enumerate()
+                .take_while(|&(i, &(seq, _))| seq == next_applied + i as u64)
+                .count();
+            if ready > 0 {
+                let mut ledger = ledger.write().unwrap();
+                for (_, record) in pending.drain(..ready) {
+                    record.apply(&mut ledger);
+                }
+                drop(ledger);
+                next_applied += ready as u64;
+                *self.applied.lock().unwrap() = next_applied;
+                self.caught_up.notify_all();
+                continue;
+            }
+
+            if stopping && next_applied == self.next.load(Ordering::SeqCst) {
+                return;
+            }
+            self.idle.store(true, Ordering::SeqCst);
+            if self.shards.iter().all(|shard| shard.lock().unwrap().is_empty()) && !self.stopped.load(Ordering::SeqCst) {
+                thread::park();
+            }
+            self.idle.store(false, Ordering::SeqCst);
+        }
+    }
+}
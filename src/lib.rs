@@ -1,8 +1,86 @@
+pub mod action_types;
+pub mod approval;
+pub mod clock;
+pub mod compliance;
+pub mod courts;
+pub mod distributed;
+#[cfg(feature = "smtp")]
+pub mod email;
+pub mod events;
+#[cfg(feature = "arbitrary")]
+mod fuzz_input;
+#[cfg(all(feature = "ffi", not(target_arch = "wasm32")))]
+pub mod ffi_c;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod health;
+pub mod identity;
+#[cfg(feature = "http-server")]
+pub mod http;
 pub mod judicial_core;
+pub mod jury;
 pub mod laws;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
 pub mod verdicts;
 pub mod ledger;
+pub mod ledger_writer;
+pub mod observers;
+#[cfg(feature = "plugins")]
+pub mod plugins;
+#[cfg(feature = "otel")]
+pub mod otel;
+pub mod policy_pack;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+pub mod precedent;
+pub mod profiles;
+pub mod rate_limit;
+pub mod replay;
+pub mod review;
+#[cfg(feature = "schemas")]
+pub mod schemas;
+pub mod scoped;
+pub mod snapshot;
+pub mod statistics;
+pub mod strictness;
+pub mod tool_guard;
+pub mod verdict_cache;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "webhooks")]
+pub mod webhooks;
 
-pub use judicial_core::JudicialCore;
+pub use action_types::{ActionTypes, UnknownActionPolicy};
+pub use approval::{ApprovalError, ApprovalService, ApprovalStatus};
+pub use compliance::{ComplianceReport, ComplianceWindow};
+pub use courts::{Court, CourtRuling, EscalationPolicy};
+pub use distributed::{CourtPeer, DistributedCourt, FallbackPolicy, QuorumVerdict, ServeConfig, TcpPeer};
+pub use health::{CacheHealth, HealthReport};
+pub use identity::{Identity, IdentityError, IdentityProvider};
+pub use judicial_core::{JudicialCore, JudicialCoreBuilder, ShutDownError};
 pub use verdicts::{Verdict, SystemAction};
-pub use laws::{MasterPair};
+pub use jury::{Deliberation, Evaluator, Jury, LawSetEvaluator, VotingRule};
+pub use laws::{Law, LawBudget, LawSet, LawViolation, MasterPair, TimeoutPolicy};
+pub use ledger_writer::{GroupCommit, WriteMode};
+pub use observers::Observer;
+pub use policy_pack::{PolicyPack, PolicyPackError};
+pub use precedent::{Precedent, PrecedentPolicy};
+pub use profiles::{PolicyProfile, PolicyProfiles};
+pub use rate_limit::{RateLimit, RateLimitScope, RateLimitStats};
+pub use replay::Divergence;
+pub use review::{
+    MemoryReviewStore, ReviewDecision, ReviewError, ReviewFallback, ReviewId, ReviewItem, ReviewQueue, ReviewSla,
+    ReviewStatus, ReviewStore, SlaAlert, SlaStage, TIMEOUT_REVIEWER,
+};
+pub use scoped::ScopedCourt;
+pub use snapshot::{CoreSnapshot, SnapshotError};
+pub use statistics::Statistics;
+pub use strictness::Strictness;
+pub use tool_guard::{ToolCall, ToolCallGuard, ToolDecision};
+pub use verdict_cache::CacheStats;
+
+/// Compiles and runs the README's examples as doctests.
+#[cfg(doctest)]
+#[doc = include_str!("../README.md")]
+struct ReadmeDoctests;
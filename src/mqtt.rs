@@ -0,0 +1,169 @@
+//! MQTT bridge for devices that cannot link the crate, behind the `mqtt`
+//! feature.
+//!
+//! Devices publish requests to the request topic and read replies from the
+//! response topic. A request is a [`SystemAction`] with an optional `id`
+//! echoed back for correlation:
+//!
+//! ```json
+//! {"id": "42", "action_type": "FILE_DELETE", "payload": "rm -rf /tmp/x", "context": "sensor-3"}
+//! ```
+//!
+//! Replies are kept small:
+//!
+//! ```json
+//! {"id": "42", "verdict": "REJECTED_WITH_SUGGESTION", "approved": false, "reason": "..."}
+//! {"id": null, "error": "payload exceeds 4096 bytes"}
+//! ```
+//!
+//! Oversized and unparseable requests are answered with an error and never
+//! ruled on.
+
+use crate::judicial_core::JudicialCore;
+use crate::verdicts::{SystemAction, Verdict};
+use rumqttc::{Client, Event, MqttOptions, Packet, Publish};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tracing::{info, warn};
+
+pub use rumqttc::QoS;
+
+/// Packets above this size drop the connection before the payload check.
+const MAX_PACKET: usize = 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct MqttConfig {
+    pub host: String,
+    pub port: u16,
+    pub client_id: String,
+    pub request_topic: String,
+    pub response_topic: String,
+    /// QoS for both the subscription and replies.
+    pub qos: QoS,
+    /// Largest request payload ruled on, in bytes.
+    pub max_payload: usize,
+    pub keep_alive: Duration,
+}
+
+impl MqttConfig {
+    /// Topics `judicial/requests` and `judicial/responses`, QoS 1 and a
+    /// 4 KiB payload limit.
+    pub fn new(host: impl Into<String>, port: u16) -> Self {
+        Self {
+            host: host.into(),
+            port,
+            client_id: "judicial-core".into(),
+            request_topic: "judicial/requests".into(),
+            response_topic: "judicial/responses".into(),
+            qos: QoS::AtLeastOnce,
+            max_payload: 4096,
+            keep_alive: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Request {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(flatten)]
+    action: SystemAction,
+}
+
+#[derive(Serialize)]
+#[serde(untagged)]
+enum Reply<'a> {
+    Verdict {
+        id: Option<String>,
+        verdict: &'static str,
+        approved: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        reason: Option<&'a str>,
+    },
+    Error {
+        id: Option<String>,
+        error: String,
+    },
+}
+
+impl<'a> Reply<'a> {
+    fn verdict(id: Option<String>, verdict: &'a Verdict) -> Self {
+        let reason = match verdict {
+            Verdict::Approved => None,
+            Verdict::ApprovedWithWarning(reason)
+            | Verdict::Rejected(reason)
+            | Verdict::RejectedWithSuggestion(reason, _)
+            | Verdict::Quarantined(reason)
+            | Verdict::Malformed(reason) => Some(reason.as_str()),
+        };
+        Reply::Verdict { id, verdict: verdict.label(), approved: verdict.is_approved(), reason }
+    }
+}
+
+/// Rules on actions arriving over MQTT.
+#[derive(Debug)]
+pub struct MqttBridge {
+    core: Arc<JudicialCore>,
+    config: MqttConfig,
+}
+
+impl MqttBridge {
+    pub fn new(core: Arc<JudicialCore>, config: MqttConfig) -> Self {
+        Self { core, config }
+    }
+
+    pub fn core(&self) -> &Arc<JudicialCore> {
+        &self.core
+    }
+
+    /// Serve requests on the calling thread, reconnecting after broker
+    /// failures. Does not return.
+    pub fn run(&self) -> ! {
+        let mut options = MqttOptions::new(&self.config.client_id, &self.config.host, self.config.port);
+        options.set_keep_alive(self.config.keep_alive);
+        options.set_max_packet_size(MAX_PACKET, MAX_PACKET);
+        let (client, mut connection) = Client::new(options, 64);
+
+        loop {
+            match connection.recv() {
+                Ok(Ok(Event::Incoming(Packet::ConnAck(_)))) => {
+                    info!(topic = %self.config.request_topic, "connected to MQTT broker");
+                    if let Err(e) = client.try_subscribe(&self.config.request_topic, self.config.qos) {
+                        warn!(error = %e, "MQTT subscribe failed");
+                    }
+                }
+                Ok(Ok(Event::Incoming(Packet::Publish(publish)))) => self.handle(&client, &publish),
+                Ok(Ok(_)) => {}
+                Ok(Err(e)) => {
+                    warn!(error = %e, "MQTT connection failed; retrying");
+                    thread::sleep(Duration::from_secs(1));
+                }
+                Err(_) => unreachable!("the bridge holds the client"),
+            }
+        }
+    }
+
+    fn handle(&self, client: &Client, publish: &Publish) {
+        let body = if publish.payload.len() > self.config.max_payload {
+            serde_json::to_vec(&Reply::Error {
+                id: None,
+                error: format!("payload exceeds {} bytes", self.config.max_payload),
+            })
+        } else {
+            match serde_json::from_slice::<Request>(&publish.payload) {
+                Ok(request) => {
+                    let verdict = self.core.rule(request.action);
+                    serde_json::to_vec(&Reply::verdict(request.id, &verdict))
+                }
+                Err(e) => serde_json::to_vec(&Reply::Error { id: None, error: e.to_string() }),
+            }
+        }
+        .unwrap();
+
+        if let Err(e) = client.try_publish(&self.config.response_topic, self.config.qos, false, body) {
+            warn!(error = %e, "MQTT reply dropped");
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::ledger::IntegrityError;
+use crate::replay::Divergence;
+use crate::review::{ReviewItem, SlaAlert};
+use crate::verdicts::{SystemAction, Verdict};
+use std::fmt;
+
+/// Receives notifications from a [`JudicialCore`](crate::JudicialCore).
+///
+/// Observers are called synchronously after the ledger has been written.
+pub trait Observer: fmt::Debug + Send + Sync {
+    fn on_verdict(&self, _action: &SystemAction, _verdict: &Verdict) {}
+
+    /// [`on_verdict`](Self::on_verdict) with the number of the law that
+    /// decided, when one did. This is what the core calls; by default it
+    /// forwards to `on_verdict`.
+    fn on_ruling(&self, action: &SystemAction, verdict: &Verdict, _law: Option<u32>) {
+        self.on_verdict(action, verdict);
+    }
+
+    fn on_policy_change(&self, _setting: &str, _change: &str) {}
+
+    /// An action was quarantined and queued for human review.
+    fn on_review_required(&self, _item: &ReviewItem) {}
+
+    /// A pending review is nearing or has breached its
+    /// [`ReviewSla`](crate::ReviewSla), as found by
+    /// [`expire_reviews`](crate::JudicialCore::expire_reviews).
+    fn on_review_sla(&self, _alert: &SlaAlert) {}
+
+    /// A [`replay`](crate::JudicialCore::replay) found rulings the current
+    /// laws would decide differently.
+    fn on_policy_drift(&self, _divergences: &[Divergence]) {}
+
+    /// [`verify_ledger`](crate::JudicialCore::verify_ledger) or
+    /// [`health`](crate::JudicialCore::health) found the ledger tampered with.
+    fn on_integrity_failure(&self, _error: &IntegrityError) {}
+}
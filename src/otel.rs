@@ -0,0 +1,61 @@
+//! OTLP export of ruling spans, behind the `otel` feature.
+//!
+//! Every `rule()` call runs in a `rule` span carrying `action_type`,
+//! `profile`, `law` and `verdict`. [`otlp_layer`] turns those spans into
+//! OpenTelemetry spans and exports them over OTLP/HTTP. Rulings made inside
+//! a span of the host agent become its children, so they appear in the same
+//! trace when the host exports through OpenTelemetry too.
+//!
+//! ```no_run
+//! use tracing_subscriber::layer::SubscriberExt;
+//! use tracing_subscriber::util::SubscriberInitExt;
+//!
+//! let (layer, _guard) = judicial_core::otel::otlp_layer("http://localhost:4318/v1/traces", "agent").unwrap();
+//! tracing_subscriber::registry().with(layer).init();
+//! ```
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::trace::{SdkTracer, SdkTracerProvider};
+use opentelemetry_sdk::Resource;
+use tracing::Subscriber;
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Keeps the exporter running. Dropping it flushes pending spans.
+#[derive(Debug)]
+pub struct OtelGuard {
+    provider: SdkTracerProvider,
+}
+
+impl OtelGuard {
+    pub fn provider(&self) -> &SdkTracerProvider {
+        &self.provider
+    }
+}
+
+impl Drop for OtelGuard {
+    fn drop(&mut self) {
+        let _ = self.provider.shutdown();
+    }
+}
+
+/// A `tracing` layer exporting spans to the OTLP/HTTP traces `endpoint`.
+pub fn otlp_layer<S>(
+    endpoint: &str,
+    service_name: &str,
+) -> Result<(OpenTelemetryLayer<S, SdkTracer>, OtelGuard), ExporterBuildError>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(endpoint)
+        .build()?;
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(Resource::builder().with_service_name(service_name.to_string()).build())
+        .build();
+    let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("judicial-core"));
+    Ok((layer, OtelGuard { provider }))
+}
@@ -0,0 +1,270 @@
+//! Laws compiled into shared libraries, behind the `plugins` feature.
+//!
+//! Every `.so` / `.dylib` / `.dll` in a plugin directory is loaded in file
+//! name order and its laws are appended to the core's own. Libraries speak
+//! a C ABI, declared in `include/judicial_plugin.h`, so they can be built
+//! with any compiler:
+//!
+//! - `uint32_t judicial_plugin_abi_version(void)` returns
+//!   [`PLUGIN_ABI_VERSION`]. It is checked before anything else is read.
+//! - `const JudicialPlugin *judicial_plugin(void)` returns a [`PluginDecl`]
+//!   that lives as long as the library.
+//!
+//! Law callbacks receive the action as NUL-terminated JSON and may be
+//! called from several threads at once. They must not unwind.
+//!
+//! [`JudicialCore::reload_plugins`](crate::JudicialCore::reload_plugins)
+//! rescans the directory. A library whose file name is already loaded is
+//! not read again, so ship a new version of a plugin under a new file name
+//! (`libpii_laws-1.3.so`) and remove the old one.
+
+use crate::laws::Law;
+use crate::verdicts::SystemAction;
+use libc::c_char;
+use libloading::Library;
+use std::env::consts::DLL_EXTENSION;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tracing::info;
+
+/// Version of the plugin ABI this build understands.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// One law as a plugin declares it.
+#[repr(C)]
+#[derive(Debug)]
+pub struct PluginLawDecl {
+    pub number: u32,
+    pub description: *const c_char,
+    pub absolute: bool,
+    /// Nullable.
+    pub suggestion: *const c_char,
+    /// Nullable; a missing callback governs every action type.
+    pub governs: Option<unsafe extern "C" fn(action_type: *const c_char) -> bool>,
+    /// Returns null for a lawful action, otherwise the objection, released
+    /// with the plugin's `free_string`.
+    pub check: unsafe extern "C" fn(action_json: *const c_char) -> *mut c_char,
+    /// Nullable. Same contract as `check`, for warnings.
+    pub check_warning: Option<unsafe extern "C" fn(action_json: *const c_char) -> *mut c_char>,
+}
+
+/// What `judicial_plugin()` returns.
+#[repr(C)]
+#[derive(Debug)]
+pub struct PluginDecl {
+    pub name: *const c_char,
+    pub law_count: usize,
+    pub laws: *const PluginLawDecl,
+    pub free_string: unsafe extern "C" fn(s: *mut c_char),
+}
+
+#[derive(Debug)]
+pub enum PluginError {
+    Io(std::io::Error),
+    Load { path: PathBuf, message: String },
+    AbiVersion { path: PathBuf, found: u32 },
+    InvalidDeclaration { path: PathBuf, message: String },
+    /// The core has been shut down.
+    ShutDown,
+}
+
+impl fmt::Display for PluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PluginError::Io(e) => write!(f, "cannot read plugin directory: {}", e),
+            PluginError::Load { path, message } => write!(f, "cannot load {}: {}", path.display(), message),
+            PluginError::AbiVersion { path, found } => write!(
+                f,
+                "{} uses plugin ABI {}, expected {}",
+                path.display(),
+                found,
+                PLUGIN_ABI_VERSION
+            ),
+            PluginError::InvalidDeclaration { path, message } => {
+                write!(f, "invalid plugin {}: {}", path.display(), message)
+            }
+            PluginError::ShutDown => write!(f, "judicial core is shut down"),
+        }
+    }
+}
+
+impl std::error::Error for PluginError {}
+
+/// A directory of law plugins.
+#[derive(Debug, Clone)]
+pub struct LawPlugins {
+    dir: PathBuf,
+}
+
+impl LawPlugins {
+    /// # Safety
+    ///
+    /// Loading a library runs its initialisers, and its laws run inside
+    /// this process. Every library ever placed in `dir` must be trusted and
+    /// must implement the ABI above faithfully.
+    pub unsafe fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Load every library in the directory. Either all load or none do.
+    pub fn load(&self) -> Result<Vec<PluginLaw>, PluginError> {
+        let mut paths: Vec<PathBuf> = fs::read_dir(&self.dir)
+            .map_err(PluginError::Io)?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == DLL_EXTENSION))
+            .collect();
+        paths.sort();
+
+        let mut laws = Vec::new();
+        for path in paths {
+            // SAFETY: the directory is trusted, per `new`.
+            laws.extend(unsafe { load_library(&path)? });
+        }
+        Ok(laws)
+    }
+}
+
+#[derive(Debug)]
+struct Plugin {
+    name: String,
+    path: PathBuf,
+    free_string: unsafe extern "C" fn(*mut c_char),
+    _library: Library,
+}
+
+/// A law implemented by a plugin. Keeps its library loaded.
+#[derive(Debug)]
+pub struct PluginLaw {
+    number: u32,
+    description: String,
+    absolute: bool,
+    suggestion: Option<String>,
+    governs: Option<unsafe extern "C" fn(*const c_char) -> bool>,
+    check: unsafe extern "C" fn(*const c_char) -> *mut c_char,
+    check_warning: Option<unsafe extern "C" fn(*const c_char) -> *mut c_char>,
+    plugin: Arc<Plugin>,
+}
+
+impl PluginLaw {
+    /// Name the plugin declared for itself.
+    pub fn plugin(&self) -> &str {
+        &self.plugin.name
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.plugin.path
+    }
+
+    fn call(&self, callback: unsafe extern "C" fn(*const c_char) -> *mut c_char, action: &SystemAction) -> Option<String> {
+        // serde_json escapes NUL, so the JSON never contains one.
+        let json = CString::new(serde_json::to_string(action).unwrap()).unwrap();
+        // SAFETY: the callback comes from a trusted plugin and gets a valid
+        // NUL-terminated string; what it returns is released by its own
+        // `free_string`.
+        unsafe {
+            let objection = callback(json.as_ptr());
+            if objection.is_null() {
+                return None;
+            }
+            let text = CStr::from_ptr(objection).to_string_lossy().into_owned();
+            (self.plugin.free_string)(objection);
+            Some(text)
+        }
+    }
+}
+
+impl Law for PluginLaw {
+    fn number(&self) -> u32 {
+        self.number
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn is_absolute(&self) -> bool {
+        self.absolute
+    }
+
+    fn suggestion(&self) -> Option<&str> {
+        self.suggestion.as_deref()
+    }
+
+    fn governs(&self, action_type: &str) -> bool {
+        let Some(governs) = self.governs else {
+            return true;
+        };
+        match CString::new(action_type) {
+            // SAFETY: as in `call`.
+            Ok(action_type) => unsafe { governs(action_type.as_ptr()) },
+            Err(_) => true,
+        }
+    }
+
+    fn check(&self, action: &SystemAction) -> Option<String> {
+        self.call(self.check, action)
+    }
+
+    fn check_warning(&self, action: &SystemAction) -> Option<String> {
+        self.check_warning.and_then(|callback| self.call(callback, action))
+    }
+}
+
+unsafe fn load_library(path: &Path) -> Result<Vec<PluginLaw>, PluginError> {
+    let invalid = |message: &str| PluginError::InvalidDeclaration { path: path.into(), message: message.into() };
+    let load_error = |e: libloading::Error| PluginError::Load { path: path.into(), message: e.to_string() };
+
+    let library = Library::new(path).map_err(load_error)?;
+    let version = *library
+        .get::<unsafe extern "C" fn() -> u32>(b"judicial_plugin_abi_version\0")
+        .map_err(load_error)?;
+    let found = version();
+    if found != PLUGIN_ABI_VERSION {
+        return Err(PluginError::AbiVersion { path: path.into(), found });
+    }
+
+    let declare = *library
+        .get::<unsafe extern "C" fn() -> *const PluginDecl>(b"judicial_plugin\0")
+        .map_err(load_error)?;
+    let decl = declare().as_ref().ok_or_else(|| invalid("judicial_plugin returned null"))?;
+    if decl.name.is_null() || (decl.laws.is_null() && decl.law_count > 0) {
+        return Err(invalid("null name or law table"));
+    }
+    let decls = match decl.law_count {
+        0 => &[][..],
+        count => std::slice::from_raw_parts(decl.laws, count),
+    };
+
+    let plugin = Arc::new(Plugin {
+        name: CStr::from_ptr(decl.name).to_string_lossy().into_owned(),
+        path: path.into(),
+        free_string: decl.free_string,
+        _library: library,
+    });
+    let mut laws = Vec::with_capacity(decls.len());
+    for law in decls {
+        if law.description.is_null() {
+            return Err(invalid(&format!("law {} has no description", law.number)));
+        }
+        laws.push(PluginLaw {
+            number: law.number,
+            description: CStr::from_ptr(law.description).to_string_lossy().into_owned(),
+            absolute: law.absolute,
+            suggestion: (!law.suggestion.is_null())
+                .then(|| CStr::from_ptr(law.suggestion).to_string_lossy().into_owned()),
+            governs: law.governs,
+            check: law.check,
+            check_warning: law.check_warning,
+            plugin: Arc::clone(&plugin),
+        });
+    }
+    info!(plugin = %plugin.name, path = %path.display(), laws = laws.len(), "law plugin loaded");
+    Ok(laws)
+}
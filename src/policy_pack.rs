@@ -0,0 +1,179 @@
+use crate::action_types::UnknownActionPolicy;
+use crate::laws::{LawBudget, LawSet, TimeoutPolicy};
+use crate::profiles::PolicyProfile;
+use crate::strictness::Strictness;
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Deployment policy kept in one TOML file.
+///
+/// Laws are code, so a pack refers to them by number: `laws` picks and
+/// orders the laws the builder already has, and profiles pick from those.
+/// [`JudicialCore::load_policy_pack`](crate::JudicialCore::load_policy_pack)
+/// swaps a pack in on a running core.
+///
+/// ```toml
+/// strictness = "Paranoid"
+/// laws = [1, 2]
+/// known_action_types = ["DATA_READ", "DATA_WRITE"]
+/// unknown_action_policy = "Quarantine"
+///
+/// [[budgets]]
+/// law = 2
+/// timeout_ms = 50
+/// on_timeout = "FailOpen"
+///
+/// [[profiles]]
+/// name = "sandbox"
+/// laws = [1]
+/// strictness = "Permissive"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyPack {
+    pub strictness: Option<Strictness>,
+    pub laws: Option<Vec<u32>>,
+    #[serde(default)]
+    pub known_action_types: Vec<String>,
+    pub unknown_action_policy: Option<UnknownActionPolicy>,
+    #[serde(default)]
+    pub budgets: Vec<BudgetSpec>,
+    #[serde(default)]
+    pub profiles: Vec<ProfileSpec>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BudgetSpec {
+    pub law: u32,
+    pub timeout_ms: u64,
+    #[serde(default)]
+    pub on_timeout: TimeoutPolicy,
+    pub trip_after: Option<u32>,
+    pub cooldown_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileSpec {
+    pub name: String,
+    pub laws: Vec<u32>,
+    pub strictness: Option<Strictness>,
+}
+
+#[derive(Debug)]
+pub enum PolicyPackError {
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+    /// A law list names the same law twice.
+    DuplicateLaw(u32),
+    /// The pack names a law that is not installed.
+    UnknownLaw(u32),
+    /// A budget with a zero timeout, or for the same law twice.
+    InvalidBudget { law: u32 },
+    DuplicateProfile(String),
+    /// The core has been shut down.
+    ShutDown,
+}
+
+impl fmt::Display for PolicyPackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolicyPackError::Io(e) => write!(f, "cannot read policy pack: {}", e),
+            PolicyPackError::Parse(e) => write!(f, "invalid policy pack: {}", e),
+            PolicyPackError::DuplicateLaw(law) => write!(f, "law {} is listed more than once", law),
+            PolicyPackError::UnknownLaw(law) => write!(f, "law {} is not installed", law),
+            PolicyPackError::InvalidBudget { law } => write!(f, "invalid time budget for law {}", law),
+            PolicyPackError::DuplicateProfile(name) => write!(f, "profile '{}' is defined more than once", name),
+            PolicyPackError::ShutDown => write!(f, "judicial core is shut down"),
+        }
+    }
+}
+
+impl std::error::Error for PolicyPackError {}
+
+/// A validated pack, ready to install on a builder.
+#[derive(Debug)]
+pub(crate) struct ResolvedPack {
+    pub laws: LawSet,
+    pub strictness: Option<Strictness>,
+    pub known_action_types: Vec<String>,
+    pub unknown_action_policy: Option<UnknownActionPolicy>,
+    pub profiles: Vec<(String, PolicyProfile)>,
+}
+
+impl PolicyPack {
+    pub fn from_toml(text: &str) -> Result<Self, PolicyPackError> {
+        toml::from_str(text).map_err(PolicyPackError::Parse)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PolicyPackError> {
+        let text = fs::read_to_string(path).map_err(PolicyPackError::Io)?;
+        Self::from_toml(&text)
+    }
+
+    /// Check the pack against the laws it selects from and build everything
+    /// it installs, so nothing is applied unless all of it is valid.
+    pub(crate) fn resolve(&self, installed: &LawSet) -> Result<ResolvedPack, PolicyPackError> {
+        let mut laws = match &self.laws {
+            Some(numbers) => select(installed, numbers)?,
+            None => installed.clone(),
+        };
+
+        let mut budgeted = BTreeSet::new();
+        for spec in &self.budgets {
+            if spec.timeout_ms == 0 || !budgeted.insert(spec.law) {
+                return Err(PolicyPackError::InvalidBudget { law: spec.law });
+            }
+            if !laws.iter().any(|law| law.number() == spec.law) {
+                return Err(PolicyPackError::UnknownLaw(spec.law));
+            }
+            laws.set_budget(spec.law, spec.budget());
+        }
+
+        let mut names = BTreeSet::new();
+        let mut profiles = Vec::new();
+        for spec in &self.profiles {
+            if !names.insert(spec.name.as_str()) {
+                return Err(PolicyPackError::DuplicateProfile(spec.name.clone()));
+            }
+            let mut profile = PolicyProfile::new(select(&laws, &spec.laws)?);
+            profile.strictness = spec.strictness;
+            profiles.push((spec.name.clone(), profile));
+        }
+
+        Ok(ResolvedPack {
+            laws,
+            strictness: self.strictness,
+            known_action_types: self.known_action_types.clone(),
+            unknown_action_policy: self.unknown_action_policy,
+            profiles,
+        })
+    }
+}
+
+impl BudgetSpec {
+    fn budget(&self) -> LawBudget {
+        let mut budget = LawBudget::new(Duration::from_millis(self.timeout_ms))
+            .on_timeout(self.on_timeout);
+        if let Some(trip_after) = self.trip_after {
+            budget = budget.trip_after(trip_after);
+        }
+        if let Some(cooldown_ms) = self.cooldown_ms {
+            budget = budget.cooldown(Duration::from_millis(cooldown_ms));
+        }
+        budget
+    }
+}
+
+fn select(laws: &LawSet, numbers: &[u32]) -> Result<LawSet, PolicyPackError> {
+    let mut seen = BTreeSet::new();
+    if let Some(&law) = numbers.iter().find(|&&law| !seen.insert(law)) {
+        return Err(PolicyPackError::DuplicateLaw(law));
+    }
+    laws.subset(numbers).map_err(PolicyPackError::UnknownLaw)
+}
@@ -0,0 +1,249 @@
+//! PostgreSQL storage for the ledger and the review queue, behind the
+//! `postgres` feature.
+//!
+//! Cores pointed at the same database share one hash chain and one review
+//! queue. Each append takes a transaction-scoped advisory lock, catches up
+//! with entries other cores wrote, and chains the new entry onto the shared
+//! tail, so the chain stays serialized across instances. Entries and items
+//! are stored as JSON text.
+//!
+//! The client is synchronous and runs its own runtime. Hosts running the
+//! core inside async code should call it from blocking threads.
+
+use crate::ledger::{LedgerBackend, LedgerEntry};
+use crate::review::{ReviewError, ReviewId, ReviewItem, ReviewStatus, ReviewStore};
+use ::postgres::{Client, NoTls};
+use std::fmt;
+use std::io;
+use std::sync::Mutex;
+use tracing::error;
+
+/// Advisory lock key held while appending to the shared ledger.
+pub const LEDGER_LOCK: i64 = 0x4a55_4449_4349_414c;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS judicial_ledger (
+    seq BIGSERIAL PRIMARY KEY,
+    hash TEXT NOT NULL UNIQUE,
+    entry TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS judicial_reviews (
+    id BIGSERIAL PRIMARY KEY,
+    resolved BOOLEAN NOT NULL DEFAULT FALSE,
+    item TEXT NOT NULL
+);
+";
+
+/// A [`LedgerBackend`] on a shared `judicial_ledger` table.
+///
+/// `entries()` reflects the shared ledger as of this core's last append or
+/// flush. Entries that could not be written are kept, retried on the next
+/// append, and reported by `flush()`. Pending entries are written in one
+/// transaction; with deferred writes, appends only queue them and each
+/// flush commits the queue.
+pub struct PostgresBackend {
+    /// Only used through `&mut self`; the mutex makes the backend `Sync`.
+    client: Mutex<Client>,
+    entries: Vec<LedgerEntry>,
+    last_seq: i64,
+    unwritten: Vec<LedgerEntry>,
+    deferred: bool,
+}
+
+impl fmt::Debug for PostgresBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PostgresBackend")
+            .field("entries", &self.entries.len())
+            .field("last_seq", &self.last_seq)
+            .field("unwritten", &self.unwritten.len())
+            .field("deferred", &self.deferred)
+            .finish()
+    }
+}
+
+impl PostgresBackend {
+    /// Connect with a libpq-style connection string, creating the tables if
+    /// needed and loading the shared ledger.
+    pub fn connect(params: &str) -> io::Result<Self> {
+        let mut client = Client::connect(params, NoTls).map_err(io::Error::other)?;
+        client.batch_execute(SCHEMA).map_err(io::Error::other)?;
+        let mut backend = Self {
+            client: Mutex::new(client),
+            entries: Vec::new(),
+            last_seq: 0,
+            unwritten: Vec::new(),
+            deferred: false,
+        };
+        backend.catch_up()?;
+        Ok(backend)
+    }
+
+    /// Load entries other cores appended since the last read.
+    fn catch_up(&mut self) -> io::Result<()> {
+        let rows = self.client.get_mut().unwrap()
+            .query("SELECT seq, entry FROM judicial_ledger WHERE seq > $1 ORDER BY seq", &[&self.last_seq])
+            .map_err(io::Error::other)?;
+        for row in rows {
+            self.entries.push(serde_json::from_str(row.get(1))?);
+            self.last_seq = row.get(0);
+        }
+        Ok(())
+    }
+
+    /// Write every unwritten entry in one transaction, chained onto the
+    /// shared tail. Nothing is written if any insert fails.
+    fn write_unwritten(&mut self) -> io::Result<()> {
+        if self.unwritten.is_empty() {
+            return Ok(());
+        }
+        let mut tx = self.client.get_mut().unwrap().transaction().map_err(io::Error::other)?;
+        tx.execute("SELECT pg_advisory_xact_lock($1)", &[&LEDGER_LOCK]).map_err(io::Error::other)?;
+
+        let rows = tx
+            .query("SELECT seq, entry FROM judicial_ledger WHERE seq > $1 ORDER BY seq", &[&self.last_seq])
+            .map_err(io::Error::other)?;
+        let mut last_seq = self.last_seq;
+        let mut caught_up = Vec::with_capacity(rows.len() + self.unwritten.len());
+        for row in rows {
+            caught_up.push(serde_json::from_str::<LedgerEntry>(row.get(1))?);
+            last_seq = row.get(0);
+        }
+
+        let mut tail = caught_up.last().or(self.entries.last()).map(|e| e.hash.clone());
+        for entry in &self.unwritten {
+            let mut entry = entry.clone();
+            if entry.previous_hash != tail {
+                entry.previous_hash = tail;
+                entry.hash = entry.compute_hash();
+            }
+            let row = tx
+                .query_one(
+                    "INSERT INTO judicial_ledger (hash, entry) VALUES ($1, $2) RETURNING seq",
+                    &[&entry.hash, &serde_json::to_string(&entry)?],
+                )
+                .map_err(io::Error::other)?;
+            last_seq = last_seq.max(row.get(0));
+            tail = Some(entry.hash.clone());
+            caught_up.push(entry);
+        }
+        tx.commit().map_err(io::Error::other)?;
+
+        self.entries.extend(caught_up);
+        self.last_seq = last_seq;
+        self.unwritten.clear();
+        Ok(())
+    }
+}
+
+impl LedgerBackend for PostgresBackend {
+    fn append(&mut self, entry: LedgerEntry) {
+        self.unwritten.push(entry);
+        if self.deferred {
+            return;
+        }
+        if let Err(e) = self.write_unwritten() {
+            error!(error = %e, unwritten = self.unwritten.len(), "ledger entry not written to postgres");
+        }
+    }
+
+    fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    /// Retry unwritten entries, then load entries other cores appended.
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_unwritten()?;
+        self.catch_up()
+    }
+
+    fn defer_writes(&mut self, defer: bool) {
+        self.deferred = defer;
+    }
+}
+
+/// A [`ReviewStore`] on a shared `judicial_reviews` table.
+pub struct PostgresReviewStore {
+    client: Mutex<Client>,
+}
+
+impl fmt::Debug for PostgresReviewStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PostgresReviewStore").finish_non_exhaustive()
+    }
+}
+
+impl PostgresReviewStore {
+    /// Connect with a libpq-style connection string, creating the tables if
+    /// needed.
+    pub fn connect(params: &str) -> io::Result<Self> {
+        let mut client = Client::connect(params, NoTls).map_err(io::Error::other)?;
+        client.batch_execute(SCHEMA).map_err(io::Error::other)?;
+        Ok(Self { client: Mutex::new(client) })
+    }
+}
+
+fn storage(error: impl fmt::Display) -> ReviewError {
+    ReviewError::Storage(error.to_string())
+}
+
+fn parse_item(json: &str) -> Result<ReviewItem, ReviewError> {
+    serde_json::from_str(json).map_err(storage)
+}
+
+impl ReviewStore for PostgresReviewStore {
+    fn insert(&self, mut item: ReviewItem) -> Result<ReviewItem, ReviewError> {
+        let mut client = self.client.lock().unwrap();
+        let id: i64 = client
+            .query_one("SELECT nextval(pg_get_serial_sequence('judicial_reviews', 'id'))", &[])
+            .map_err(storage)?
+            .get(0);
+        item.id = id as ReviewId;
+        client
+            .execute(
+                "INSERT INTO judicial_reviews (id, resolved, item) VALUES ($1, FALSE, $2)",
+                &[&id, &serde_json::to_string(&item).map_err(storage)?],
+            )
+            .map_err(storage)?;
+        Ok(item)
+    }
+
+    fn get(&self, id: ReviewId) -> Result<Option<ReviewItem>, ReviewError> {
+        let row = self.client.lock().unwrap()
+            .query_opt("SELECT item FROM judicial_reviews WHERE id = $1", &[&(id as i64)])
+            .map_err(storage)?;
+        row.map(|row| parse_item(row.get(0))).transpose()
+    }
+
+    fn unresolved(&self) -> Result<Vec<ReviewItem>, ReviewError> {
+        self.client.lock().unwrap()
+            .query("SELECT item FROM judicial_reviews WHERE NOT resolved ORDER BY id", &[])
+            .map_err(storage)?
+            .iter()
+            .map(|row| parse_item(row.get(0)))
+            .collect()
+    }
+
+    fn update(
+        &self,
+        id: ReviewId,
+        change: &mut dyn FnMut(&mut ReviewItem) -> Result<(), ReviewError>,
+    ) -> Result<ReviewItem, ReviewError> {
+        let mut client = self.client.lock().unwrap();
+        let mut tx = client.transaction().map_err(storage)?;
+        let row = tx
+            .query_opt("SELECT item FROM judicial_reviews WHERE id = $1 FOR UPDATE", &[&(id as i64)])
+            .map_err(storage)?
+            .ok_or(ReviewError::NotFound(id))?;
+        let mut item = parse_item(row.get(0))?;
+        change(&mut item)?;
+
+        let resolved = matches!(item.status, ReviewStatus::Resolved { .. });
+        tx.execute(
+            "UPDATE judicial_reviews SET resolved = $2, item = $3 WHERE id = $1",
+            &[&(id as i64), &resolved, &serde_json::to_string(&item).map_err(storage)?],
+        )
+        .map_err(storage)?;
+        tx.commit().map_err(storage)?;
+        Ok(item)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::review::{ReviewDecision, ReviewId, ReviewItem, ReviewStatus};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Resolved reviews keyed by action type and quarantine reason.
+type DecisionLog = HashMap<(String, String), Vec<(ReviewId, ReviewDecision)>>;
+
+/// When past review decisions may settle a new quarantine automatically.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrecedentPolicy {
+    /// Resolved reviews required for the same action type and reason.
+    pub min_decisions: usize,
+    /// Share of those decisions that must agree, in `0.0..=1.0`.
+    pub confidence_threshold: f64,
+}
+
+impl Default for PrecedentPolicy {
+    fn default() -> Self {
+        Self { min_decisions: 3, confidence_threshold: 0.9 }
+    }
+}
+
+/// A past decision that applies to a new quarantine.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Precedent {
+    pub decision: ReviewDecision,
+    /// Most recent review that reached this decision.
+    pub review: ReviewId,
+    pub confidence: f64,
+    pub decisions: usize,
+}
+
+/// Human review decisions indexed by action type and quarantine reason.
+#[derive(Debug)]
+pub struct Precedents {
+    policy: PrecedentPolicy,
+    decisions: Mutex<DecisionLog>,
+}
+
+impl Precedents {
+    pub fn new(policy: PrecedentPolicy) -> Self {
+        Self { policy, decisions: Mutex::new(HashMap::new()) }
+    }
+
+    pub fn policy(&self) -> PrecedentPolicy {
+        self.policy
+    }
+
+    /// Learn from a resolved review. Unresolved items are ignored.
+    pub fn record(&self, item: &ReviewItem) {
+        if let ReviewStatus::Resolved { decision, .. } = item.status {
+            let key = (item.action.action_type.clone(), item.reason.clone());
+            self.decisions.lock().unwrap().entry(key).or_default().push((item.id, decision));
+        }
+    }
+
+    /// The decision to reuse for a quarantine, if past reviews agree strongly
+    /// enough. Approval needs a strict majority, so a tie leans to rejection.
+    pub fn find(&self, action_type: &str, reason: &str) -> Option<Precedent> {
+        let decisions = self.decisions.lock().unwrap();
+        let history = decisions.get(&(action_type.to_string(), reason.to_string()))?;
+        if history.len() < self.policy.min_decisions.max(1) {
+            return None;
+        }
+
+        let approvals = history.iter().filter(|(_, d)| *d == ReviewDecision::Approve).count();
+        let (decision, agreeing) = if approvals * 2 > history.len() {
+            (ReviewDecision::Approve, approvals)
+        } else {
+            (ReviewDecision::Reject, history.len() - approvals)
+        };
+
+        let confidence = agreeing as f64 / history.len() as f64;
+        if confidence < self.policy.confidence_threshold {
+            return None;
+        }
+
+        let review = history.iter().rev().find(|(_, d)| *d == decision).map(|(id, _)| *id)?;
+        Some(Precedent { decision, review, confidence, decisions: history.len() })
+    }
+}
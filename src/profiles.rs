@@ -0,0 +1,63 @@
+use crate::laws::LawSet;
+use crate::strictness::Strictness;
+use crate::verdicts::SystemAction;
+
+/// Laws and strictness for one named environment.
+#[derive(Debug, Clone)]
+pub struct PolicyProfile {
+    pub laws: LawSet,
+    /// Overrides the core's strictness for actions in this profile.
+    pub strictness: Option<Strictness>,
+}
+
+impl PolicyProfile {
+    pub fn new(laws: LawSet) -> Self {
+        Self { laws, strictness: None }
+    }
+
+    pub fn with_strictness(mut self, strictness: Strictness) -> Self {
+        self.strictness = Some(strictness);
+        self
+    }
+}
+
+/// Named profiles, matched against an action's context.
+///
+/// The first profile, in registration order, whose name occurs in the
+/// context applies. Actions matching no profile use the core's own laws.
+#[derive(Debug, Clone, Default)]
+pub struct PolicyProfiles {
+    profiles: Vec<(String, PolicyProfile)>,
+}
+
+impl PolicyProfiles {
+    pub fn insert(&mut self, name: impl Into<String>, profile: PolicyProfile) {
+        let name = name.into();
+        match self.profiles.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, existing)) => *existing = profile,
+            None => self.profiles.push((name, profile)),
+        }
+    }
+
+    pub fn select(&self, action: &SystemAction) -> Option<(&str, &PolicyProfile)> {
+        self.profiles.iter()
+            .find(|(name, _)| action.context.contains(name.as_str()))
+            .map(|(name, profile)| (name.as_str(), profile))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &PolicyProfile)> {
+        self.profiles.iter().map(|(name, profile)| (name.as_str(), profile))
+    }
+
+    pub fn len(&self) -> usize {
+        self.profiles.len()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.iter().map(|(name, _)| name.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+}
@@ -0,0 +1,176 @@
+use crate::verdicts::SystemAction;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Actor name used for actions that do not carry one.
+pub const ANONYMOUS_ACTOR: &str = "anonymous";
+
+/// At most `max_rulings` per `window`, counted per actor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max_rulings: u32,
+    pub window: Duration,
+}
+
+impl RateLimit {
+    pub fn per_second(max_rulings: u32) -> Self {
+        Self { max_rulings, window: Duration::seconds(1) }
+    }
+
+    pub fn per_minute(max_rulings: u32) -> Self {
+        Self { max_rulings, window: Duration::minutes(1) }
+    }
+}
+
+/// Which actions a [`RateLimit`] applies to.
+///
+/// Actors are counted by the action's `actor`, which only an
+/// [`IdentityProvider`](crate::IdentityProvider) makes trustworthy. Without
+/// one, a caller can spread its actions over made-up actors and never reach
+/// a per-actor limit; use [`AllActors`](Self::AllActors) or install a
+/// provider.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RateLimitScope {
+    /// Every action of every actor.
+    AllActors,
+    /// Every action of one actor.
+    Actor(String),
+    /// One action type, counted separately for each actor.
+    ActionType(String),
+    ActorAndActionType(String, String),
+}
+
+impl RateLimitScope {
+    fn matches(&self, actor: &str, action_type: &str) -> bool {
+        match self {
+            RateLimitScope::AllActors => true,
+            RateLimitScope::Actor(a) => a == actor,
+            RateLimitScope::ActionType(t) => t == action_type,
+            RateLimitScope::ActorAndActionType(a, t) => a == actor && t == action_type,
+        }
+    }
+
+    fn bucket(&self, actor: &str, action_type: &str) -> (String, Option<String>) {
+        match self {
+            RateLimitScope::AllActors | RateLimitScope::Actor(_) => (actor.to_string(), None),
+            RateLimitScope::ActionType(_) | RateLimitScope::ActorAndActionType(_, _) => {
+                (actor.to_string(), Some(action_type.to_string()))
+            }
+        }
+    }
+}
+
+/// Throttling counters.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RateLimitStats {
+    pub allowed: u64,
+    pub throttled: u64,
+    pub throttled_by_actor: HashMap<String, u64>,
+    pub throttled_by_action_type: HashMap<String, u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Window {
+    started: DateTime<Utc>,
+    count: u32,
+    length: Duration,
+}
+
+impl Window {
+    fn is_over(&self, now: DateTime<Utc>) -> bool {
+        now - self.started >= self.length
+    }
+}
+
+#[derive(Debug, Default)]
+struct LimiterState {
+    windows: HashMap<(RateLimitScope, String, Option<String>), Window>,
+    /// When windows that have run out are next dropped.
+    next_sweep: Option<DateTime<Utc>>,
+    stats: RateLimitStats,
+}
+
+/// Fixed-window ruling limits. Every matching limit must have room for the
+/// action to proceed; throttled attempts do not consume quota. Windows that
+/// have run out are dropped as rulings arrive, at most once per shortest
+/// window, so idle actors are not tracked forever.
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    limits: Vec<(RateLimitScope, RateLimit)>,
+    state: Mutex<LimiterState>,
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn limit(&mut self, scope: RateLimitScope, limit: RateLimit) {
+        self.limits.push((scope, limit));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.limits.is_empty()
+    }
+
+    /// Count the action, or return why it is throttled.
+    pub fn check(&self, action: &SystemAction, now: DateTime<Utc>) -> Result<(), String> {
+        let actor = action.actor.as_deref().unwrap_or(ANONYMOUS_ACTOR);
+        let action_type = action.action_type.as_str();
+        let mut state = self.state.lock().unwrap();
+        self.sweep(&mut state, now);
+
+        let matching: Vec<_> = self.limits.iter()
+            .filter(|(scope, _)| scope.matches(actor, action_type))
+            .map(|(scope, limit)| {
+                let (bucket_actor, bucket_type) = scope.bucket(actor, action_type);
+                ((scope.clone(), bucket_actor, bucket_type), *limit)
+            })
+            .collect();
+
+        for (key, limit) in &matching {
+            let fresh = Window { started: now, count: 0, length: limit.window };
+            let window = state.windows.entry(key.clone()).or_insert(fresh);
+            if window.is_over(now) {
+                *window = fresh;
+            }
+            if window.count >= limit.max_rulings {
+                state.stats.throttled += 1;
+                *state.stats.throttled_by_actor.entry(actor.to_string()).or_default() += 1;
+                *state.stats.throttled_by_action_type.entry(action_type.to_string()).or_default() += 1;
+                return Err(format!(
+                    "rate limit: {} exceeded {} rulings per {}ms",
+                    actor, limit.max_rulings, limit.window.num_milliseconds()
+                ));
+            }
+        }
+
+        for (key, _) in matching {
+            if let Some(window) = state.windows.get_mut(&key) {
+                window.count += 1;
+            }
+        }
+        state.stats.allowed += 1;
+        Ok(())
+    }
+
+    fn sweep(&self, state: &mut LimiterState, now: DateTime<Utc>) {
+        if state.next_sweep.is_some_and(|at| now < at) {
+            return;
+        }
+        state.windows.retain(|_, window| !window.is_over(now));
+        let shortest = self.limits.iter().map(|(_, limit)| limit.window).min().unwrap_or_default();
+        state.next_sweep = now.checked_add_signed(shortest);
+    }
+
+    /// Number of rate-limit windows currently tracked.
+    pub fn bucket_count(&self) -> usize {
+        self.state.lock().unwrap().windows.len()
+    }
+
+    pub fn stats(&self) -> RateLimitStats {
+        self.state.lock().unwrap().stats.clone()
+    }
+}
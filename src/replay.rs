@@ -0,0 +1,22 @@
+use crate::verdicts::{SystemAction, Verdict};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// A ledgered ruling that the current laws would decide the other way.
+#[derive(Debug, Clone, Serialize)]
+pub struct Divergence {
+    /// Hash of the ledger entry that recorded the original ruling.
+    pub hash: String,
+    pub timestamp: DateTime<Utc>,
+    pub action: SystemAction,
+    /// Verdict as recorded in the ledger.
+    pub recorded: String,
+    pub current: Verdict,
+}
+
+impl Divergence {
+    /// Approved then, rejected now.
+    pub fn is_tightening(&self) -> bool {
+        !self.current.is_approved()
+    }
+}
@@ -0,0 +1,342 @@
+use crate::courts::Court;
+use crate::verdicts::SystemAction;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::error;
+
+pub type ReviewId = u64;
+
+/// Reviewer recorded on items settled by [`ReviewSla`] expiry.
+pub const TIMEOUT_REVIEWER: &str = "sla-timeout";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReviewDecision {
+    Approve,
+    Reject,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ReviewStatus {
+    Pending,
+    Claimed {
+        reviewer: String,
+        claimed_at: DateTime<Utc>,
+    },
+    Resolved {
+        reviewer: String,
+        decision: ReviewDecision,
+        rationale: String,
+        resolved_at: DateTime<Utc>,
+    },
+}
+
+/// A quarantined action awaiting a human decision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewItem {
+    pub id: ReviewId,
+    pub action: SystemAction,
+    pub reason: String,
+    pub submitted_at: DateTime<Utc>,
+    /// Hash of the ledger entry that quarantined the action.
+    pub ledger_hash: Option<String>,
+    pub status: ReviewStatus,
+}
+
+/// How an overdue review is settled.
+#[derive(Debug, Clone)]
+pub enum ReviewFallback {
+    Reject,
+    /// Let the court's verdict on the action decide.
+    Escalate(Arc<Court>),
+}
+
+/// Time allowed for a human decision before the fallback applies.
+#[derive(Debug, Clone)]
+pub struct ReviewSla {
+    pub timeout: Duration,
+    pub fallback: ReviewFallback,
+    /// Alert observers this long before the deadline.
+    pub warn_before: Option<Duration>,
+    /// Link to a review in the reviewers' tool; `{id}` is replaced by the
+    /// review id.
+    pub link_template: Option<String>,
+}
+
+impl ReviewSla {
+    pub fn new(timeout: Duration, fallback: ReviewFallback) -> Self {
+        Self { timeout, fallback, warn_before: None, link_template: None }
+    }
+
+    pub fn warn_before(mut self, before: Duration) -> Self {
+        self.warn_before = Some(before);
+        self
+    }
+
+    pub fn link_template(mut self, template: impl Into<String>) -> Self {
+        self.link_template = Some(template.into());
+        self
+    }
+
+    pub(crate) fn alert(&self, stage: SlaStage, item: &ReviewItem) -> SlaAlert {
+        SlaAlert {
+            stage,
+            deadline: item.submitted_at + chrono::Duration::from_std(self.timeout).unwrap_or(chrono::Duration::MAX),
+            link: self.link_template.as_ref().map(|t| t.replace("{id}", &item.id.to_string())),
+            item: item.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlaStage {
+    /// Within [`ReviewSla::warn_before`] of the deadline.
+    Approaching,
+    /// Past the deadline; the fallback has settled the review.
+    Breached,
+}
+
+/// A review nearing or past its [`ReviewSla`] deadline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlaAlert {
+    pub stage: SlaStage,
+    pub deadline: DateTime<Utc>,
+    /// The review as it stood when the alert was raised; its `reason`
+    /// summarises why the action was quarantined.
+    pub item: ReviewItem,
+    pub link: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReviewError {
+    NotFound(ReviewId),
+    AlreadyClaimed { reviewer: String },
+    /// Resolving requires claiming first.
+    NotClaimed,
+    AlreadyResolved,
+    /// The [`ReviewStore`] failed.
+    Storage(String),
+}
+
+impl fmt::Display for ReviewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReviewError::NotFound(id) => write!(f, "review #{} does not exist", id),
+            ReviewError::AlreadyClaimed { reviewer } => write!(f, "review already claimed by {}", reviewer),
+            ReviewError::NotClaimed => write!(f, "review must be claimed before it is resolved"),
+            ReviewError::AlreadyResolved => write!(f, "review is already resolved"),
+            ReviewError::Storage(message) => write!(f, "review storage failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ReviewError {}
+
+/// Storage for review items. Lifecycle rules stay in [`ReviewQueue`].
+pub trait ReviewStore: fmt::Debug + Send + Sync {
+    /// Store a new item under a fresh id and return it with that id.
+    fn insert(&self, item: ReviewItem) -> Result<ReviewItem, ReviewError>;
+
+    fn get(&self, id: ReviewId) -> Result<Option<ReviewItem>, ReviewError>;
+
+    /// Unresolved items, oldest first.
+    fn unresolved(&self) -> Result<Vec<ReviewItem>, ReviewError>;
+
+    /// Apply `change` to an item atomically, storing it only if `change`
+    /// succeeds.
+    fn update(
+        &self,
+        id: ReviewId,
+        change: &mut dyn FnMut(&mut ReviewItem) -> Result<(), ReviewError>,
+    ) -> Result<ReviewItem, ReviewError>;
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    next_id: ReviewId,
+    items: BTreeMap<ReviewId, ReviewItem>,
+}
+
+/// Keeps review items in process memory. The default store.
+#[derive(Debug, Default)]
+pub struct MemoryReviewStore {
+    state: Mutex<QueueState>,
+}
+
+impl ReviewStore for MemoryReviewStore {
+    fn insert(&self, mut item: ReviewItem) -> Result<ReviewItem, ReviewError> {
+        let mut state = self.state.lock().unwrap();
+        state.next_id += 1;
+        item.id = state.next_id;
+        state.items.insert(item.id, item.clone());
+        Ok(item)
+    }
+
+    fn get(&self, id: ReviewId) -> Result<Option<ReviewItem>, ReviewError> {
+        Ok(self.state.lock().unwrap().items.get(&id).cloned())
+    }
+
+    fn unresolved(&self) -> Result<Vec<ReviewItem>, ReviewError> {
+        Ok(self.state.lock().unwrap().items.values()
+            .filter(|item| !matches!(item.status, ReviewStatus::Resolved { .. }))
+            .cloned()
+            .collect())
+    }
+
+    fn update(
+        &self,
+        id: ReviewId,
+        change: &mut dyn FnMut(&mut ReviewItem) -> Result<(), ReviewError>,
+    ) -> Result<ReviewItem, ReviewError> {
+        let mut state = self.state.lock().unwrap();
+        let item = state.items.get_mut(&id).ok_or(ReviewError::NotFound(id))?;
+        let mut changed = item.clone();
+        change(&mut changed)?;
+        *item = changed.clone();
+        Ok(changed)
+    }
+}
+
+/// Quarantined actions and their review lifecycle: pending → claimed → resolved.
+///
+/// Resolve through [`JudicialCore::resolve_review`](crate::JudicialCore::resolve_review)
+/// to have the decision recorded in the ledger. Items live in a
+/// [`ReviewStore`], in memory unless the core was built with another.
+#[derive(Debug)]
+pub struct ReviewQueue {
+    store: Box<dyn ReviewStore>,
+    subscribers: Mutex<Vec<Sender<ReviewItem>>>,
+}
+
+impl Default for ReviewQueue {
+    fn default() -> Self {
+        Self::with_store(Box::new(MemoryReviewStore::default()))
+    }
+}
+
+impl ReviewQueue {
+    pub fn with_store(store: Box<dyn ReviewStore>) -> Self {
+        Self { store, subscribers: Mutex::default() }
+    }
+
+    pub fn submit(
+        &self,
+        action: SystemAction,
+        reason: String,
+        ledger_hash: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Result<ReviewId, ReviewError> {
+        let item = self.store.insert(ReviewItem {
+            id: 0,
+            action,
+            reason,
+            submitted_at: now,
+            ledger_hash,
+            status: ReviewStatus::Pending,
+        })?;
+
+        self.subscribers.lock().unwrap()
+            .retain(|subscriber| subscriber.send(item.clone()).is_ok());
+        Ok(item.id)
+    }
+
+    /// Receive every item submitted from now on, as it is submitted.
+    /// Dropping the receiver unsubscribes.
+    pub fn subscribe(&self) -> Receiver<ReviewItem> {
+        let (sender, receiver) = mpsc::channel();
+        self.subscribers.lock().unwrap().push(sender);
+        receiver
+    }
+
+    /// The item, or `None` when it does not exist or the store failed.
+    pub fn get(&self, id: ReviewId) -> Option<ReviewItem> {
+        self.store.get(id).unwrap_or_else(|e| {
+            error!(error = %e, review = id, "review lookup failed");
+            None
+        })
+    }
+
+    /// Unresolved items, oldest first. Claimed items are included. Empty when
+    /// the store failed.
+    pub fn pending(&self) -> Vec<ReviewItem> {
+        self.store.unresolved().unwrap_or_else(|e| {
+            error!(error = %e, "pending reviews unavailable");
+            Vec::new()
+        })
+    }
+
+    pub fn claim(&self, id: ReviewId, reviewer: &str, now: DateTime<Utc>) -> Result<ReviewItem, ReviewError> {
+        self.store.update(id, &mut |item| {
+            match &item.status {
+                ReviewStatus::Pending => {}
+                ReviewStatus::Claimed { reviewer, .. } => {
+                    return Err(ReviewError::AlreadyClaimed { reviewer: reviewer.clone() });
+                }
+                ReviewStatus::Resolved { .. } => return Err(ReviewError::AlreadyResolved),
+            }
+            item.status = ReviewStatus::Claimed { reviewer: reviewer.to_string(), claimed_at: now };
+            Ok(())
+        })
+    }
+
+    pub fn resolve(
+        &self,
+        id: ReviewId,
+        decision: ReviewDecision,
+        rationale: &str,
+        now: DateTime<Utc>,
+    ) -> Result<ReviewItem, ReviewError> {
+        self.store.update(id, &mut |item| {
+            let reviewer = match &item.status {
+                ReviewStatus::Pending => return Err(ReviewError::NotClaimed),
+                ReviewStatus::Claimed { reviewer, .. } => reviewer.clone(),
+                ReviewStatus::Resolved { .. } => return Err(ReviewError::AlreadyResolved),
+            };
+            item.status = ReviewStatus::Resolved {
+                reviewer,
+                decision,
+                rationale: rationale.to_string(),
+                resolved_at: now,
+            };
+            Ok(())
+        })
+    }
+
+    /// Unresolved items submitted at or before `cutoff`, oldest first.
+    pub fn overdue(&self, cutoff: DateTime<Utc>) -> Vec<ReviewItem> {
+        self.pending().into_iter()
+            .filter(|item| item.submitted_at <= cutoff)
+            .collect()
+    }
+
+    /// Settle an unresolved item without a reviewer, whether or not it was claimed.
+    pub fn expire(
+        &self,
+        id: ReviewId,
+        decision: ReviewDecision,
+        rationale: &str,
+        now: DateTime<Utc>,
+    ) -> Result<ReviewItem, ReviewError> {
+        self.store.update(id, &mut |item| {
+            if let ReviewStatus::Resolved { .. } = item.status {
+                return Err(ReviewError::AlreadyResolved);
+            }
+            item.status = ReviewStatus::Resolved {
+                reviewer: TIMEOUT_REVIEWER.to_string(),
+                decision,
+                rationale: rationale.to_string(),
+                resolved_at: now,
+            };
+            Ok(())
+        })
+    }
+
+    pub fn pending_count(&self) -> usize {
+        self.pending().len()
+    }
+}
@@ -0,0 +1,78 @@
+//! Payload schemas per action type, behind the `schemas` feature.
+//!
+//! Payloads of action types with a registered JSON Schema must be JSON
+//! documents valid against it. `rule()` checks this before any law runs and
+//! answers [`Verdict::Malformed`](crate::Verdict::Malformed) otherwise.
+//! Action types without a schema are not checked.
+
+use crate::verdicts::SystemAction;
+use jsonschema::Validator;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A schema that could not be compiled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError {
+    pub action_type: String,
+    pub message: String,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid schema for {}: {}", self.action_type, self.message)
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+#[derive(Default)]
+pub struct ActionSchemas {
+    validators: HashMap<String, Validator>,
+}
+
+impl fmt::Debug for ActionSchemas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActionSchemas")
+            .field("action_types", &self.validators.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl ActionSchemas {
+    /// Register `schema` for `action_type`, replacing any earlier one.
+    pub fn register(&mut self, action_type: impl Into<String>, schema: &Value) -> Result<(), SchemaError> {
+        let action_type = action_type.into();
+        let validator = jsonschema::validator_for(schema).map_err(|e| SchemaError {
+            action_type: action_type.clone(),
+            message: e.to_string(),
+        })?;
+        self.validators.insert(action_type, validator);
+        Ok(())
+    }
+
+    pub fn is_registered(&self, action_type: &str) -> bool {
+        self.validators.contains_key(action_type)
+    }
+
+    /// Check the payload, returning the reason it is malformed.
+    pub fn validate(&self, action: &SystemAction) -> Result<(), String> {
+        let Some(validator) = self.validators.get(&action.action_type) else {
+            return Ok(());
+        };
+        let payload: Value = serde_json::from_str(&action.payload)
+            .map_err(|e| format!("{} payload is not JSON: {}", action.action_type, e))?;
+
+        let errors: Vec<String> = validator.iter_errors(&payload)
+            .map(|e| match e.instance_path.to_string() {
+                path if path.is_empty() => e.to_string(),
+                path => format!("{}: {}", path, e),
+            })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("{} payload does not match its schema: {}", action.action_type, errors.join("; ")))
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::judicial_core::JudicialCore;
+use crate::verdicts::{SystemAction, Verdict};
+
+/// A [`JudicialCore`] handle pre-bound to a context and actor.
+///
+/// Every action ruled through the handle carries the scope's context, so
+/// policy profiles select on it, and its actor, so rate limits count it.
+#[derive(Debug, Clone)]
+pub struct ScopedCourt<'a> {
+    core: &'a JudicialCore,
+    context: String,
+    actor: String,
+}
+
+impl<'a> ScopedCourt<'a> {
+    pub(crate) fn new(core: &'a JudicialCore, context: String, actor: String) -> Self {
+        Self { core, context, actor }
+    }
+
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+
+    pub fn actor(&self) -> &str {
+        &self.actor
+    }
+
+    pub fn core(&self) -> &'a JudicialCore {
+        self.core
+    }
+
+    pub fn rule(&self, action_type: impl Into<String>, payload: impl Into<String>) -> Verdict {
+        self.core.rule(self.action(action_type, payload, ""))
+    }
+
+    /// Rule with extra context flags (e.g. "encrypted") appended to the scope's context.
+    pub fn rule_with(
+        &self,
+        action_type: impl Into<String>,
+        payload: impl Into<String>,
+        flags: &str,
+    ) -> Verdict {
+        self.core.rule(self.action(action_type, payload, flags))
+    }
+
+    /// Build the action this scope would submit, without ruling on it.
+    pub fn action(&self, action_type: impl Into<String>, payload: impl Into<String>, flags: &str) -> SystemAction {
+        let context = if flags.is_empty() {
+            self.context.clone()
+        } else {
+            format!("{},{}", self.context, flags)
+        };
+        SystemAction::new(action_type, payload, context).with_actor(self.actor.clone())
+    }
+}
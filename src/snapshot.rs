@@ -0,0 +1,68 @@
+use crate::judicial_core::Policy;
+use crate::strictness::Strictness;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+
+/// Checkpoint of a [`JudicialCore`](crate::JudicialCore)'s governance state.
+///
+/// A snapshot taken in this process also holds the policy then in force
+/// (laws, strictness, profiles and action types) and restores all of it.
+/// One that has been serialized and read back holds only the fields below,
+/// so restoring it resets strictness and requires the same laws.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoreSnapshot {
+    pub taken_at: DateTime<Utc>,
+    pub strictness: Strictness,
+    /// Numbers of the active laws, in evaluation order.
+    pub laws: Vec<u32>,
+    /// Number of ledger entries when the snapshot was taken.
+    pub ledger_len: usize,
+    /// Hash of the last ledger entry when the snapshot was taken.
+    pub ledger_head: Option<String>,
+    #[serde(skip)]
+    pub(crate) policy: RetainedPolicy,
+}
+
+/// The policy a snapshot was taken under, if it was taken in this process.
+/// Ignored when comparing snapshots; the public fields describe it.
+#[derive(Clone, Default)]
+pub(crate) struct RetainedPolicy(pub(crate) Option<Arc<Policy>>);
+
+impl PartialEq for RetainedPolicy {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl fmt::Debug for RetainedPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.0.is_some() { "Retained" } else { "NotRetained" })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The core enforces a different law set than a snapshot that does not
+    /// hold its policy.
+    LawSetMismatch { expected: Vec<u32>, found: Vec<u32> },
+    /// The snapshot's ledger position is not part of this core's ledger.
+    ForeignLedger,
+    /// The core has been shut down and its ledger is closed.
+    ShutDown,
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::LawSetMismatch { expected, found } => {
+                write!(f, "snapshot laws {:?} do not match active laws {:?}", expected, found)
+            }
+            SnapshotError::ForeignLedger => write!(f, "snapshot was taken from a different ledger"),
+            SnapshotError::ShutDown => write!(f, "judicial core is shut down"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
@@ -0,0 +1,82 @@
+use crate::rate_limit::RateLimitStats;
+use crate::verdicts::Verdict;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Ruling counters returned by `JudicialCore::statistics()`.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Statistics {
+    pub rulings: u64,
+    /// Rulings per verdict kind, keyed by [`Verdict::label`].
+    pub verdicts: BTreeMap<String, u64>,
+    /// Rulings each law was violated in, whether or not it decided the
+    /// verdict, as the compliance report counts them.
+    pub law_fired: BTreeMap<u32, u64>,
+    /// Rejections per action type, most rejected first.
+    pub rejected_action_types: Vec<(String, u64)>,
+    pub average_latency_us: f64,
+    pub max_latency_us: u64,
+    pub rate_limits: RateLimitStats,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    rulings: u64,
+    verdicts: BTreeMap<String, u64>,
+    law_fired: BTreeMap<u32, u64>,
+    rejected_action_types: HashMap<String, u64>,
+    total_latency: Duration,
+    max_latency: Duration,
+}
+
+/// Thread-safe accumulator behind [`Statistics`].
+#[derive(Debug, Default)]
+pub struct StatsCollector {
+    counters: Mutex<Counters>,
+}
+
+impl StatsCollector {
+    /// Count a ruling. `laws` are the laws it cited, each counted once.
+    pub fn record(&self, action_type: &str, verdict: &Verdict, laws: &[u32], latency: Duration) {
+        let mut c = self.counters.lock().unwrap();
+        c.rulings += 1;
+        *c.verdicts.entry(verdict.label().to_string()).or_default() += 1;
+        for (i, law) in laws.iter().enumerate() {
+            if !laws[..i].contains(law) {
+                *c.law_fired.entry(*law).or_default() += 1;
+            }
+        }
+        if !verdict.is_approved() {
+            *c.rejected_action_types.entry(action_type.to_string()).or_default() += 1;
+        }
+        c.total_latency += latency;
+        c.max_latency = c.max_latency.max(latency);
+    }
+
+    pub fn snapshot(&self, rate_limits: RateLimitStats) -> Statistics {
+        let c = self.counters.lock().unwrap();
+
+        let mut rejected_action_types: Vec<(String, u64)> = c.rejected_action_types.iter()
+            .map(|(t, n)| (t.clone(), *n))
+            .collect();
+        rejected_action_types.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        let average_latency_us = if c.rulings == 0 {
+            0.0
+        } else {
+            c.total_latency.as_secs_f64() * 1_000_000.0 / c.rulings as f64
+        };
+
+        Statistics {
+            rulings: c.rulings,
+            verdicts: c.verdicts.clone(),
+            law_fired: c.law_fired.clone(),
+            rejected_action_types,
+            average_latency_us,
+            max_latency_us: c.max_latency.as_micros() as u64,
+            rate_limits,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How aggressively the Master Pair is enforced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Strictness {
+    /// Law 2 violations are downgraded to warnings. Law 1 stays absolute.
+    Permissive,
+    /// Laws fire as written and warnings are not acted on, so verdicts are
+    /// those of the Master Pair alone.
+    #[default]
+    Standard,
+    /// Warnings become rejections, so exemptions must be declared as context flags.
+    Paranoid,
+}
+
+impl fmt::Display for Strictness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Strictness::Permissive => "permissive",
+            Strictness::Standard => "standard",
+            Strictness::Paranoid => "paranoid",
+        };
+        f.write_str(name)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::judicial_core::JudicialCore;
+use crate::verdicts::{SystemAction, Verdict};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use tracing::info;
+
+/// A tool invocation as agent frameworks report it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCall {
+    pub tool: String,
+    #[serde(default)]
+    pub arguments: Value,
+    pub agent_id: String,
+    /// Passed on to the core's identity provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<String>,
+}
+
+/// What the agent should do with a tool call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "decision", rename_all = "snake_case")]
+pub enum ToolDecision {
+    Allow {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        warning: Option<String>,
+    },
+    Deny { reason: String },
+    /// Run the tool with these arguments instead. They have been ruled on
+    /// and approved.
+    Modify { arguments: Value, reason: String },
+}
+
+impl ToolDecision {
+    pub fn is_allowed(&self) -> bool {
+        !matches!(self, ToolDecision::Deny { .. })
+    }
+}
+
+type Rewriter = dyn Fn(&ToolCall, &str) -> Option<Value> + Send + Sync;
+
+/// Governance hook for agent frameworks: rules on tool calls before they run.
+///
+/// Each call becomes a [`SystemAction`] whose action type is the tool name
+/// (or its mapped action type), whose payload is the arguments as JSON and
+/// whose actor is the agent. With a rewriter installed, a rejection that
+/// carries a suggestion is offered to it; corrected arguments are ruled on
+/// in turn and, if approved, returned as [`ToolDecision::Modify`].
+pub struct ToolCallGuard {
+    core: Arc<JudicialCore>,
+    context: String,
+    action_types: HashMap<String, String>,
+    rewriter: Option<Box<Rewriter>>,
+}
+
+impl ToolCallGuard {
+    /// Actions carry the context `tool_call`.
+    pub fn new(core: Arc<JudicialCore>) -> Self {
+        Self {
+            core,
+            context: "tool_call".into(),
+            action_types: HashMap::new(),
+            rewriter: None,
+        }
+    }
+
+    /// Context given to every action, so policy profiles can select on it.
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.context = context.into();
+        self
+    }
+
+    /// Rule on calls to `tool` as `action_type`, e.g. `shell` as `FILE_DELETE`.
+    pub fn map_tool(mut self, tool: impl Into<String>, action_type: impl Into<String>) -> Self {
+        self.action_types.insert(tool.into(), action_type.into());
+        self
+    }
+
+    /// Offer rejected calls and the law's suggestion to `rewriter`, which
+    /// may return corrected arguments.
+    pub fn rewriter(mut self, rewriter: impl Fn(&ToolCall, &str) -> Option<Value> + Send + Sync + 'static) -> Self {
+        self.rewriter = Some(Box::new(rewriter));
+        self
+    }
+
+    pub fn core(&self) -> &Arc<JudicialCore> {
+        &self.core
+    }
+
+    /// The action a call is ruled on as.
+    pub fn action(&self, call: &ToolCall) -> SystemAction {
+        let action_type = self.action_types.get(&call.tool).unwrap_or(&call.tool);
+        let mut action = SystemAction::new(action_type.as_str(), call.arguments.to_string(), self.context.as_str())
+            .with_actor(call.agent_id.as_str());
+        action.credentials = call.credentials.clone();
+        action
+    }
+
+    pub fn check(&self, call: &ToolCall) -> ToolDecision {
+        let verdict = self.core.rule(self.action(call));
+        if let Verdict::RejectedWithSuggestion(reason, suggestion) = &verdict {
+            if let Some(arguments) = self.rewriter.as_ref().and_then(|rewrite| rewrite(call, suggestion)) {
+                let modified = ToolCall { arguments, ..call.clone() };
+                if self.core.rule(self.action(&modified)).is_approved() {
+                    info!(tool = %call.tool, agent = %call.agent_id, "tool call modified");
+                    return ToolDecision::Modify { arguments: modified.arguments, reason: reason.clone() };
+                }
+            }
+        }
+        decision(verdict)
+    }
+}
+
+fn decision(verdict: Verdict) -> ToolDecision {
+    match verdict {
+        Verdict::Approved => ToolDecision::Allow { warning: None },
+        Verdict::ApprovedWithWarning(warning) => ToolDecision::Allow { warning: Some(warning) },
+        Verdict::Rejected(reason) | Verdict::RejectedWithSuggestion(reason, _) | Verdict::Malformed(reason) => {
+            ToolDecision::Deny { reason }
+        }
+        Verdict::Quarantined(reason) => ToolDecision::Deny { reason: format!("held for human review: {}", reason) },
+    }
+}
+
+impl fmt::Debug for ToolCallGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ToolCallGuard")
+            .field("context", &self.context)
+            .field("action_types", &self.action_types)
+            .field("rewriter", &self.rewriter.is_some())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,158 @@
+use crate::laws::LawViolation;
+use crate::verdicts::{SystemAction, Verdict};
+use chrono::{DateTime, Utc};
+use lru::LruCache;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// What the laws found for an action, as cached.
+pub(crate) type Evaluation = (Verdict, Option<u32>, Vec<LawViolation>);
+
+/// Canonical identity of an action for caching: SHA-256 over its type,
+/// payload, context, actor and sorted roles, each length-prefixed.
+/// Credentials are left out; identity has been verified, and written into
+/// actor and roles, before the cache is consulted.
+pub(crate) fn action_key(action: &SystemAction) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    let mut field = |bytes: &[u8]| {
+        hasher.update((bytes.len() as u64).to_be_bytes());
+        hasher.update(bytes);
+    };
+    field(action.action_type.as_bytes());
+    field(action.payload.as_bytes());
+    field(action.context.as_bytes());
+    match &action.actor {
+        Some(actor) => field(actor.as_bytes()),
+        None => field(&[]),
+    }
+    let mut roles: Vec<&str> = action.roles.iter().map(String::as_str).collect();
+    roles.sort_unstable();
+    roles.dedup();
+    for role in roles {
+        field(role.as_bytes());
+    }
+    hasher.finalize().into()
+}
+
+/// Counters reported by [`JudicialCore::cache_stats`](crate::JudicialCore::cache_stats).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Lookups that found an entry past its time to live.
+    pub expired: u64,
+    /// Entries pushed out to make room.
+    pub evicted: u64,
+    /// Policy changes that emptied the cache.
+    pub invalidations: u64,
+    pub entries: usize,
+    pub capacity: usize,
+    /// Policy epoch entries are served under; advances on each invalidation.
+    pub epoch: u64,
+}
+
+impl CacheStats {
+    /// Share of lookups served from the cache, 0.0 before the first lookup.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    evaluation: Evaluation,
+    expires: DateTime<Utc>,
+    epoch: u64,
+}
+
+#[derive(Debug)]
+struct State {
+    entries: LruCache<[u8; 32], Entry>,
+    stats: CacheStats,
+}
+
+/// Law evaluations of recently seen actions.
+///
+/// Entries live for a fixed time and are dropped wholesale whenever the
+/// policy that produced them changes. Each entry carries the policy epoch
+/// it was computed under, so an evaluation that raced a policy change is
+/// never served afterwards.
+#[derive(Debug)]
+pub(crate) struct VerdictCache {
+    ttl: chrono::Duration,
+    epoch: AtomicU64,
+    state: Mutex<State>,
+}
+
+impl VerdictCache {
+    pub(crate) fn new(capacity: NonZeroUsize, ttl: Duration) -> Self {
+        Self {
+            ttl: chrono::Duration::from_std(ttl).unwrap_or(chrono::Duration::MAX),
+            epoch: AtomicU64::new(0),
+            state: Mutex::new(State {
+                entries: LruCache::new(capacity),
+                stats: CacheStats { capacity: capacity.get(), ..CacheStats::default() },
+            }),
+        }
+    }
+
+    /// The current policy epoch. Read it before reading the policy an
+    /// evaluation depends on, and pass it to [`insert`](Self::insert).
+    pub(crate) fn epoch(&self) -> u64 {
+        self.epoch.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn get(&self, key: &[u8; 32], now: DateTime<Utc>) -> Option<Evaluation> {
+        let epoch = self.epoch();
+        let mut state = self.state.lock().unwrap();
+        let fresh = match state.entries.get(key) {
+            Some(entry) if entry.epoch == epoch && entry.expires > now => Some(entry.evaluation.clone()),
+            Some(_) => {
+                state.entries.pop(key);
+                state.stats.expired += 1;
+                None
+            }
+            None => None,
+        };
+        match fresh {
+            Some(_) => state.stats.hits += 1,
+            None => state.stats.misses += 1,
+        }
+        fresh
+    }
+
+    pub(crate) fn insert(&self, key: [u8; 32], evaluation: Evaluation, epoch: u64, now: DateTime<Utc>) {
+        if epoch != self.epoch() {
+            return;
+        }
+        let expires = now.checked_add_signed(self.ttl).unwrap_or(DateTime::<Utc>::MAX_UTC);
+        let mut state = self.state.lock().unwrap();
+        if let Some((evicted, _)) = state.entries.push(key, Entry { evaluation, expires, epoch }) {
+            if evicted != key {
+                state.stats.evicted += 1;
+            }
+        }
+    }
+
+    /// Forget every evaluation. Call after the policy has changed.
+    pub(crate) fn invalidate(&self) {
+        self.epoch.fetch_add(1, Ordering::SeqCst);
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.stats.invalidations += 1;
+    }
+
+    pub(crate) fn stats(&self) -> CacheStats {
+        let state = self.state.lock().unwrap();
+        CacheStats { entries: state.entries.len(), epoch: self.epoch(), ..state.stats.clone() }
+    }
+}
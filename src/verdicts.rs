@@ -1,15 +1,98 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SystemAction {
     pub action_type: String,
     pub payload: String,
     pub context: String,
+    /// Agent or user the action is performed for, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    /// Proof of `actor`, such as a bearer token, for the core's
+    /// `IdentityProvider`. Stripped before the action is ruled on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<String>,
+    /// Roles of the verified actor. Set by the core from verified
+    /// credentials; roles supplied by the caller are discarded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+}
+
+impl SystemAction {
+    pub fn new(action_type: impl Into<String>, payload: impl Into<String>, context: impl Into<String>) -> Self {
+        Self {
+            action_type: action_type.into(),
+            payload: payload.into(),
+            context: context.into(),
+            actor: None,
+            credentials: None,
+            roles: Vec::new(),
+        }
+    }
+
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+
+    pub fn with_credentials(mut self, credentials: impl Into<String>) -> Self {
+        self.credentials = Some(credentials.into());
+        self
+    }
+
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+}
+
+/// Credentials are never printed. Ledger hashes are taken over this output,
+/// so the actor and roles appear only when present and entries written
+/// before they existed still verify.
+impl fmt::Debug for SystemAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("SystemAction");
+        debug
+            .field("action_type", &self.action_type)
+            .field("payload", &self.payload)
+            .field("context", &self.context);
+        if self.actor.is_some() {
+            debug.field("actor", &self.actor);
+        }
+        if !self.roles.is_empty() {
+            debug.field("roles", &self.roles);
+        }
+        debug.finish()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Verdict {
     Approved,
+    ApprovedWithWarning(String),
     Rejected(String),
     RejectedWithSuggestion(String, String),
+    /// Neither approved nor rejected: hold the action for review.
+    Quarantined(String),
+    /// The payload does not match the schema declared for its action type,
+    /// so no law was consulted.
+    Malformed(String),
+}
+
+impl Verdict {
+    pub fn is_approved(&self) -> bool {
+        matches!(self, Verdict::Approved | Verdict::ApprovedWithWarning(_))
+    }
+
+    /// Stable upper-case name of the verdict kind.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Approved => "APPROVED",
+            Verdict::ApprovedWithWarning(_) => "APPROVED_WITH_WARNING",
+            Verdict::Rejected(_) => "REJECTED",
+            Verdict::RejectedWithSuggestion(_, _) => "REJECTED_WITH_SUGGESTION",
+            Verdict::Quarantined(_) => "QUARANTINED",
+            Verdict::Malformed(_) => "MALFORMED",
+        }
+    }
 }
@@ -0,0 +1,49 @@
+//! JavaScript bindings for `wasm32-unknown-unknown`, behind the `wasm` feature.
+//!
+//! The browser has no threads, so the wrapped core uses
+//! [`WriteMode::Direct`](crate::WriteMode::Direct) and no law budgets. Time
+//! comes from `Date.now()` through chrono's `wasmbind` support, and ruling
+//! latency from `performance.now()` through `web-time`.
+//!
+//! `tests/wasm.rs` runs the bindings on the host and under
+//! `wasm-bindgen-test-runner`.
+
+use crate::judicial_core::JudicialCore;
+use crate::verdicts::SystemAction;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct WasmCore {
+    core: JudicialCore,
+}
+
+#[wasm_bindgen]
+impl WasmCore {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self { core: JudicialCore::new() }
+    }
+
+    /// The verdict as JSON, e.g. `{"Rejected":"..."}` or `"Approved"`.
+    pub fn rule(&self, action_type: &str, payload: &str, context: &str) -> String {
+        let verdict = self.core.rule(SystemAction::new(action_type, payload, context));
+        serde_json::to_string(&verdict).unwrap()
+    }
+
+    #[wasm_bindgen(js_name = complianceScore)]
+    pub fn compliance_score(&self) -> f64 {
+        self.core.get_compliance_score()
+    }
+
+    #[wasm_bindgen(js_name = exportLedger)]
+    pub fn export_ledger(&self) -> String {
+        self.core.export_ledger()
+    }
+}
+
+impl Default for WasmCore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
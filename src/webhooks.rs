@@ -0,0 +1,388 @@
+//! Webhook notifications, behind the `webhooks` feature.
+//!
+//! [`WebhookNotifier`] is an [`Observer`]: register it on the builder and it
+//! POSTs each event as JSON to every configured URL from a background thread,
+//! so slow endpoints never hold up a ruling. Failed deliveries are retried
+//! with exponential backoff, then logged and dropped. A delivery waiting out
+//! its backoff does not hold up the ones behind it.
+//!
+//! The queue is bounded by [`WebhookConfig::queue_capacity`]: when an
+//! endpoint stays down and the queue fills, the oldest delivery is dropped,
+//! logged and counted in [`WebhookNotifier::dropped`].
+//!
+//! Requests leave the system, often for third parties such as chat or
+//! paging services, so events never include an action's payload, which may
+//! be the very secret a law rejected; only its size. The ledger hash in
+//! review events points to the full record.
+//!
+//! With a secret configured, each request carries
+//! `X-Judicial-Signature: sha256=<hex HMAC-SHA256 of the body>`.
+
+use crate::ledger::IntegrityError;
+use crate::observers::Observer;
+use crate::review::{ReviewId, ReviewItem, ReviewStatus, SlaAlert, SlaStage};
+use crate::verdicts::{SystemAction, Verdict};
+use hmac::{Hmac, Mac};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sha2::Sha256;
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// How serious a verdict is, for filtering what gets sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum Severity {
+    Warning,
+    /// Rejected with a suggested remedy.
+    Correctable,
+    Rejection,
+}
+
+impl Severity {
+    fn of(verdict: &Verdict) -> Option<Self> {
+        match verdict {
+            Verdict::Approved | Verdict::Quarantined(_) => None,
+            Verdict::ApprovedWithWarning(_) => Some(Severity::Warning),
+            Verdict::RejectedWithSuggestion(_, _) => Some(Severity::Correctable),
+            Verdict::Rejected(_) | Verdict::Malformed(_) => Some(Severity::Rejection),
+        }
+    }
+}
+
+/// An action as described to webhooks: everything but the payload.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActionSummary {
+    pub action_type: String,
+    pub actor: Option<String>,
+    pub payload_bytes: usize,
+}
+
+impl From<&SystemAction> for ActionSummary {
+    fn from(action: &SystemAction) -> Self {
+        Self {
+            action_type: action.action_type.clone(),
+            actor: action.actor.clone(),
+            payload_bytes: action.payload.len(),
+        }
+    }
+}
+
+/// A review as described to webhooks.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewSummary {
+    pub id: ReviewId,
+    pub action: ActionSummary,
+    pub reason: String,
+    pub submitted_at: DateTime<Utc>,
+    /// Hash of the ledger entry that quarantined the action.
+    pub ledger_hash: Option<String>,
+    pub status: ReviewStatus,
+}
+
+impl From<&ReviewItem> for ReviewSummary {
+    fn from(item: &ReviewItem) -> Self {
+        Self {
+            id: item.id,
+            action: ActionSummary::from(&item.action),
+            reason: item.reason.clone(),
+            submitted_at: item.submitted_at,
+            ledger_hash: item.ledger_hash.clone(),
+            status: item.status.clone(),
+        }
+    }
+}
+
+/// Body of a webhook request.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    Verdict { severity: Severity, action: ActionSummary, law: Option<u32>, verdict: Verdict },
+    ReviewRequired { review: ReviewSummary },
+    ReviewSla { stage: SlaStage, deadline: DateTime<Utc>, review: ReviewSummary, link: Option<String> },
+    IntegrityFailure { error: IntegrityError },
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    pub urls: Vec<String>,
+    pub secret: Option<Vec<u8>>,
+    /// Verdicts below this severity are not sent.
+    pub min_severity: Severity,
+    pub max_attempts: u32,
+    /// Wait before the first retry; doubles on each further retry.
+    pub backoff: Duration,
+    pub timeout: Duration,
+    /// Deliveries (one per event and URL, retries included) held at once.
+    pub queue_capacity: usize,
+    /// How long dropping the notifier keeps delivering what is queued.
+    /// Deliveries still queued then are dropped and counted.
+    pub shutdown_timeout: Duration,
+}
+
+impl WebhookConfig {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            urls: vec![url.into()],
+            secret: None,
+            min_severity: Severity::Rejection,
+            max_attempts: 3,
+            backoff: Duration::from_millis(500),
+            timeout: Duration::from_secs(5),
+            queue_capacity: 1024,
+            shutdown_timeout: Duration::from_secs(10),
+        }
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.urls.push(url.into());
+        self
+    }
+
+    pub fn secret(mut self, secret: impl Into<Vec<u8>>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    pub fn min_severity(mut self, severity: Severity) -> Self {
+        self.min_severity = severity;
+        self
+    }
+
+    pub fn retries(mut self, max_attempts: u32, backoff: Duration) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self.backoff = backoff;
+        self
+    }
+
+    pub fn queue_capacity(mut self, capacity: usize) -> Self {
+        self.queue_capacity = capacity.max(1);
+        self
+    }
+
+    pub fn shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+}
+
+/// Sends verdicts, review requests, review SLA alerts and tamper alarms to
+/// webhooks.
+#[derive(Debug)]
+pub struct WebhookNotifier {
+    min_severity: Severity,
+    urls: Vec<Arc<str>>,
+    secret: Option<Vec<u8>>,
+    shutdown_timeout: Duration,
+    queue: Arc<Queue>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl WebhookNotifier {
+    pub fn new(config: WebhookConfig) -> Self {
+        let queue = Arc::new(Queue {
+            state: Mutex::new(QueueState::default()),
+            ready: Condvar::new(),
+            capacity: config.queue_capacity.max(1),
+            dropped: AtomicU64::new(0),
+        });
+        let min_severity = config.min_severity;
+        let urls = config.urls.iter().map(|url| Arc::from(url.as_str())).collect();
+        let secret = config.secret.clone();
+        let shutdown_timeout = config.shutdown_timeout;
+        let worker = {
+            let queue = Arc::clone(&queue);
+            thread::Builder::new()
+                .name("judicial-webhooks".into())
+                .spawn(move || deliver(config, &queue))
+                .expect("failed to spawn webhook thread")
+        };
+
+        Self {
+            min_severity,
+            urls,
+            secret,
+            shutdown_timeout,
+            queue,
+            worker: Some(worker),
+        }
+    }
+
+    /// Deliveries dropped so far because the queue was full.
+    /// Deliveries abandoned at shutdown are logged when the notifier is dropped.
+    pub fn dropped(&self) -> u64 {
+        self.queue.dropped.load(Ordering::Relaxed)
+    }
+
+    fn send(&self, event: WebhookEvent) {
+        let body: Arc<str> = serde_json::to_string(&event).unwrap().into();
+        let signature: Option<Arc<str>> = self.secret.as_deref().map(|secret| sign(secret, &body).into());
+        let now = Instant::now();
+        for url in &self.urls {
+            self.queue.push(Delivery {
+                url: Arc::clone(url),
+                body: Arc::clone(&body),
+                signature: signature.clone(),
+                attempt: 1,
+                due: now,
+            });
+        }
+    }
+}
+
+impl Observer for WebhookNotifier {
+    fn on_verdict(&self, action: &SystemAction, verdict: &Verdict) {
+        self.on_ruling(action, verdict, None);
+    }
+
+    fn on_ruling(&self, action: &SystemAction, verdict: &Verdict, law: Option<u32>) {
+        if let Some(severity) = Severity::of(verdict).filter(|s| *s >= self.min_severity) {
+            self.send(WebhookEvent::Verdict {
+                severity,
+                action: ActionSummary::from(action),
+                law,
+                verdict: verdict.clone(),
+            });
+        }
+    }
+
+    fn on_review_required(&self, item: &ReviewItem) {
+        self.send(WebhookEvent::ReviewRequired { review: ReviewSummary::from(item) });
+    }
+
+    fn on_review_sla(&self, alert: &SlaAlert) {
+        self.send(WebhookEvent::ReviewSla {
+            stage: alert.stage,
+            deadline: alert.deadline,
+            review: ReviewSummary::from(&alert.item),
+            link: alert.link.clone(),
+        });
+    }
+
+    fn on_integrity_failure(&self, error: &IntegrityError) {
+        self.send(WebhookEvent::IntegrityFailure { error: error.clone() });
+    }
+}
+
+impl Drop for WebhookNotifier {
+    /// Delivers what is already queued, retrying without waiting out the
+    /// backoff, for up to [`WebhookConfig::shutdown_timeout`] (plus one
+    /// request timeout). Whatever is left then is dropped and counted.
+    fn drop(&mut self) {
+        self.queue.state.lock().unwrap().closing = Some(Instant::now() + self.shutdown_timeout);
+        self.queue.ready.notify_one();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+/// One attempt at sending an event to one URL.
+#[derive(Debug)]
+struct Delivery {
+    url: Arc<str>,
+    body: Arc<str>,
+    signature: Option<Arc<str>>,
+    attempt: u32,
+    due: Instant,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    deliveries: VecDeque<Delivery>,
+    /// Set on drop: deliver until this deadline, then give up.
+    closing: Option<Instant>,
+}
+
+#[derive(Debug)]
+struct Queue {
+    state: Mutex<QueueState>,
+    ready: Condvar,
+    capacity: usize,
+    dropped: AtomicU64,
+}
+
+impl Queue {
+    fn push(&self, delivery: Delivery) {
+        let mut state = self.state.lock().unwrap();
+        if state.deliveries.len() >= self.capacity {
+            if let Some(oldest) = state.deliveries.pop_front() {
+                let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                warn!(url = %oldest.url, attempt = oldest.attempt, dropped, "webhook queue full, dropped oldest delivery");
+            }
+        }
+        state.deliveries.push_back(delivery);
+        drop(state);
+        self.ready.notify_one();
+    }
+
+    /// The first delivery that is due, or `None` once closed and drained
+    /// or past the closing deadline. After close, backoff is no longer
+    /// waited out.
+    fn next(&self) -> Option<Delivery> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            match state.closing {
+                Some(deadline) if now >= deadline => {
+                    let abandoned = state.deliveries.len();
+                    if abandoned > 0 {
+                        state.deliveries.clear();
+                        self.dropped.fetch_add(abandoned as u64, Ordering::Relaxed);
+                        warn!(abandoned, "webhook deliveries dropped at shutdown");
+                    }
+                    return None;
+                }
+                Some(_) => return state.deliveries.pop_front(),
+                None => {}
+            }
+            if let Some(i) = state.deliveries.iter().position(|d| d.due <= now) {
+                return state.deliveries.remove(i);
+            }
+            state = match state.deliveries.iter().map(|d| d.due).min() {
+                Some(due) => self.ready.wait_timeout(state, due - now).unwrap().0,
+                None => self.ready.wait(state).unwrap(),
+            };
+        }
+    }
+}
+
+fn deliver(config: WebhookConfig, queue: &Queue) {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(config.timeout))
+        .build()
+        .into();
+
+    while let Some(mut delivery) = queue.next() {
+        let url = &*delivery.url;
+        let mut request = agent.post(url).header("Content-Type", "application/json");
+        if let Some(signature) = &delivery.signature {
+            request = request.header("X-Judicial-Signature", &**signature);
+        }
+        match request.send(&*delivery.body) {
+            Ok(_) => {}
+            Err(error) if delivery.attempt >= config.max_attempts => {
+                warn!(%url, %error, attempts = delivery.attempt, "webhook delivery failed");
+            }
+            Err(_) => {
+                delivery.due = Instant::now() + config.backoff.saturating_mul(2u32.saturating_pow(delivery.attempt - 1));
+                delivery.attempt += 1;
+                queue.push(delivery);
+            }
+        }
+    }
+}
+
+fn sign(secret: &[u8], body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts any key length");
+    mac.update(body.as_bytes());
+    let mut signature = String::from("sha256=");
+    for byte in mac.finalize().into_bytes() {
+        let _ = write!(signature, "{:02x}", byte);
+    }
+    signature
+}
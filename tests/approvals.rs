@@ -0,0 +1,237 @@
+use judicial_core::review::ReviewItem;
+use judicial_core::{
+    ApprovalError, ApprovalService, ApprovalStatus, JudicialCore, JudicialCoreBuilder, ReviewDecision, ReviewStatus,
+    SystemAction, UnknownActionPolicy,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+fn teleport() -> SystemAction {
+    SystemAction::new("TELEPORT", "beam me up", "ops")
+}
+
+fn quarantining() -> JudicialCoreBuilder {
+    JudicialCore::builder().unknown_action_policy(UnknownActionPolicy::Quarantine)
+}
+
+/// An approval desk decided by the test. Tickets are the review ids.
+#[derive(Debug, Clone, Default)]
+struct Desk(Arc<Mutex<DeskState>>);
+
+#[derive(Debug, Default)]
+struct DeskState {
+    down: bool,
+    requests: Vec<String>,
+    decisions: HashMap<String, ApprovalStatus>,
+}
+
+impl Desk {
+    fn decide(&self, ticket: &str, decision: ReviewDecision) {
+        let status = ApprovalStatus::Decided { decision, reviewer: "desk".into(), rationale: "checked".into() };
+        self.0.lock().unwrap().decisions.insert(ticket.into(), status);
+    }
+
+    fn requests(&self) -> Vec<String> {
+        self.0.lock().unwrap().requests.clone()
+    }
+
+    fn set_down(&self, down: bool) {
+        self.0.lock().unwrap().down = down;
+    }
+}
+
+impl ApprovalService for Desk {
+    fn request(&self, item: &ReviewItem) -> Result<String, ApprovalError> {
+        let mut state = self.0.lock().unwrap();
+        if state.down {
+            return Err(ApprovalError("desk is down".into()));
+        }
+        state.requests.push(item.id.to_string());
+        Ok(item.id.to_string())
+    }
+
+    fn status(&self, ticket: &str) -> Result<ApprovalStatus, ApprovalError> {
+        let state = self.0.lock().unwrap();
+        if state.down {
+            return Err(ApprovalError("desk is down".into()));
+        }
+        Ok(state.decisions.get(ticket).cloned().unwrap_or(ApprovalStatus::Pending))
+    }
+}
+
+#[test]
+fn polled_decisions_resolve_reviews() {
+    let desk = Desk::default();
+    let court = quarantining().approval_service(desk.clone()).build();
+    court.rule(teleport());
+    let id = court.reviews().pending()[0].id;
+    assert_eq!(desk.requests(), [id.to_string()]);
+
+    assert!(court.poll_approvals().is_empty());
+    desk.decide(&id.to_string(), ReviewDecision::Reject);
+    let resolved = court.poll_approvals();
+    assert_eq!(resolved.len(), 1);
+    let ReviewStatus::Resolved { reviewer, decision, rationale, .. } = &resolved[0].status else { panic!("pending") };
+    assert_eq!((reviewer.as_str(), *decision, rationale.as_str()), ("desk", ReviewDecision::Reject, "checked"));
+    assert!(court.reviews().pending().is_empty());
+    assert!(court.poll_approvals().is_empty(), "applied twice");
+
+    let entry = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert!(entry.verdict.starts_with(&format!("REVIEW: REJECTED #{} by desk (checked)", id)), "{}", entry.verdict);
+}
+
+#[test]
+fn pushed_decisions_resolve_reviews() {
+    let desk = Desk::default();
+    let court = quarantining().approval_service(desk.clone()).build();
+    court.rule(teleport());
+    let id = court.reviews().pending()[0].id;
+
+    court.receive_decision(id, "desk", ReviewDecision::Approve, "known transport").unwrap();
+    desk.decide(&id.to_string(), ReviewDecision::Reject);
+    assert!(court.poll_approvals().is_empty(), "the pushed decision was overridden");
+    assert!(court.receive_decision(id, "desk", ReviewDecision::Reject, "late").is_err());
+}
+
+#[test]
+fn an_unavailable_service_is_retried() {
+    let desk = Desk::default();
+    desk.set_down(true);
+    let court = quarantining().approval_service(desk.clone()).build();
+    court.rule(teleport());
+    let id = court.reviews().pending()[0].id;
+    assert!(desk.requests().is_empty());
+    assert!(court.poll_approvals().is_empty());
+
+    desk.set_down(false);
+    assert!(court.poll_approvals().is_empty());
+    assert_eq!(desk.requests(), [id.to_string()], "the failed request was not retried");
+
+    desk.decide(&id.to_string(), ReviewDecision::Approve);
+    desk.set_down(true);
+    assert!(court.poll_approvals().is_empty());
+    assert_eq!(court.reviews().pending().len(), 1);
+    desk.set_down(false);
+    assert_eq!(court.poll_approvals().len(), 1);
+}
+
+#[cfg(feature = "http-approvals")]
+mod http {
+    use super::{quarantining, teleport};
+    use judicial_core::approval::HttpApprovalService;
+    use judicial_core::{ApprovalService, ApprovalStatus, ReviewDecision};
+    use serde_json::Value;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// One request as the approval server saw it.
+    #[derive(Debug, Clone)]
+    struct Seen {
+        method: String,
+        path: String,
+        authorization: Option<String>,
+        body: String,
+    }
+
+    /// Serve each request with `answer(method, path)`'s status and body,
+    /// and return the base URL and the requests seen.
+    fn server(answer: fn(&str, &str) -> (u16, &'static str)) -> (String, Arc<Mutex<Vec<Seen>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&seen);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut parts = line.split_whitespace();
+                let (method, path) = (parts.next().unwrap().to_string(), parts.next().unwrap().to_string());
+                let (mut length, mut authorization) = (0, None);
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(": ").unwrap();
+                    match name.to_ascii_lowercase().as_str() {
+                        "content-length" => length = value.parse().unwrap(),
+                        "authorization" => authorization = Some(value.to_string()),
+                        _ => {}
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+
+                let (status, reply) = answer(&method, &path);
+                log.lock().unwrap().push(Seen { method, path, authorization, body: String::from_utf8(body).unwrap() });
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status, reply.len(), reply
+                ).unwrap();
+            }
+        });
+        (base, seen)
+    }
+
+    fn desk(method: &str, path: &str) -> (u16, &'static str) {
+        match (method, path) {
+            ("POST", "/approvals") => (200, r#"{"ticket": "T-1"}"#),
+            ("GET", "/approvals/T-1") => (200, r#"{"status": "approved", "reviewer": "carol", "rationale": "ok"}"#),
+            ("GET", "/approvals/T-2") => (200, r#"{"status": "pending"}"#),
+            ("GET", "/approvals/T-3") => (200, r#"{"status": "rejected", "reviewer": "dave"}"#),
+            _ => (404, "{}"),
+        }
+    }
+
+    #[test]
+    fn the_http_service_requests_and_polls_approvals() {
+        let (base, seen) = server(desk);
+        let court = quarantining().approval_service(HttpApprovalService::new(&base).bearer_token("s3cret")).build();
+        court.rule(teleport());
+        let resolved = court.poll_approvals();
+        assert_eq!(resolved.len(), 1);
+
+        let seen = seen.lock().unwrap().clone();
+        assert_eq!(seen.len(), 2);
+        assert_eq!((seen[0].method.as_str(), seen[0].path.as_str()), ("POST", "/approvals"));
+        assert_eq!((seen[1].method.as_str(), seen[1].path.as_str()), ("GET", "/approvals/T-1"));
+        assert!(seen.iter().all(|s| s.authorization.as_deref() == Some("Bearer s3cret")));
+        let request: Value = serde_json::from_str(&seen[0].body).unwrap();
+        assert_eq!(request["review_id"], resolved[0].id);
+        assert_eq!(request["action"]["action_type"], "TELEPORT");
+        assert_eq!(request["reason"], "Unknown action type 'TELEPORT'");
+        assert_eq!(request["ledger_hash"].as_str(), resolved[0].ledger_hash.as_deref());
+
+        let service = HttpApprovalService::new(&base);
+        assert_eq!(service.status("T-2").unwrap(), ApprovalStatus::Pending);
+        assert_eq!(
+            service.status("T-3").unwrap(),
+            ApprovalStatus::Decided { decision: ReviewDecision::Reject, reviewer: "dave".into(), rationale: String::new() }
+        );
+    }
+
+    #[test]
+    fn http_failures_are_errors() {
+        let (base, _) = server(|_, _| (500, "{}"));
+        let service = HttpApprovalService::new(&base);
+        assert!(service.status("T-1").is_err());
+
+        let (base, _) = server(|_, _| (200, r#"{"status": "lost"}"#));
+        let error = HttpApprovalService::new(&base).status("T-1").unwrap_err();
+        assert!(error.to_string().starts_with("approval service failed: "), "{}", error);
+
+        let (base, seen) = server(|_, _| (503, "{}"));
+        let court = quarantining().approval_service(HttpApprovalService::new(&base)).build();
+        court.rule(teleport());
+        assert!(court.poll_approvals().is_empty());
+        assert_eq!(court.reviews().pending().len(), 1);
+        assert_eq!(seen.lock().unwrap().len(), 2, "the failed request was not retried");
+    }
+}
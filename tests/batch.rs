@@ -0,0 +1,108 @@
+use judicial_core::{JudicialCore, RateLimit, RateLimitScope, SystemAction, UnknownActionPolicy, Verdict};
+
+fn actions() -> Vec<SystemAction> {
+    vec![
+        SystemAction::new("DATA_READ", "SELECT 1", "analytics").with_actor("agent"),
+        SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance").with_actor("agent"),
+        SystemAction::new("TELEPORT", "beam me up", "ops").with_actor("agent"),
+        SystemAction::new("DATA_EXPORT", "password=hunter2", "reporting").with_actor("other"),
+        SystemAction::new("SYSTEM_CMD", "backup && rm -rf /data/temp", "admin").with_actor("agent"),
+    ]
+}
+
+/// At most three rulings per actor, unknown action types held for review.
+fn court() -> JudicialCore {
+    let limit = RateLimit { max_rulings: 3, window: chrono::Duration::seconds(60) };
+    JudicialCore::builder()
+        .unknown_action_policy(UnknownActionPolicy::Quarantine)
+        .rate_limit(RateLimitScope::AllActors, limit)
+        .build()
+}
+
+fn recorded(core: &JudicialCore) -> Vec<String> {
+    core.query_ledger(|_| true, 0, usize::MAX).iter()
+        .map(|e| format!("{:?} {}", e.action, e.verdict))
+        .collect()
+}
+
+#[test]
+fn a_batch_is_ruled_and_recorded_as_if_ruled_in_turn() {
+    let (batched, sequential) = (court(), court());
+    let verdicts = batched.rule_batch(&actions());
+    let expected: Vec<_> = actions().into_iter().map(|action| sequential.rule(action)).collect();
+    assert_eq!(format!("{:?}", verdicts), format!("{:?}", expected));
+
+    // The fourth ruling for `agent` is throttled, in submission order.
+    let Verdict::Rejected(reason) = &verdicts[4] else { panic!("not throttled") };
+    assert!(reason.starts_with("rate limit: agent exceeded 3 rulings"), "{}", reason);
+    assert_eq!(recorded(&batched), recorded(&sequential));
+    assert!(batched.verify_ledger().is_ok());
+    assert_eq!(batched.reviews().pending().len(), 1);
+}
+
+#[test]
+fn empty_and_refused_batches() {
+    let court = court();
+    assert!(court.rule_batch(&[]).is_empty());
+    assert!(court.query_ledger(|_| true, 0, usize::MAX).is_empty());
+
+    court.shutdown().unwrap();
+    let verdicts = court.rule_batch(&actions());
+    assert_eq!(verdicts.len(), actions().len());
+    assert!(verdicts.iter().all(|verdict| !verdict.is_approved()));
+}
+
+#[cfg(feature = "parallel")]
+mod parallel {
+    use judicial_core::laws::Law;
+    use judicial_core::{JudicialCore, SystemAction};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    /// A slow law that tracks how many checks run at once.
+    #[derive(Debug, Default)]
+    struct Slow {
+        running: AtomicUsize,
+        most: Arc<AtomicUsize>,
+    }
+
+    impl Law for Slow {
+        fn number(&self) -> u32 {
+            9
+        }
+
+        fn description(&self) -> &str {
+            "takes its time"
+        }
+
+        fn check(&self, _action: &SystemAction) -> Option<String> {
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.most.fetch_max(running, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(20));
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            None
+        }
+    }
+
+    #[test]
+    fn laws_weigh_a_batch_concurrently() {
+        let most = Arc::new(AtomicUsize::new(0));
+        let court = JudicialCore::builder().law(Slow { most: Arc::clone(&most), ..Slow::default() }).build();
+        let actions: Vec<SystemAction> = (0..16)
+            .map(|i| SystemAction::new("DATA_READ", format!("SELECT {}", i), "analytics"))
+            .collect();
+
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+        let verdicts = pool.install(|| court.rule_batch(&actions));
+        assert!(verdicts.iter().all(|verdict| verdict.is_approved()));
+        assert!(most.load(Ordering::SeqCst) > 1, "the laws never ran concurrently");
+
+        let payloads: Vec<String> = court.query_ledger(|_| true, 0, usize::MAX).into_iter()
+            .map(|e| e.action.payload)
+            .collect();
+        let submitted: Vec<String> = actions.iter().map(|a| a.payload.clone()).collect();
+        assert_eq!(payloads, submitted);
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "cli")]
+
+use judicial_core::ledger::LedgerEntry;
+use judicial_core::{JudicialCore, SystemAction};
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+fn judicial(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_judicial")).args(args).output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    assert!(!output.status.success(), "succeeded: {}", String::from_utf8_lossy(&output.stdout));
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+/// A file in the temp directory, unique to this test and process.
+fn scratch(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("judicial-cli-{}-{}", std::process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+const ACTIONS: &str = r#"{"action_type":"DATA_READ","payload":"SELECT 1","context":"analytics","actor":"agent"}
+
+{"action_type":"SYSTEM_SHUTDOWN","payload":"halt","context":"maintenance"}
+"#;
+
+#[test]
+fn rule_prints_the_verdict() {
+    let approved = stdout(&judicial(&["rule", "DATA_READ", "SELECT 1", "analytics"]));
+    assert_eq!(approved.trim(), r#""Approved""#);
+
+    let rejected = stdout(&judicial(&["rule", "SYSTEM_SHUTDOWN", "halt", "maintenance", "--actor", "agent"]));
+    assert!(rejected.contains("Non-emergency system shutdown"), "{}", rejected);
+
+    let pack = scratch("rule-pack.toml", "laws = [1]");
+    let lenient = judicial(&["rule", "SYSTEM_SHUTDOWN", "halt", "maintenance", "--policy-pack", pack.to_str().unwrap()]);
+    assert_eq!(stdout(&lenient).trim(), r#""Approved""#);
+}
+
+#[test]
+fn simulate_exports_a_ledger_the_other_commands_read() {
+    let actions = scratch("actions.jsonl", ACTIONS);
+    let ledger = std::env::temp_dir().join(format!("judicial-cli-{}-ledger.json", std::process::id()));
+    let ledger_arg = ledger.to_str().unwrap();
+    let output = judicial(&["simulate", actions.to_str().unwrap(), "--export-ledger", ledger_arg]);
+    let verdicts = stdout(&output);
+    assert_eq!(verdicts.lines().count(), 2, "{}", verdicts);
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with("compliance score: "));
+
+    let verified = stdout(&judicial(&["ledger", "verify", ledger_arg]));
+    assert!(verified.ends_with("2 entries, chain intact\n"), "{}", verified);
+
+    let queried = stdout(&judicial(&["ledger", "query", ledger_arg, "--actor", "agent"]));
+    let entries: Vec<LedgerEntry> = serde_json::from_str(&queried).unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].action.action_type, "DATA_READ");
+    let queried = stdout(&judicial(&["ledger", "query", ledger_arg, "--offset", "1", "--limit", "5"]));
+    let entries: Vec<LedgerEntry> = serde_json::from_str(&queried).unwrap();
+    assert_eq!(entries[0].action.action_type, "SYSTEM_SHUTDOWN");
+
+    let report: serde_json::Value = serde_json::from_str(&stdout(&judicial(&["report", ledger_arg]))).unwrap();
+    assert_eq!((&report["overall"]["rulings"], &report["overall"]["rejections"]), (&2.into(), &1.into()));
+}
+
+#[test]
+fn failures_exit_nonzero_with_a_message() {
+    let actions = scratch("bad.jsonl", "{\"action_type\": 1}\n");
+    let message = stderr(&judicial(&["simulate", actions.to_str().unwrap()]));
+    assert!(message.starts_with(&format!("judicial: {}:1: ", actions.display())), "{}", message);
+
+    let core = JudicialCore::new();
+    core.rule(SystemAction::new("DATA_READ", "SELECT 1", "analytics"));
+    let mut entries: Vec<LedgerEntry> = serde_json::from_str(&core.export_ledger()).unwrap();
+    entries[0].verdict = "REJECTED".into();
+    let tampered = scratch("tampered.json", &serde_json::to_string(&entries).unwrap());
+    let message = stderr(&judicial(&["ledger", "verify", tampered.to_str().unwrap()]));
+    assert!(message.starts_with(&format!("judicial: {}: ", tampered.display())), "{}", message);
+
+    let missing = judicial(&["report", "/nonexistent/ledger.json"]);
+    assert!(stderr(&missing).starts_with("judicial: /nonexistent/ledger.json: "));
+
+    let pack = scratch("bad-pack.toml", "laws = [7]");
+    let message = stderr(&judicial(&["rule", "DATA_READ", "SELECT 1", "--policy-pack", pack.to_str().unwrap()]));
+    assert!(message.contains("law 7 is not installed"), "{}", message);
+}
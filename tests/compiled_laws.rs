@@ -0,0 +1,159 @@
+use judicial_core::laws::{Law, LawPatterns, Scan};
+use judicial_core::{LawSet, Strictness, SystemAction};
+use std::sync::Arc;
+
+/// The switch between per-pattern search and one automaton pass.
+const AUTOMATON_THRESHOLD: usize = 32;
+
+#[derive(Debug)]
+struct KeywordLaw {
+    number: u32,
+    keywords: &'static [&'static str],
+}
+
+impl Law for KeywordLaw {
+    fn number(&self) -> u32 {
+        self.number
+    }
+
+    fn description(&self) -> &str {
+        "keyword law"
+    }
+
+    fn check(&self, action: &SystemAction) -> Option<String> {
+        self.keywords.iter()
+            .find(|keyword| action.payload.contains(*keyword))
+            .map(|keyword| format!("mentions {}", keyword))
+    }
+
+    fn patterns(&self) -> LawPatterns {
+        LawPatterns { payload: self.keywords, context: &[] }
+    }
+
+    fn check_scanned(&self, _action: &SystemAction, scan: &Scan<'_>) -> Option<String> {
+        scan.first_payload().map(|index| format!("mentions {}", self.keywords[index]))
+    }
+}
+
+/// A law with its patterns hidden, so the law set leaves it to `check`.
+#[derive(Debug)]
+struct Unscanned(Arc<dyn Law>);
+
+impl Law for Unscanned {
+    fn number(&self) -> u32 {
+        self.0.number()
+    }
+
+    fn description(&self) -> &str {
+        self.0.description()
+    }
+
+    fn is_absolute(&self) -> bool {
+        self.0.is_absolute()
+    }
+
+    fn suggestion(&self) -> Option<&str> {
+        self.0.suggestion()
+    }
+
+    fn governs(&self, action_type: &str) -> bool {
+        self.0.governs(action_type)
+    }
+
+    fn check(&self, action: &SystemAction) -> Option<String> {
+        self.0.check(action)
+    }
+
+    fn check_warning(&self, action: &SystemAction) -> Option<String> {
+        self.0.check_warning(action)
+    }
+}
+
+const KEYWORDS: [&[&str]; 8] = [
+    &["exfiltrate", "keylogger", "botnet", "ransom"],
+    &["bypass_auth", "disable_mfa", "sudo su", "chmod 777"],
+    &["crypto_miner", "xmrig", "stratum+tcp"],
+    &["phishing", "spoofed_sender", "credential_harvest"],
+    &["shadow_copy", "vssadmin", "bcdedit"],
+    &["reverse_shell", "nc -e", "bash -i"],
+    &["pii_dump", "customer_export", "bulk_download"],
+    &["telemetry_off", "audit_disable", "log_clear", "ransomware", "botnet_c2", "su"],
+];
+
+fn with_keywords(count: usize) -> LawSet {
+    KEYWORDS[..count].iter().enumerate().fold(LawSet::master_pair(), |laws, (i, keywords)| {
+        laws.with(KeywordLaw { number: 10 + i as u32, keywords })
+    })
+}
+
+fn unscanned(laws: &LawSet) -> LawSet {
+    laws.iter().fold(LawSet::new(), |set, law| set.with(Unscanned(Arc::clone(law))))
+}
+
+fn payload_patterns(laws: &LawSet) -> usize {
+    let mut patterns: Vec<&str> = laws.iter().flat_map(|law| law.patterns().payload.iter().copied()).collect();
+    patterns.sort_unstable();
+    patterns.dedup();
+    patterns.len()
+}
+
+fn actions() -> Vec<SystemAction> {
+    let filler = "summarise the quarterly figures for the regional teams ".repeat(4);
+    let mut actions = vec![
+        SystemAction::new("DATA_ANALYSIS", "", ""),
+        SystemAction::new("DATA_ANALYSIS", filler.clone(), "research"),
+        SystemAction::new("SYSTEM_CMD", "rm -rf /data/temp", "admin"),
+        SystemAction::new("SYSTEM_CMD", "backup && rm -rf /data/temp", "admin"),
+        SystemAction::new("SYSTEM_CMD", "backup && rm -rf /data/temp", "admin rollback"),
+        SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance"),
+        SystemAction::new("SYSTEM_SHUTDOWN", "halt", "emergency"),
+        SystemAction::new("DB_QUERY", "DROP TABLE users", "ops"),
+        SystemAction::new("DB_QUERY", format!("{} delete from sessions with rollback", filler), "ops,backup"),
+        SystemAction::new("DATA_EXPORT", format!("{} credit_card", filler), "audit,compliance_approved"),
+        SystemAction::new("DATA_EXPORT", "password=hunter2", "reporting"),
+        SystemAction::new("DATA_ANALYSIS", "sudo sudo su -", "ops"),
+        SystemAction::new("DATA_ANALYSIS", "ransomware botnet_c2 nc -e", "ops"),
+        SystemAction::new("DATA_ANALYSIS", "résumé → exfiltrate ✓", "ops"),
+        SystemAction::new("DATA_ANALYSIS", format!("{}log_clear", filler), "ops"),
+        SystemAction::new("TELEPORT", "keylogger", "ops"),
+    ];
+    for keywords in KEYWORDS {
+        for keyword in keywords {
+            actions.push(SystemAction::new("DATA_ANALYSIS", format!("{} {}", filler, keyword), "ops"));
+        }
+    }
+    actions
+}
+
+fn assert_agree(laws: LawSet) {
+    let reference = unscanned(&laws);
+    for action in actions() {
+        for strictness in [Strictness::Permissive, Strictness::Standard, Strictness::Paranoid] {
+            let compiled = laws.evaluate(&action, strictness);
+            let checked = reference.evaluate(&action, strictness);
+            assert_eq!(
+                format!("{:?}", compiled), format!("{:?}", checked),
+                "{:?} under {:?}", action.payload, strictness
+            );
+        }
+    }
+}
+
+#[test]
+fn scanned_checks_agree_below_the_automaton_threshold() {
+    let laws = with_keywords(2);
+    assert!(payload_patterns(&laws) <= AUTOMATON_THRESHOLD, "{} patterns", payload_patterns(&laws));
+    assert_agree(laws);
+}
+
+#[test]
+fn scanned_checks_agree_above_the_automaton_threshold() {
+    let laws = with_keywords(KEYWORDS.len());
+    assert!(payload_patterns(&laws) > AUTOMATON_THRESHOLD, "{} patterns", payload_patterns(&laws));
+    assert_agree(laws);
+}
+
+#[test]
+fn scanned_checks_agree_for_the_master_pair_alone() {
+    assert_agree(LawSet::master_pair());
+}
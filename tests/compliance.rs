@@ -0,0 +1,25 @@
+use judicial_core::{ComplianceWindow, JudicialCore, SystemAction};
+
+#[test]
+fn by_law_counts_every_law_a_rejection_violated() {
+    let court = JudicialCore::new();
+    court.rule(SystemAction::new("DATA_WRITE", "UPDATE users SET password = 'x'; drop table users", "ops"));
+    court.rule(SystemAction::new("DATA_READ", "SELECT name FROM users", "analytics"));
+
+    let report = court.compliance_report(ComplianceWindow::Lifetime);
+    assert_eq!(report.overall.rejections, 1);
+    for law in [1, 2] {
+        let score = report.by_law[&law];
+        assert_eq!((score.rulings, score.rejections), (2, 1), "law {}", law);
+        assert_eq!(score.score, 0.5);
+    }
+}
+
+#[test]
+fn by_law_leaves_out_laws_nothing_violated() {
+    let court = JudicialCore::new();
+    court.rule(SystemAction::new("DATA_EXPORT", "SELECT name FROM users", "analytics"));
+
+    let report = court.compliance_report(ComplianceWindow::Lifetime);
+    assert_eq!(report.by_law.keys().copied().collect::<Vec<_>>(), vec![1]);
+}
@@ -0,0 +1,103 @@
+use judicial_core::{Court, EscalationPolicy, JudicialCore, PolicyPack, SystemAction, UnknownActionPolicy, Verdict};
+use std::sync::Arc;
+
+fn teleport() -> SystemAction {
+    SystemAction::new("TELEPORT", "beam me up", "ops")
+}
+
+fn shutdown() -> SystemAction {
+    SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance")
+}
+
+fn quarantining() -> JudicialCore {
+    JudicialCore::builder().unknown_action_policy(UnknownActionPolicy::Quarantine).build()
+}
+
+/// A parent court that only enforces the first law.
+fn lenient(name: &str) -> Arc<Court> {
+    let core = JudicialCore::new();
+    core.apply_policy_pack(&PolicyPack::from_toml("laws = [1]").unwrap()).unwrap();
+    Arc::new(Court::new(name, core))
+}
+
+#[test]
+fn escalated_rejections_are_decided_by_the_parent() {
+    // The second law's rejections come with a suggestion.
+    let escalation = EscalationPolicy { rejections_with_suggestion: true, ..EscalationPolicy::default() };
+    let trial = Court::new("trial", JudicialCore::new()).with_parent(lenient("appellate")).with_escalation(escalation);
+
+    let ruling = trial.rule(shutdown());
+    assert!(ruling.verdict.is_approved());
+    assert_eq!(ruling.decided_by, "appellate");
+    let courts: Vec<(&str, bool)> = ruling.history.iter()
+        .map(|(court, verdict)| (court.as_str(), verdict.is_approved()))
+        .collect();
+    assert_eq!(courts, [("trial", false), ("appellate", true)]);
+}
+
+#[test]
+fn other_verdicts_stay_with_the_trial_court() {
+    let appellate = lenient("appellate");
+    let trial = Court::new("trial", JudicialCore::new()).with_parent(Arc::clone(&appellate));
+    let heard = appellate.core().query_ledger(|_| true, 0, usize::MAX).len();
+
+    let ruling = trial.rule(SystemAction::new("DATA_READ", "SELECT 1", "analytics"));
+    assert!(matches!(ruling.verdict, Verdict::Approved));
+    assert_eq!((ruling.decided_by.as_str(), ruling.history.len()), ("trial", 1));
+
+    let ruling = trial.rule(shutdown());
+    assert!(!ruling.verdict.is_approved());
+    assert_eq!(ruling.decided_by, "trial", "suggestions are not escalated by default");
+    assert_eq!(appellate.core().query_ledger(|_| true, 0, usize::MAX).len(), heard);
+}
+
+#[test]
+fn escalated_quarantines_are_reviewed_only_by_the_parent() {
+    let appellate = Arc::new(Court::new("appellate", quarantining()));
+    let trial = Court::new("trial", quarantining()).with_parent(Arc::clone(&appellate));
+
+    let ruling = trial.rule(teleport());
+    assert!(matches!(ruling.verdict, Verdict::Quarantined(_)));
+    assert_eq!(ruling.decided_by, "appellate");
+
+    assert!(trial.core().reviews().pending().is_empty(), "orphaned review in the trial court");
+    assert_eq!(appellate.core().reviews().pending().len(), 1);
+    let recorded = trial.core().query_ledger(|_| true, 0, usize::MAX);
+    assert!(recorded.last().unwrap().verdict.starts_with("QUARANTINED"));
+}
+
+#[test]
+fn quarantines_kept_by_the_trial_court_are_reviewed_there() {
+    let top = Court::new("trial", quarantining());
+    assert!(matches!(top.rule(teleport()).verdict, Verdict::Quarantined(_)));
+    assert_eq!(top.core().reviews().pending().len(), 1);
+
+    let escalation = EscalationPolicy { quarantines: false, ..EscalationPolicy::default() };
+    let appellate = Arc::new(Court::new("appellate", quarantining()));
+    let trial = Court::new("trial", quarantining()).with_parent(Arc::clone(&appellate)).with_escalation(escalation);
+    assert_eq!(trial.rule(teleport()).decided_by, "trial");
+    assert_eq!(trial.core().reviews().pending().len(), 1);
+    assert!(appellate.core().reviews().pending().is_empty());
+}
+
+#[test]
+fn appeals_are_heard_by_the_parent_after_the_trial_verdict() {
+    let trial = Court::new("trial", JudicialCore::new()).with_parent(lenient("appellate"));
+    let ruling = trial.rule(shutdown());
+    assert_eq!(ruling.decided_by, "trial");
+
+    let appeal = trial.appeal(shutdown(), ruling.verdict).unwrap();
+    assert!(appeal.verdict.is_approved());
+    assert_eq!(appeal.decided_by, "appellate");
+    let courts: Vec<(&str, bool)> = appeal.history.iter()
+        .map(|(court, verdict)| (court.as_str(), verdict.is_approved()))
+        .collect();
+    assert_eq!(courts, [("trial", false), ("appellate", true)]);
+}
+
+#[test]
+fn the_top_court_cannot_be_appealed() {
+    let top = Court::new("appellate", JudicialCore::new());
+    let ruling = top.rule(shutdown());
+    assert!(top.appeal(shutdown(), ruling.verdict).is_none());
+}
@@ -0,0 +1,152 @@
+use judicial_core::distributed::{AdjudicationRequest, AdjudicationResponse, PeerError};
+use judicial_core::{CourtPeer, DistributedCourt, FallbackPolicy, JudicialCore, Law, SystemAction, Verdict};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A peer that always answers the same way, or never answers.
+#[derive(Debug)]
+struct FixedPeer {
+    name: String,
+    verdict: Option<Verdict>,
+}
+
+impl FixedPeer {
+    fn approving(name: &str) -> Self {
+        Self { name: name.into(), verdict: Some(Verdict::Approved) }
+    }
+
+    fn rejecting(name: &str) -> Self {
+        Self { name: name.into(), verdict: Some(Verdict::Rejected("peer says no".into())) }
+    }
+
+    fn unreachable(name: &str) -> Self {
+        Self { name: name.into(), verdict: None }
+    }
+}
+
+impl CourtPeer for FixedPeer {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn adjudicate(&self, request: &AdjudicationRequest, _timeout: Duration) -> Result<AdjudicationResponse, PeerError> {
+        let verdict = self.verdict.clone().ok_or(PeerError::Timeout)?;
+        Ok(AdjudicationResponse { request_id: request.request_id, court: self.name.clone(), verdict })
+    }
+}
+
+fn court() -> DistributedCourt {
+    DistributedCourt::new("local", JudicialCore::new())
+}
+
+fn read() -> SystemAction {
+    SystemAction::new("DATA_READ", "SELECT name FROM users", "analytics")
+}
+
+#[test]
+fn every_court_must_agree_by_default() {
+    let court = court().peer(FixedPeer::approving("a")).peer(FixedPeer::rejecting("b"));
+    assert_eq!(court.quorum(), 3);
+
+    let ruling = court.rule(read());
+    assert!(!ruling.verdict.is_approved());
+    assert_eq!((ruling.approvals, ruling.rejections), (2, 1));
+}
+
+#[test]
+fn quorum_does_not_depend_on_call_order() {
+    let before = court().with_quorum(2).peer(FixedPeer::approving("a")).peer(FixedPeer::rejecting("b"));
+    let after = court().peer(FixedPeer::approving("a")).peer(FixedPeer::rejecting("b")).with_quorum(2);
+
+    for court in [before, after] {
+        assert_eq!(court.quorum(), 2);
+        let ruling = court.rule(read());
+        assert!(ruling.verdict.is_approved());
+        assert!(!ruling.fallback);
+    }
+}
+
+#[test]
+fn quorum_is_clamped_to_the_courts_present() {
+    assert_eq!(court().with_quorum(0).peer(FixedPeer::approving("a")).quorum(), 1);
+    assert_eq!(court().with_quorum(9).peer(FixedPeer::approving("a")).quorum(), 2);
+}
+
+#[test]
+fn a_reachable_rejection_outweighs_the_fallback() {
+    for fallback in [FallbackPolicy::Reject, FallbackPolicy::LocalVerdict] {
+        let court = court()
+            .with_quorum(2)
+            .peer(FixedPeer::unreachable("a"))
+            .peer(FixedPeer::rejecting("b"))
+            .with_fallback(fallback);
+        let ruling = court.rule(read());
+        assert!(!ruling.fallback);
+        assert!(matches!(&ruling.verdict, Verdict::Rejected(reason) if reason == "peer says no"));
+        assert_eq!(ruling.unreachable, vec!["a".to_string()]);
+    }
+}
+
+#[test]
+fn unreachable_peers_leave_an_unopposed_outcome_to_the_fallback() {
+    let rejecting = court().peer(FixedPeer::unreachable("a")).peer(FixedPeer::approving("b"));
+    let ruling = rejecting.rule(read());
+    assert!(ruling.fallback);
+    assert!(!ruling.verdict.is_approved());
+
+    let local = court()
+        .peer(FixedPeer::unreachable("a"))
+        .peer(FixedPeer::approving("b"))
+        .with_fallback(FallbackPolicy::LocalVerdict);
+    let ruling = local.rule(read());
+    assert!(ruling.fallback);
+    assert!(ruling.verdict.is_approved());
+}
+
+/// Answers as a different court, after a delay.
+#[derive(Debug)]
+struct SlowImpostor;
+
+impl CourtPeer for SlowImpostor {
+    fn name(&self) -> &str {
+        "b"
+    }
+
+    fn adjudicate(&self, request: &AdjudicationRequest, _timeout: Duration) -> Result<AdjudicationResponse, PeerError> {
+        thread::sleep(Duration::from_millis(200));
+        Ok(AdjudicationResponse { request_id: request.request_id, court: "a".into(), verdict: Verdict::Approved })
+    }
+}
+
+#[derive(Debug)]
+struct SlowLaw;
+
+impl Law for SlowLaw {
+    fn number(&self) -> u32 {
+        3
+    }
+
+    fn description(&self) -> &str {
+        "Takes its time"
+    }
+
+    fn check(&self, _action: &SystemAction) -> Option<String> {
+        thread::sleep(Duration::from_millis(200));
+        None
+    }
+}
+
+#[test]
+fn votes_are_recorded_under_the_configured_peer_name() {
+    let ruling = court().peer(SlowImpostor).rule(read());
+    let names: Vec<&str> = ruling.responses.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["local", "b"]);
+}
+
+#[test]
+fn the_local_core_rules_while_peers_are_asked() {
+    let court = DistributedCourt::new("local", JudicialCore::builder().law(SlowLaw).build()).peer(SlowImpostor);
+    let started = Instant::now();
+    assert!(court.rule(read()).verdict.is_approved());
+    assert!(started.elapsed() < Duration::from_millis(380), "took {:?}", started.elapsed());
+}
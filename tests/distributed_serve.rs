@@ -0,0 +1,78 @@
+use judicial_core::distributed::{serve_with, AdjudicationRequest, ServeConfig};
+use judicial_core::{CourtPeer, JudicialCore, SystemAction, TcpPeer, Verdict};
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+fn server(config: ServeConfig) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    thread::spawn(move || serve_with(Arc::new(JudicialCore::new()), "remote", listener, config));
+    addr
+}
+
+fn request(id: u64) -> AdjudicationRequest {
+    AdjudicationRequest { request_id: id, action: SystemAction::new("DATA_READ", "SELECT 1", "analytics") }
+}
+
+const TIMEOUT: Duration = Duration::from_secs(2);
+
+#[test]
+fn allowed_peers_get_rulings() {
+    let peer = TcpPeer::new("remote", server(ServeConfig::default()));
+    let response = peer.adjudicate(&request(1), TIMEOUT).unwrap();
+    assert_eq!((response.request_id, response.court.as_str()), (1, "remote"));
+    assert!(matches!(response.verdict, Verdict::Approved));
+}
+
+#[test]
+fn other_peers_are_turned_away() {
+    let config = ServeConfig { allowed_peers: vec!["10.0.0.1".parse().unwrap()], ..ServeConfig::default() };
+    let peer = TcpPeer::new("remote", server(config));
+    assert!(peer.adjudicate(&request(1), TIMEOUT).is_err());
+}
+
+#[test]
+fn oversized_requests_close_the_connection() {
+    let addr = server(ServeConfig::default().max_request_bytes(1024));
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.set_read_timeout(Some(TIMEOUT)).unwrap();
+    // The server may hang up before all of it is written.
+    let _ = stream.write_all(&[b'x'; 64 * 1024]);
+    let mut reply = Vec::new();
+    let _ = stream.read_to_end(&mut reply);
+    assert!(reply.is_empty());
+
+    let peer = TcpPeer::new("remote", addr);
+    assert!(peer.adjudicate(&request(2), TIMEOUT).is_ok(), "server stopped serving");
+}
+
+#[test]
+fn connections_beyond_the_limit_are_refused_until_one_closes() {
+    let addr = server(ServeConfig::default().max_connections(1));
+    let peer = TcpPeer::new("remote", addr);
+
+    let idle = TcpStream::connect(addr).unwrap();
+    // Let the server take the idle connection's slot.
+    thread::sleep(Duration::from_millis(100));
+    assert!(peer.adjudicate(&request(1), TIMEOUT).is_err());
+
+    drop(idle);
+    let deadline = Instant::now() + TIMEOUT;
+    while peer.adjudicate(&request(2), TIMEOUT).is_err() {
+        assert!(Instant::now() < deadline, "slot never freed");
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn idle_connections_time_out() {
+    let addr = server(ServeConfig::default().max_connections(1).read_timeout(Duration::from_millis(100)));
+    let mut idle = TcpStream::connect(addr).unwrap();
+    idle.set_read_timeout(Some(TIMEOUT)).unwrap();
+    let started = Instant::now();
+    assert_eq!(idle.read(&mut [0; 16]).unwrap(), 0);
+    assert!(started.elapsed() < TIMEOUT);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "smtp")]
+
+use chrono::Utc;
+use judicial_core::email::{EmailAlerts, EmailConfig};
+use judicial_core::{Observer, ReviewItem, ReviewStatus, SlaAlert, SlaStage, SystemAction};
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread;
+
+/// Accept one SMTP session and return the message data it carried.
+fn smtp_server() -> (String, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("smtp://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut writer = stream;
+        writer.write_all(b"220 localhost\r\n").unwrap();
+
+        let mut data = String::new();
+        let mut in_data = false;
+        let mut line = String::new();
+        while reader.read_line(&mut line).unwrap() > 0 {
+            if in_data {
+                if line == ".\r\n" {
+                    in_data = false;
+                    writer.write_all(b"250 queued\r\n").unwrap();
+                } else {
+                    data.push_str(&line);
+                }
+            } else if line.starts_with("DATA") {
+                in_data = true;
+                writer.write_all(b"354 go ahead\r\n").unwrap();
+            } else if line.starts_with("QUIT") {
+                writer.write_all(b"221 bye\r\n").unwrap();
+                break;
+            } else {
+                writer.write_all(b"250 ok\r\n").unwrap();
+            }
+            line.clear();
+        }
+        data
+    });
+    (url, server)
+}
+
+#[test]
+fn sla_alerts_withhold_the_payload() {
+    let (url, server) = smtp_server();
+    let alerts = EmailAlerts::new(EmailConfig::new(url, "court@example.com", "oncall@example.com")).unwrap();
+
+    let payload = "UPDATE users SET password = 'hunter2'";
+    let item = ReviewItem {
+        id: 7,
+        action: SystemAction::new("DATA_WRITE", payload, "ops").with_actor("agent"),
+        reason: "Unknown action type 'DATA_WRITE'".into(),
+        submitted_at: Utc::now(),
+        ledger_hash: Some("abc123".into()),
+        status: ReviewStatus::Pending,
+    };
+    alerts.on_review_sla(&SlaAlert { stage: SlaStage::Breached, deadline: Utc::now(), item, link: None });
+    drop(alerts);
+
+    let mail = server.join().unwrap();
+    assert!(mail.contains("Review #7"));
+    assert!(mail.contains(&format!("Payload: {} bytes, withheld; see review #7", payload.len())));
+    assert!(!mail.contains("hunter2"));
+}
@@ -0,0 +1,116 @@
+use chrono::Utc;
+use judicial_core::events::{EventSink, PublishingBackend};
+use judicial_core::ledger::{LedgerBackend, LedgerEntry};
+use judicial_core::{GroupCommit, JudicialCore, SystemAction, WriteMode};
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// What the backend and the sink did, in order.
+type Log = Arc<Mutex<Vec<String>>>;
+
+#[derive(Debug)]
+struct LoggingBackend {
+    entries: Vec<LedgerEntry>,
+    log: Log,
+    failing: Arc<AtomicBool>,
+}
+
+impl LedgerBackend for LoggingBackend {
+    fn append(&mut self, entry: LedgerEntry) {
+        self.log.lock().unwrap().push(format!("append {}", entry.action.payload));
+        self.entries.push(entry);
+    }
+
+    fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.failing.load(Ordering::SeqCst) {
+            return Err(io::Error::other("disk full"));
+        }
+        self.log.lock().unwrap().push("flush".into());
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+struct LoggingSink(Log);
+
+impl EventSink for LoggingSink {
+    fn publish(&self, entry: &LedgerEntry) {
+        self.0.lock().unwrap().push(format!("publish {}", entry.action.payload));
+    }
+}
+
+fn publishing(log: &Log, failing: &Arc<AtomicBool>) -> PublishingBackend<LoggingBackend> {
+    let inner = LoggingBackend { entries: Vec::new(), log: Arc::clone(log), failing: Arc::clone(failing) };
+    PublishingBackend::new(inner, LoggingSink(Arc::clone(log)))
+}
+
+fn entry(payload: &str) -> LedgerEntry {
+    LedgerEntry {
+        timestamp: Utc::now(),
+        action: SystemAction::new("DATA_READ", payload, "analytics"),
+        verdict: "APPROVED".into(),
+        hash: String::new(),
+        previous_hash: None,
+        law: None,
+        jury: false,
+        dissents: Vec::new(),
+        violations: Vec::new(),
+    }
+}
+
+fn read(payload: &str) -> SystemAction {
+    SystemAction::new("DATA_READ", payload, "analytics")
+}
+
+#[test]
+fn entries_are_published_after_they_are_stored() {
+    let log = Log::default();
+    let court = JudicialCore::builder().ledger_backend(publishing(&log, &Arc::default())).build();
+    court.rule(read("SELECT 1"));
+
+    assert_eq!(*log.lock().unwrap(), ["append SELECT 1", "publish SELECT 1"]);
+}
+
+#[test]
+fn group_commit_publishes_after_the_flush() {
+    let log = Log::default();
+    let court = JudicialCore::builder()
+        .ledger_backend(publishing(&log, &Arc::default()))
+        .write_mode(WriteMode::GroupCommit(GroupCommit::new(Duration::from_millis(5))))
+        .build();
+    court.rule(read("SELECT 1"));
+
+    assert_eq!(*log.lock().unwrap(), ["append SELECT 1", "flush", "publish SELECT 1"]);
+}
+
+#[test]
+fn entries_a_failed_flush_left_behind_go_out_with_the_next_one() {
+    let log = Log::default();
+    let failing = Arc::new(AtomicBool::new(true));
+    let mut backend = publishing(&log, &failing);
+    backend.defer_writes(true);
+
+    backend.append(entry("first"));
+    assert!(backend.flush().is_err());
+    backend.append(entry("second"));
+    failing.store(false, Ordering::SeqCst);
+    backend.flush().unwrap();
+
+    assert_eq!(*log.lock().unwrap(), ["append first", "append second", "flush", "publish first", "publish second"]);
+}
+
+#[test]
+fn entries_already_stored_are_not_republished() {
+    let log = Log::default();
+    let inner = LoggingBackend { entries: vec![entry("old")], log: Arc::clone(&log), failing: Arc::default() };
+    let mut backend = PublishingBackend::new(inner, LoggingSink(Arc::clone(&log)));
+    backend.append(entry("new"));
+
+    assert_eq!(*log.lock().unwrap(), ["append new", "publish new"]);
+}
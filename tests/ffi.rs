@@ -0,0 +1,53 @@
+#![cfg(all(feature = "ffi", not(target_arch = "wasm32")))]
+
+use judicial_core::ffi_c::{
+    jc_core_free, jc_core_new, jc_rule, jc_string_free, JC_APPROVED, JC_INVALID_ARGUMENT, JC_REJECTED_WITH_SUGGESTION,
+};
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+/// Rule through the C ABI; returns the code and the reason, if any.
+fn rule(action_type: &str, payload: &str, context: &str) -> (i32, Option<String>) {
+    let (action_type, payload, context) =
+        (CString::new(action_type).unwrap(), CString::new(payload).unwrap(), CString::new(context).unwrap());
+    unsafe {
+        let core = jc_core_new();
+        let mut reason: *mut c_char = ptr::null_mut();
+        let code = jc_rule(core, action_type.as_ptr(), payload.as_ptr(), context.as_ptr(), &mut reason);
+        let text = (!reason.is_null()).then(|| CStr::from_ptr(reason).to_str().unwrap().to_string());
+        jc_string_free(reason);
+        jc_core_free(core);
+        (code, text)
+    }
+}
+
+#[test]
+fn approvals_come_back_with_their_ledger_wording() {
+    assert_eq!(rule("DATA_READ", "SELECT 1", "analytics"), (JC_APPROVED, Some("APPROVED".into())));
+}
+
+#[test]
+fn rejections_carry_their_suggestion() {
+    let (code, reason) = rule("SYSTEM_SHUTDOWN", "halt", "maintenance");
+    assert_eq!(code, JC_REJECTED_WITH_SUGGESTION);
+    assert_eq!(
+        reason.as_deref(),
+        Some("REJECTED: Non-emergency system shutdown; suggestion: Provide rollback mechanism or sandbox execution.")
+    );
+}
+
+#[test]
+fn invalid_arguments_leave_no_reason() {
+    let payload = CString::new("halt").unwrap();
+    unsafe {
+        let core = jc_core_new();
+        let mut reason: *mut c_char = ptr::null_mut();
+        let code = jc_rule(core, ptr::null(), payload.as_ptr(), payload.as_ptr(), &mut reason);
+        assert_eq!((code, reason.is_null()), (JC_INVALID_ARGUMENT, true));
+
+        let invalid = [0xff_u8, 0];
+        let code = jc_rule(core, invalid.as_ptr().cast(), payload.as_ptr(), payload.as_ptr(), &mut reason);
+        assert_eq!((code, reason.is_null()), (JC_INVALID_ARGUMENT, true));
+        jc_core_free(core);
+    }
+}
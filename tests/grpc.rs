@@ -0,0 +1,34 @@
+#![cfg(feature = "grpc")]
+
+use judicial_core::grpc::{ActionRequest, BatchRequest, ComplianceRequest, GrpcCourt, Judicial, LedgerQuery};
+use judicial_core::JudicialCore;
+use tonic::Request;
+
+fn action(action_type: &str, payload: &str) -> ActionRequest {
+    ActionRequest {
+        action_type: action_type.into(),
+        payload: payload.into(),
+        context: "analytics".into(),
+        ..ActionRequest::default()
+    }
+}
+
+#[tokio::test]
+async fn rulings_and_ledger_reads_answer() {
+    let court = GrpcCourt::new(JudicialCore::builder());
+
+    let reply = court.rule(Request::new(action("DATA_READ", "SELECT name FROM users"))).await.unwrap();
+    assert!(reply.into_inner().approved);
+
+    let batch = BatchRequest {
+        actions: vec![action("DATA_READ", "SELECT 1"), action("DATA_WRITE", "drop table users")],
+    };
+    let verdicts = court.rule_batch(Request::new(batch)).await.unwrap().into_inner().verdicts;
+    assert_eq!(verdicts.iter().map(|v| v.approved).collect::<Vec<_>>(), vec![true, false]);
+
+    let ledger = court.query_ledger(Request::new(LedgerQuery::default())).await.unwrap().into_inner();
+    assert_eq!(ledger.entries.len(), 3);
+
+    let compliance = court.get_compliance(Request::new(ComplianceRequest::default())).await.unwrap().into_inner();
+    assert_eq!((compliance.rulings, compliance.rejections), (3, 1));
+}
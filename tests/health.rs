@@ -0,0 +1,25 @@
+use judicial_core::{CacheHealth, JudicialCore, Strictness, SystemAction};
+use std::time::Duration;
+
+fn read() -> SystemAction {
+    SystemAction::new("DATA_READ", "SELECT name FROM users", "analytics")
+}
+
+#[test]
+fn health_reports_the_verdict_cache() {
+    let court = JudicialCore::builder().verdict_cache(16, Duration::from_secs(60)).build();
+    court.rule(read());
+    court.rule(read());
+
+    let cache = court.health().verdict_cache;
+    assert_eq!(cache, Some(CacheHealth { entries: 1, capacity: 16, hit_rate: 0.5, epoch: 0 }));
+
+    court.set_strictness(Strictness::Paranoid).unwrap();
+    let cache = court.health().verdict_cache.unwrap();
+    assert_eq!((cache.entries, cache.epoch), (0, 1));
+}
+
+#[test]
+fn health_without_a_cache_reports_none() {
+    assert_eq!(JudicialCore::new().health().verdict_cache, None);
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "http-server")]
+
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::Router;
+use http_body_util::BodyExt;
+use judicial_core::http::router;
+use judicial_core::{
+    Identity, IdentityError, IdentityProvider, JudicialCore, ReviewItem, ReviewStatus, SystemAction,
+    UnknownActionPolicy,
+};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+/// Accepts any token as the name of the actor it stands for.
+#[derive(Debug)]
+struct NameTokens;
+
+impl IdentityProvider for NameTokens {
+    fn verify(&self, credentials: &str) -> Result<Identity, IdentityError> {
+        Ok(Identity { actor: credentials.to_string(), roles: Vec::new() })
+    }
+}
+
+/// A core holding one quarantined action, and that action's review id.
+fn quarantined(provider: Option<NameTokens>) -> (Router, u64) {
+    let mut builder = JudicialCore::builder().unknown_action_policy(UnknownActionPolicy::Quarantine);
+    if let Some(provider) = provider {
+        builder = builder.identity_provider(provider);
+    }
+    let core = builder.build();
+    core.rule(SystemAction::new("TELEPORT", "beam me up", "ops"));
+    let id = core.reviews().pending()[0].id;
+    (router(Arc::new(core)), id)
+}
+
+async fn post(app: &Router, uri: &str, token: Option<&str>, body: &str) -> (StatusCode, Vec<u8>) {
+    let mut request = Request::post(uri).header(header::CONTENT_TYPE, "application/json");
+    if let Some(token) = token {
+        request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let response = app.clone().oneshot(request.body(Body::from(body.to_string())).unwrap()).await.unwrap();
+    let status = response.status();
+    (status, response.into_body().collect().await.unwrap().to_bytes().to_vec())
+}
+
+#[tokio::test]
+async fn rule_answers_with_the_verdict() {
+    let (app, _) = quarantined(None);
+    let action = r#"{"action_type": "DATA_READ", "payload": "SELECT name FROM users", "context": "analytics"}"#;
+    let (status, body) = post(&app, "/v1/rule", None, action).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, br#""Approved""#);
+}
+
+#[tokio::test]
+async fn claims_need_a_verified_reviewer() {
+    let (app, id) = quarantined(Some(NameTokens));
+    let uri = format!("/v1/reviews/{}/claim", id);
+
+    let (status, _) = post(&app, &uri, None, r#"{"reviewer": "alice"}"#).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, body) = post(&app, &uri, Some("alice"), r#"{"reviewer": "mallory"}"#).await;
+    assert_eq!(status, StatusCode::OK);
+    let item: ReviewItem = serde_json::from_slice(&body).unwrap();
+    assert!(matches!(item.status, ReviewStatus::Claimed { reviewer, .. } if reviewer == "alice"));
+}
+
+#[tokio::test]
+async fn only_the_claimant_resolves() {
+    let (app, id) = quarantined(Some(NameTokens));
+    post(&app, &format!("/v1/reviews/{}/claim", id), Some("alice"), "").await;
+
+    let uri = format!("/v1/reviews/{}/resolve", id);
+    let resolution = r#"{"decision": "Approve", "rationale": "expected"}"#;
+    let (status, _) = post(&app, &uri, Some("bob"), resolution).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = post(&app, &uri, Some("alice"), resolution).await;
+    assert_eq!(status, StatusCode::OK);
+    let item: ReviewItem = serde_json::from_slice(&body).unwrap();
+    assert!(matches!(item.status, ReviewStatus::Resolved { reviewer, .. } if reviewer == "alice"));
+}
+
+#[tokio::test]
+async fn reviews_are_refused_without_an_identity_provider() {
+    let (app, id) = quarantined(None);
+    let (status, _) = post(&app, &format!("/v1/reviews/{}/claim", id), Some("alice"), "").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+async fn get(app: &Router, uri: &str) -> (StatusCode, String) {
+    let response = app.clone().oneshot(Request::get(uri).body(Body::empty()).unwrap()).await.unwrap();
+    let status = response.status();
+    (status, String::from_utf8(response.into_body().collect().await.unwrap().to_bytes().to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn reporting_endpoints_answer() {
+    let (app, id) = quarantined(None);
+
+    let (status, body) = get(&app, "/v1/reviews").await;
+    assert_eq!(status, StatusCode::OK);
+    let pending: Vec<ReviewItem> = serde_json::from_str(&body).unwrap();
+    assert_eq!(pending[0].id, id);
+
+    let (status, body) = get(&app, "/v1/health").await;
+    assert_eq!(status, StatusCode::OK);
+    let health: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(health["pending_reviews"], 1);
+
+    let (status, body) = get(&app, "/v1/statistics").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("\"rulings\":1"), "{}", body);
+
+    let (status, body) = get(&app, "/metrics").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.contains("judicial_pending_reviews 1"), "{}", body);
+}
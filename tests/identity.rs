@@ -0,0 +1,184 @@
+use judicial_core::laws::Law;
+use judicial_core::{Identity, IdentityError, IdentityProvider, JudicialCore, SystemAction, Verdict};
+
+/// Accepts `Bearer alice-token` only.
+#[derive(Debug)]
+struct Tokens;
+
+impl IdentityProvider for Tokens {
+    fn verify(&self, credentials: &str) -> Result<Identity, IdentityError> {
+        match credentials {
+            "Bearer alice-token" => Ok(Identity { actor: "alice".into(), roles: vec!["exporter".into()] }),
+            _ => Err(IdentityError("unknown token".into())),
+        }
+    }
+}
+
+/// Exports need the verified `exporter` role.
+#[derive(Debug)]
+struct ExportersOnly;
+
+impl Law for ExportersOnly {
+    fn number(&self) -> u32 {
+        9
+    }
+
+    fn description(&self) -> &str {
+        "only exporters export"
+    }
+
+    fn check(&self, action: &SystemAction) -> Option<String> {
+        (action.action_type == "DATA_EXPORT" && !action.has_role("exporter")).then(|| "not an exporter".into())
+    }
+}
+
+fn export() -> SystemAction {
+    SystemAction::new("DATA_EXPORT", "orders.csv", "audit,compliance_approved,exporter")
+}
+
+#[test]
+fn verified_roles_reach_the_laws_and_credentials_do_not_reach_the_ledger() {
+    let court = JudicialCore::builder().law(ExportersOnly).identity_provider(Tokens).build();
+    let action = export().with_actor("mallory").with_credentials("Bearer alice-token");
+    assert!(court.rule(action).is_approved());
+
+    let entry = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert_eq!(entry.action.actor.as_deref(), Some("alice"));
+    assert_eq!(entry.action.roles, ["exporter"]);
+    assert_eq!(entry.action.credentials, None);
+    assert_eq!(court.verify_identity("Bearer alice-token").unwrap().actor, "alice");
+}
+
+#[test]
+fn unverified_actions_have_no_roles() {
+    let court = JudicialCore::builder().law(ExportersOnly).identity_provider(Tokens).build();
+    let Verdict::Rejected(reason) = court.rule(export().with_credentials("Bearer forged")) else {
+        panic!("forged credentials accepted");
+    };
+    assert_eq!(reason, "identity not verified: unknown token");
+    assert_eq!(court.verify_identity("Bearer forged").unwrap_err(), IdentityError("unknown token".into()));
+
+    // Self-declared roles, in the roles field or the context, count for nothing.
+    let mut claimed = export();
+    claimed.roles = vec!["exporter".into()];
+    assert!(!court.rule(claimed).is_approved());
+    let entry = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert!(entry.action.roles.is_empty());
+
+    let without_provider = JudicialCore::builder().law(ExportersOnly).build();
+    assert!(!without_provider.rule(export().with_credentials("Bearer alice-token")).is_approved());
+    assert!(without_provider.verify_identity("Bearer alice-token").is_err());
+}
+
+#[cfg(feature = "oidc")]
+mod oidc {
+    use judicial_core::identity::OidcProvider;
+    use judicial_core::{Identity, IdentityProvider};
+    use jsonwebtoken::{Algorithm, EncodingKey, Header};
+    use serde_json::{json, Value};
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    /// Ed25519 keys in PKCS#8 DER. The issuer publishes the first as `k1`.
+    const SIGNING_KEY: [u8; 48] = [
+        0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22, 0x04, 0x20, 0x05, 0x3f,
+        0x9c, 0x74, 0xa9, 0x6e, 0x57, 0x91, 0x18, 0x3b, 0xdd, 0xae, 0x51, 0x09, 0x27, 0x9e, 0xe6, 0xf1, 0x13, 0x88,
+        0xaa, 0x23, 0xae, 0x64, 0x68, 0x72, 0xd0, 0x33, 0xe0, 0xa6, 0x44, 0x5d,
+    ];
+    const PUBLIC_KEY: &str = "d82lxufTK1L0V4lZvsoO3l1RN9vKuBLiJKY58aiOCwI";
+    const OTHER_KEY: [u8; 48] = [
+        0x30, 0x2e, 0x02, 0x01, 0x00, 0x30, 0x05, 0x06, 0x03, 0x2b, 0x65, 0x70, 0x04, 0x22, 0x04, 0x20, 0x5a, 0xe4,
+        0x3f, 0x11, 0x7d, 0xcc, 0x98, 0x89, 0x01, 0x89, 0x13, 0xe2, 0x6b, 0x21, 0xcf, 0x3c, 0xae, 0xac, 0x79, 0x8a,
+        0x80, 0x43, 0x02, 0xbd, 0xe8, 0x8a, 0x18, 0xee, 0x95, 0xa9, 0x78, 0x7a,
+    ];
+
+    /// An issuer serving its discovery document and keys, with an HMAC
+    /// secret wrongly published as `h1`. Returns the issuer URL.
+    fn issuer() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let issuer = format!("http://{}", listener.local_addr().unwrap());
+        let discovery = json!({ "issuer": issuer, "jwks_uri": format!("{}/jwks", issuer) }).to_string();
+        let jwks = json!({ "keys": [
+            { "kty": "OKP", "crv": "Ed25519", "x": PUBLIC_KEY, "kid": "k1", "alg": "EdDSA" },
+            { "kty": "oct", "k": "c2VjcmV0", "kid": "h1", "alg": "HS256" },
+        ]}).to_string();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                reader.read_line(&mut request).unwrap();
+                let mut header = String::new();
+                while reader.read_line(&mut header).unwrap() > 2 {
+                    header.clear();
+                }
+                let (status, body) = match request.split_whitespace().nth(1).unwrap() {
+                    "/.well-known/openid-configuration" => (200, discovery.as_str()),
+                    "/jwks" => (200, jwks.as_str()),
+                    _ => (404, "{}"),
+                };
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status, body.len(), body
+                ).unwrap();
+            }
+        });
+        issuer
+    }
+
+    fn now() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    fn token(kid: &str, key: &[u8], claims: Value) -> String {
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some(kid.into());
+        jsonwebtoken::encode(&header, &claims, &EncodingKey::from_ed_der(key)).unwrap()
+    }
+
+    fn token_with(claims: Value) -> String {
+        token("k1", &SIGNING_KEY, claims)
+    }
+
+    fn claims(issuer: &str, audience: &str, expires: u64) -> Value {
+        json!({ "iss": issuer, "aud": audience, "exp": expires, "sub": "alice", "roles": ["exporter", "ops"] })
+    }
+
+    #[test]
+    fn tokens_signed_by_the_issuer_are_verified() {
+        let issuer = issuer();
+        let provider = OidcProvider::discover(&issuer, "judicial").unwrap();
+        let token = token_with(claims(&issuer, "judicial", now() + 600));
+        assert_eq!(
+            provider.verify(&format!("Bearer {}", token)).unwrap(),
+            Identity { actor: "alice".into(), roles: vec!["exporter".into(), "ops".into()] }
+        );
+
+        let provider = OidcProvider::discover(&issuer, "judicial").unwrap().roles_claim("groups");
+        let mut grouped = claims(&issuer, "judicial", now() + 600);
+        grouped["groups"] = json!("auditors  ops");
+        let identity = provider.verify(&token_with(grouped)).unwrap();
+        assert_eq!(identity.roles, ["auditors", "ops"]);
+    }
+
+    #[test]
+    fn other_tokens_are_refused() {
+        let issuer = issuer();
+        let provider = OidcProvider::discover(&issuer, "judicial").unwrap();
+        let refused = |token: String| provider.verify(&token).unwrap_err().to_string();
+
+        let valid = claims(&issuer, "judicial", now() + 600);
+        assert!(refused(token("k1", &OTHER_KEY, valid.clone())).contains("InvalidSignature"));
+        assert!(refused(token_with(claims(&issuer, "someone-else", now() + 600))).contains("InvalidAudience"));
+        assert!(refused(token_with(claims("https://evil.example", "judicial", now() + 600))).contains("InvalidIssuer"));
+        assert!(refused(token_with(claims(&issuer, "judicial", now() - 600))).contains("ExpiredSignature"));
+        assert_eq!(refused(token("k9", &SIGNING_KEY, valid.clone())), "identity not verified: unknown signing key k9");
+        assert_eq!(refused(token("h1", &SIGNING_KEY, valid)), "identity not verified: signing key h1 is symmetric");
+        assert!(provider.verify("not a token").is_err());
+
+        assert!(OidcProvider::discover(format!("{}/missing", issuer), "judicial").is_err());
+    }
+}
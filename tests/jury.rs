@@ -0,0 +1,73 @@
+use judicial_core::{
+    ComplianceWindow, Evaluator, JudicialCore, Jury, PolicyPack, Strictness, SystemAction, Verdict, VotingRule,
+};
+
+/// A juror that always gives the same verdict.
+#[derive(Debug)]
+struct Fixed(&'static str, Verdict);
+
+impl Evaluator for Fixed {
+    fn name(&self) -> &str {
+        self.0
+    }
+
+    fn evaluate(&self, _action: &SystemAction, _strictness: Strictness) -> Verdict {
+        self.1.clone()
+    }
+}
+
+fn export() -> SystemAction {
+    SystemAction::new("DATA_EXPORT", "customers.csv", "reporting")
+}
+
+#[test]
+fn jury_quarantines_go_to_review() {
+    let jury = Jury::new(VotingRule::Majority)
+        .juror(Fixed("cautious", Verdict::Quarantined("needs a second look".into())))
+        .juror(Fixed("lenient", Verdict::Approved))
+        .juror(Fixed("strict", Verdict::Rejected("no exports".into())));
+    let court = JudicialCore::builder().jury(jury).build();
+
+    let verdict = court.rule(export());
+    assert!(matches!(&verdict, Verdict::Quarantined(reason) if reason == "needs a second look"));
+
+    let pending = court.reviews().pending();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].action.payload, "customers.csv");
+    assert_eq!(pending[0].reason, "needs a second look");
+
+    let entry = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert_eq!(pending[0].ledger_hash.as_deref(), Some(entry.hash.as_str()));
+    assert_eq!(entry.dissents, ["lenient: APPROVED"]);
+}
+
+#[test]
+fn other_jury_verdicts_skip_review() {
+    let jury = Jury::new(VotingRule::Unanimous).juror(Fixed("strict", Verdict::Rejected("no exports".into())));
+    let court = JudicialCore::builder().jury(jury).build();
+
+    assert!(!court.rule(export()).is_approved());
+    assert!(court.reviews().pending().is_empty());
+}
+
+#[test]
+fn replay_skips_unanimous_jury_verdicts() {
+    // The laws would reject a non-emergency shutdown; the jury waves it through.
+    let jury = Jury::new(VotingRule::Unanimous).juror(Fixed("lenient", Verdict::Approved));
+    let court = JudicialCore::builder().jury(jury).build();
+    let shutdown = SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance");
+    assert!(court.rule(shutdown.clone()).is_approved());
+
+    let entry = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert!(entry.jury && entry.dissents.is_empty());
+    assert!(court.verify_ledger().is_ok());
+    assert!(court.replay(ComplianceWindow::Lifetime).is_empty());
+
+    // The same approval reached by the laws is replayed.
+    let court = JudicialCore::new();
+    let snapshot = court.snapshot();
+    court.apply_policy_pack(&PolicyPack::from_toml("laws = [1]").unwrap()).unwrap();
+    assert!(court.rule(shutdown).is_approved());
+    court.restore(&snapshot).unwrap();
+    assert_eq!(court.replay(ComplianceWindow::Lifetime).len(), 1);
+}
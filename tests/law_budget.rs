@@ -0,0 +1,107 @@
+use judicial_core::{JudicialCore, Law, LawBudget, SystemAction, TimeoutPolicy};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// A law that takes as long as it is told to, and notes where it ran.
+#[derive(Debug, Clone, Default)]
+struct SlowLaw {
+    calls: Arc<AtomicUsize>,
+    delay: Arc<Mutex<Duration>>,
+    threads: Arc<Mutex<HashSet<String>>>,
+}
+
+impl SlowLaw {
+    fn set_delay(&self, delay: Duration) {
+        *self.delay.lock().unwrap() = delay;
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+}
+
+impl Law for SlowLaw {
+    fn number(&self) -> u32 {
+        3
+    }
+
+    fn description(&self) -> &str {
+        "Takes its time"
+    }
+
+    fn check(&self, _action: &SystemAction) -> Option<String> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let name = thread::current().name().unwrap_or_default().to_string();
+        self.threads.lock().unwrap().insert(name);
+        let delay = *self.delay.lock().unwrap();
+        thread::sleep(delay);
+        None
+    }
+}
+
+const COOLDOWN: Duration = Duration::from_millis(100);
+
+fn court(law: &SlowLaw) -> Arc<JudicialCore> {
+    let budget = LawBudget::new(Duration::from_millis(20))
+        .on_timeout(TimeoutPolicy::FailClosed)
+        .trip_after(1)
+        .cooldown(COOLDOWN);
+    Arc::new(JudicialCore::builder().law(law.clone()).law_budget(3, budget).build())
+}
+
+fn read() -> SystemAction {
+    SystemAction::new("DATA_READ", "SELECT name FROM users", "analytics")
+}
+
+#[test]
+fn a_half_open_circuit_lets_exactly_one_probe_through() {
+    let law = SlowLaw::default();
+    law.set_delay(Duration::from_millis(300));
+    let court = court(&law);
+
+    assert!(!court.rule(read()).is_approved());
+    assert!(!court.rule(read()).is_approved());
+    assert_eq!(law.calls(), 1, "an open circuit skips the law");
+
+    thread::sleep(COOLDOWN + Duration::from_millis(20));
+    let rulers: Vec<_> = (0..8)
+        .map(|_| {
+            let court = Arc::clone(&court);
+            thread::spawn(move || court.rule(read()))
+        })
+        .collect();
+    for ruler in rulers {
+        assert!(!ruler.join().unwrap().is_approved());
+    }
+    assert_eq!(law.calls(), 2, "one probe, not one per caller");
+}
+
+#[test]
+fn a_successful_probe_closes_the_circuit() {
+    let law = SlowLaw::default();
+    law.set_delay(Duration::from_millis(300));
+    let court = court(&law);
+    assert!(!court.rule(read()).is_approved());
+
+    law.set_delay(Duration::ZERO);
+    thread::sleep(COOLDOWN + Duration::from_millis(20));
+    assert!(court.rule(read()).is_approved());
+    assert!(court.rule(read()).is_approved());
+    assert_eq!(law.calls(), 3);
+}
+
+#[test]
+fn evaluations_reuse_pool_threads() {
+    let law = SlowLaw::default();
+    let court = court(&law);
+    for _ in 0..200 {
+        assert!(court.rule(read()).is_approved());
+    }
+
+    let threads = law.threads.lock().unwrap();
+    assert!(threads.iter().all(|name| name.starts_with("law-budget-")), "{:?}", threads);
+    assert!(threads.len() <= 16, "{} threads for 200 evaluations", threads.len());
+}
@@ -0,0 +1,162 @@
+use judicial_core::ledger::{LedgerBackend, LedgerEntry};
+use judicial_core::{GroupCommit, JudicialCore, SystemAction, Verdict, WriteMode};
+use std::io;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Counts flushes and how many entries each has made durable.
+#[derive(Debug, Clone, Default)]
+struct Counters {
+    flushes: Arc<AtomicUsize>,
+    durable: Arc<AtomicUsize>,
+    /// Flushes fail while set.
+    failing: Arc<AtomicBool>,
+}
+
+#[derive(Debug)]
+struct CountingBackend {
+    entries: Vec<LedgerEntry>,
+    deferred: bool,
+    counters: Counters,
+}
+
+impl CountingBackend {
+    fn new(counters: &Counters) -> Self {
+        Self { entries: Vec::new(), deferred: false, counters: counters.clone() }
+    }
+
+    fn sync(&self) {
+        self.counters.flushes.fetch_add(1, Ordering::SeqCst);
+        self.counters.durable.store(self.entries.len(), Ordering::SeqCst);
+    }
+}
+
+impl LedgerBackend for CountingBackend {
+    fn append(&mut self, entry: LedgerEntry) {
+        self.entries.push(entry);
+        if !self.deferred {
+            self.sync();
+        }
+    }
+
+    fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.counters.failing.load(Ordering::SeqCst) {
+            return Err(io::Error::other("disk full"));
+        }
+        self.sync();
+        Ok(())
+    }
+
+    fn defer_writes(&mut self, defer: bool) {
+        self.deferred = defer;
+    }
+}
+
+fn court(counters: &Counters, max_latency: Duration) -> JudicialCore {
+    JudicialCore::builder()
+        .ledger_backend(CountingBackend::new(counters))
+        .write_mode(WriteMode::GroupCommit(GroupCommit::new(max_latency)))
+        .build()
+}
+
+fn action(i: usize) -> SystemAction {
+    SystemAction::new("DATA_READ", format!("SELECT {}", i), "research")
+}
+
+#[test]
+fn rule_returns_only_once_its_entry_is_flushed() {
+    let counters = Counters::default();
+    let court = court(&counters, Duration::from_millis(1));
+    for i in 0..20 {
+        court.rule(action(i));
+        assert!(counters.durable.load(Ordering::SeqCst) > i, "ruling {} returned before its flush", i);
+    }
+    assert_eq!(court.verify_ledger(), Ok(()));
+}
+
+#[test]
+fn concurrent_rulings_share_flushes() {
+    const THREADS: usize = 16;
+    const RULINGS_PER_THREAD: usize = 50;
+
+    let counters = Counters::default();
+    let court = Arc::new(court(&counters, Duration::from_millis(5)));
+    let workers: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let court = Arc::clone(&court);
+            thread::spawn(move || (0..RULINGS_PER_THREAD).for_each(|i| {
+                court.rule(action(i));
+            }))
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert_eq!(court.verify_ledger(), Ok(()));
+    assert_eq!(court.query_ledger(|_| true, 0, usize::MAX).len(), THREADS * RULINGS_PER_THREAD);
+    assert!(counters.flushes.load(Ordering::SeqCst) < THREADS * RULINGS_PER_THREAD);
+}
+
+#[test]
+fn batch_waits_for_one_flush_not_one_per_ruling() {
+    const BATCH: usize = 40;
+    let max_latency = Duration::from_millis(50);
+
+    let counters = Counters::default();
+    let court = court(&counters, max_latency);
+    let actions: Vec<_> = (0..BATCH).map(action).collect();
+
+    let started = Instant::now();
+    let verdicts = court.rule_batch(&actions);
+    let elapsed = started.elapsed();
+
+    assert_eq!(verdicts.len(), BATCH);
+    assert!(elapsed < max_latency * 4, "batch took {:?}", elapsed);
+    assert!(counters.flushes.load(Ordering::SeqCst) <= 2);
+    assert_eq!(counters.durable.load(Ordering::SeqCst), BATCH);
+    assert_eq!(court.verify_ledger(), Ok(()));
+}
+
+#[test]
+fn groups_close_at_max_entries() {
+    let counters = Counters::default();
+    let court = JudicialCore::builder()
+        .ledger_backend(CountingBackend::new(&counters))
+        .write_mode(WriteMode::GroupCommit(GroupCommit::new(Duration::from_secs(5)).max_entries(10)))
+        .build();
+    let actions: Vec<_> = (0..30).map(action).collect();
+
+    let started = Instant::now();
+    court.rule_batch(&actions);
+    assert!(started.elapsed() < Duration::from_secs(5), "full groups waited for max_latency");
+    assert_eq!(counters.flushes.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn a_failed_flush_withdraws_the_group_approvals() {
+    let counters = Counters::default();
+    let court = court(&counters, Duration::from_millis(1));
+    counters.failing.store(true, Ordering::SeqCst);
+
+    let Verdict::Rejected(reason) = court.rule(action(0)) else { panic!("approved without a flush") };
+    assert_eq!(reason, "Ruling not persisted to the ledger: disk full");
+
+    let shutdown = SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance");
+    let verdicts = court.rule_batch(&[action(1), shutdown]);
+    assert!(matches!(&verdicts[0], Verdict::Rejected(reason) if reason.contains("not persisted")));
+    assert!(matches!(&verdicts[1], Verdict::RejectedWithSuggestion(reason, _) if reason == "Non-emergency system shutdown"));
+    assert_eq!(counters.durable.load(Ordering::SeqCst), 0);
+
+    // The next flush persists what the failed ones left behind.
+    counters.failing.store(false, Ordering::SeqCst);
+    assert!(court.rule(action(2)).is_approved());
+    assert_eq!(counters.durable.load(Ordering::SeqCst), 4);
+    assert_eq!(court.verify_ledger(), Ok(()));
+}
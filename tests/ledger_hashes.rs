@@ -0,0 +1,51 @@
+use chrono::Utc;
+use judicial_core::ledger::LedgerEntry;
+use judicial_core::SystemAction;
+use sha2::{Digest, Sha256};
+
+/// The action as the first ledgers hashed it, before actors and roles.
+mod first {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    pub struct SystemAction {
+        pub action_type: String,
+        pub payload: String,
+        pub context: String,
+    }
+}
+
+#[test]
+fn entries_without_an_actor_hash_as_the_first_ledgers_did() {
+    let timestamp = Utc::now();
+    let action = SystemAction::new("DATA_READ", "SELECT 1", "analytics");
+    let first = first::SystemAction {
+        action_type: action.action_type.clone(),
+        payload: action.payload.clone(),
+        context: action.context.clone(),
+    };
+    let mut hasher = Sha256::new();
+    hasher.update(format!("{:?}{:?}{:?}", timestamp, first, "APPROVED").as_bytes());
+    let expected = format!("{:x}", hasher.finalize());
+
+    let entry = LedgerEntry {
+        timestamp,
+        action,
+        verdict: "APPROVED".into(),
+        hash: String::new(),
+        previous_hash: None,
+        law: None,
+        jury: false,
+        dissents: Vec::new(),
+        violations: Vec::new(),
+    };
+    assert_eq!(entry.compute_hash(), expected);
+}
+
+#[test]
+fn actors_are_hashed_when_present_and_credentials_never() {
+    let action = SystemAction::new("DATA_READ", "SELECT 1", "analytics").with_actor("alice").with_credentials("s3cret");
+    let printed = format!("{:?}", action);
+    assert!(printed.contains("alice"), "{}", printed);
+    assert!(!printed.contains("s3cret"), "{}", printed);
+    assert!(!format!("{:?}", SystemAction::new("DATA_READ", "SELECT 1", "analytics")).contains("actor"));
+}
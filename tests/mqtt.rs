@@ -0,0 +1,136 @@
+#![cfg(feature = "mqtt")]
+
+use judicial_core::mqtt::{MqttBridge, MqttConfig};
+use judicial_core::JudicialCore;
+use serde_json::{json, Value};
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// The broker side of one MQTT 3.1.1 connection, just enough to hand the
+/// bridge requests and collect its replies.
+struct Broker {
+    stream: TcpStream,
+}
+
+impl Broker {
+    /// Accept the bridge, acknowledge its connection and subscription, and
+    /// return the topic it subscribed to.
+    fn accept(listener: &TcpListener) -> (Self, String) {
+        let (stream, _) = listener.accept().unwrap();
+        stream.set_read_timeout(Some(Duration::from_secs(10))).unwrap();
+        let mut broker = Broker { stream };
+
+        let (kind, _) = broker.read_packet();
+        assert_eq!(kind, 0x10, "expected CONNECT");
+        broker.stream.write_all(&[0x20, 0x02, 0x00, 0x00]).unwrap();
+
+        let (kind, body) = broker.read_packet();
+        assert_eq!(kind, 0x82, "expected SUBSCRIBE");
+        let topic_len = u16::from_be_bytes([body[2], body[3]]) as usize;
+        let topic = String::from_utf8(body[4..4 + topic_len].to_vec()).unwrap();
+        let qos = body[4 + topic_len];
+        broker.stream.write_all(&[0x90, 0x03, body[0], body[1], qos]).unwrap();
+        (broker, topic)
+    }
+
+    fn read_packet(&mut self) -> (u8, Vec<u8>) {
+        let mut byte = [0; 1];
+        self.stream.read_exact(&mut byte).unwrap();
+        let kind = byte[0];
+        let (mut length, mut shift) = (0usize, 0);
+        loop {
+            self.stream.read_exact(&mut byte).unwrap();
+            length |= ((byte[0] & 0x7f) as usize) << shift;
+            shift += 7;
+            if byte[0] & 0x80 == 0 {
+                break;
+            }
+        }
+        let mut body = vec![0; length];
+        self.stream.read_exact(&mut body).unwrap();
+        (kind, body)
+    }
+
+    /// Publish `payload` at QoS 0.
+    fn publish(&mut self, topic: &str, payload: &[u8]) {
+        let mut body = (topic.len() as u16).to_be_bytes().to_vec();
+        body.extend_from_slice(topic.as_bytes());
+        body.extend_from_slice(payload);
+        let mut packet = vec![0x30];
+        let mut length = body.len();
+        loop {
+            let digit = (length % 128) as u8;
+            length /= 128;
+            packet.push(if length > 0 { digit | 0x80 } else { digit });
+            if length == 0 {
+                break;
+            }
+        }
+        packet.extend(body);
+        self.stream.write_all(&packet).unwrap();
+    }
+
+    /// The next QoS 1 publish from the bridge, acknowledged, as its topic
+    /// and JSON payload.
+    fn reply(&mut self) -> (String, Value) {
+        let (kind, body) = self.read_packet();
+        assert_eq!(kind & 0xf0, 0x30, "expected PUBLISH, got {:#x}", kind);
+        let topic_len = u16::from_be_bytes([body[0], body[1]]) as usize;
+        let topic = String::from_utf8(body[2..2 + topic_len].to_vec()).unwrap();
+        let packet_id = &body[2 + topic_len..4 + topic_len];
+        self.stream.write_all(&[0x40, 0x02, packet_id[0], packet_id[1]]).unwrap();
+        (topic, serde_json::from_slice(&body[4 + topic_len..]).unwrap())
+    }
+}
+
+fn bridge(max_payload: usize) -> (Broker, String, Arc<JudicialCore>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut config = MqttConfig::new("127.0.0.1", listener.local_addr().unwrap().port());
+    config.max_payload = max_payload;
+    let core = Arc::new(JudicialCore::new());
+    let bridge = MqttBridge::new(Arc::clone(&core), config);
+    thread::spawn(move || bridge.run());
+    let (broker, topic) = Broker::accept(&listener);
+    (broker, topic, core)
+}
+
+#[test]
+fn requests_are_answered_on_the_response_topic() {
+    let (mut broker, topic, core) = bridge(4096);
+    assert_eq!(topic, "judicial/requests");
+
+    broker.publish(&topic, br#"{"id": "42", "action_type": "SYSTEM_SHUTDOWN", "payload": "halt", "context": "maintenance"}"#);
+    let (response_topic, reply) = broker.reply();
+    assert_eq!(response_topic, "judicial/responses");
+    assert_eq!(
+        reply,
+        json!({"id": "42", "verdict": "REJECTED_WITH_SUGGESTION", "approved": false, "reason": "Non-emergency system shutdown"})
+    );
+
+    broker.publish(&topic, br#"{"action_type": "DATA_READ", "payload": "SELECT 1", "context": "sensor-3"}"#);
+    assert_eq!(broker.reply().1, json!({"id": null, "verdict": "APPROVED", "approved": true}));
+
+    let ruled: Vec<String> = core.query_ledger(|_| true, 0, usize::MAX).into_iter()
+        .map(|e| e.action.action_type)
+        .collect();
+    assert_eq!(ruled, ["SYSTEM_SHUTDOWN", "DATA_READ"]);
+}
+
+#[test]
+fn bad_requests_are_answered_with_an_error_and_not_ruled_on() {
+    let (mut broker, topic, core) = bridge(128);
+
+    let oversized = format!(r#"{{"id": "1", "action_type": "DATA_READ", "payload": "{}", "context": ""}}"#, "x".repeat(200));
+    broker.publish(&topic, oversized.as_bytes());
+    assert_eq!(broker.reply().1, json!({"id": null, "error": "payload exceeds 128 bytes"}));
+
+    broker.publish(&topic, b"halt everything");
+    let reply = broker.reply().1;
+    assert_eq!(reply["id"], Value::Null);
+    assert!(reply["error"].as_str().unwrap().starts_with("expected value"), "{}", reply);
+
+    assert!(core.query_ledger(|_| true, 0, usize::MAX).is_empty());
+}
@@ -0,0 +1,131 @@
+#![cfg(all(feature = "plugins", unix))]
+
+//! Builds `tests/plugins/keyword_law.c` with the system C compiler.
+
+use judicial_core::laws::Law;
+use judicial_core::plugins::{LawPlugins, PluginError};
+use judicial_core::{JudicialCore, Strictness, SystemAction, UnknownActionPolicy, Verdict};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// An empty plugin directory unique to this test and process.
+fn plugin_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("judicial-plugins-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Build the keyword law into `dir/file`, with extra `-D` definitions.
+fn build(dir: &Path, file: &str, number: u32, keyword: &str, defines: &[&str]) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let status = Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".into()))
+        .args(["-shared", "-fPIC", "-o"])
+        .arg(dir.join(file))
+        .arg("-I").arg(root.join("include"))
+        .arg(format!("-DNUMBER={}", number))
+        .arg(format!("-DKEYWORD=\"{}\"", keyword))
+        .args(defines.iter().map(|d| format!("-D{}", d)))
+        .arg(root.join("tests/plugins/keyword_law.c"))
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
+
+fn plugins(dir: &Path) -> LawPlugins {
+    // SAFETY: the directory only holds libraries built by these tests.
+    unsafe { LawPlugins::new(dir) }
+}
+
+fn analysis(payload: &str) -> SystemAction {
+    SystemAction::new("DATA_ANALYSIS", payload, "research")
+}
+
+#[test]
+fn plugin_laws_follow_the_builtin_laws() {
+    let dir = plugin_dir("rule");
+    build(&dir, "mining-1.so", 20, "xmrig", &[]);
+    fs::write(dir.join("README.txt"), "not a library").unwrap();
+
+    let laws = plugins(&dir).load().unwrap();
+    assert!(laws[0].governs("GPU_JOB") && !laws[0].governs("SYSTEM_CMD"));
+    assert_eq!(laws.len(), 1);
+    assert_eq!((laws[0].number(), laws[0].description(), laws[0].plugin()), (20, "no xmrig", "keywords-xmrig"));
+    assert_eq!(laws[0].path(), dir.join("mining-1.so"));
+
+    let court = JudicialCore::builder()
+        .unknown_action_policy(UnknownActionPolicy::FailClosed)
+        .law_plugins(plugins(&dir))
+        .unwrap()
+        .build();
+    // Action types a plugin law governs are known.
+    assert!(court.rule(SystemAction::new("GPU_JOB", "train", "research")).is_approved());
+    let Verdict::RejectedWithSuggestion(reason, suggestion) = court.rule(analysis("run xmrig --pool")) else {
+        panic!("approved")
+    };
+    assert_eq!((reason.as_str(), suggestion.as_str()), ("mentions xmrig", "Use the approved compute pool."));
+    assert!(matches!(court.rule(analysis("curl https://example.com")), Verdict::Approved));
+    // The built-in laws come first and decide.
+    let shutdown = SystemAction::new("SYSTEM_SHUTDOWN", "halt xmrig", "maintenance");
+    let Verdict::RejectedWithSuggestion(reason, suggestion) = court.rule(shutdown) else { panic!("approved") };
+    assert_eq!(reason, "Law 2: Non-emergency system shutdown; Law 20: mentions xmrig");
+    assert_eq!(suggestion, "Provide rollback mechanism or sandbox execution.");
+
+    // Warnings count under paranoid strictness; the law is not absolute.
+    court.set_strictness(Strictness::Paranoid).unwrap();
+    let Verdict::RejectedWithSuggestion(warning, _) = court.rule(analysis("curl https://example.com")) else {
+        panic!("approved")
+    };
+    assert_eq!(warning, "downloads something");
+    court.set_strictness(Strictness::Permissive).unwrap();
+    assert!(matches!(court.rule(analysis("xmrig")), Verdict::ApprovedWithWarning(_)));
+}
+
+#[test]
+fn reloading_picks_up_added_and_removed_libraries() {
+    let dir = plugin_dir("reload");
+    build(&dir, "mining-1.so", 20, "xmrig", &[]);
+    let court = JudicialCore::builder().law_plugins(plugins(&dir)).unwrap().build();
+    assert!(court.rule(analysis("minerd -o pool")).is_approved());
+
+    build(&dir, "mining-2.so", 21, "minerd", &[]);
+    assert_eq!(court.reload_plugins().unwrap(), 2);
+    assert!(!court.rule(analysis("minerd -o pool")).is_approved());
+
+    fs::remove_file(dir.join("mining-1.so")).unwrap();
+    assert_eq!(court.reload_plugins().unwrap(), 1);
+    assert!(court.rule(analysis("xmrig")).is_approved());
+
+    let changes = court.query_ledger(|e| e.action.action_type == "POLICY_CHANGE", 0, usize::MAX);
+    assert!(changes.last().unwrap().action.payload.contains("[21]"), "{:?}", changes.last().unwrap().action);
+}
+
+#[test]
+fn broken_plugins_are_refused_and_leave_the_laws_alone() {
+    let dir = plugin_dir("broken");
+    build(&dir, "mining-1.so", 20, "xmrig", &[]);
+    let court = JudicialCore::builder().law_plugins(plugins(&dir)).unwrap().build();
+
+    build(&dir, "mining-2.so", 21, "minerd", &["ABI_VERSION=2"]);
+    let error = court.reload_plugins().unwrap_err();
+    assert!(matches!(&error, PluginError::AbiVersion { path, found: 2 } if path.ends_with("mining-2.so")), "{}", error);
+    assert!(error.to_string().ends_with("mining-2.so uses plugin ABI 2, expected 1"), "{}", error);
+    assert!(!court.rule(analysis("xmrig")).is_approved(), "a failed reload dropped the loaded laws");
+
+    fs::remove_file(dir.join("mining-2.so")).unwrap();
+    build(&dir, "mining-3.so", 21, "minerd", &[]);
+    build(&dir, "mining-4.so", 22, "cpuminer", &["NO_DESCRIPTION"]);
+    assert!(matches!(court.reload_plugins(), Err(PluginError::InvalidDeclaration { .. })));
+    assert!(court.rule(analysis("minerd")).is_approved(), "half a reload was applied");
+
+    fs::write(dir.join("mining-4.so"), "not a library").unwrap();
+    assert!(matches!(court.reload_plugins(), Err(PluginError::Load { .. })));
+
+    fs::remove_file(dir.join("mining-4.so")).unwrap();
+    court.shutdown().unwrap();
+    assert!(matches!(court.reload_plugins(), Err(PluginError::ShutDown)));
+
+    let missing = JudicialCore::builder().law_plugins(plugins(&dir.join("missing")));
+    assert!(matches!(missing, Err(PluginError::Io(_))));
+}
@@ -0,0 +1,58 @@
+/* A law plugin for tests/plugins.rs, built with -DNUMBER=n -DKEYWORD="..."
+ * and optionally -DABI_VERSION=n or -DNO_DESCRIPTION. */
+
+#include "judicial_plugin.h"
+
+#include <stdlib.h>
+#include <string.h>
+
+#ifndef ABI_VERSION
+#define ABI_VERSION JUDICIAL_PLUGIN_ABI_VERSION
+#endif
+
+#ifdef NO_DESCRIPTION
+#define DESCRIPTION NULL
+#else
+#define DESCRIPTION "no " KEYWORD
+#endif
+
+static bool governs(const char *action_type) {
+  return strcmp(action_type, "SYSTEM_CMD") != 0;
+}
+
+static char *check(const char *action_json) {
+  return strstr(action_json, KEYWORD) ? strdup("mentions " KEYWORD) : NULL;
+}
+
+static char *check_warning(const char *action_json) {
+  return strstr(action_json, "curl") ? strdup("downloads something") : NULL;
+}
+
+static void free_string(char *s) {
+  free(s);
+}
+
+static const JudicialPluginLaw laws[] = {{
+    .number = NUMBER,
+    .description = DESCRIPTION,
+    .absolute = false,
+    .suggestion = "Use the approved compute pool.",
+    .governs = governs,
+    .check = check,
+    .check_warning = check_warning,
+}};
+
+static const JudicialPlugin plugin = {
+    .name = "keywords-" KEYWORD,
+    .law_count = 1,
+    .laws = laws,
+    .free_string = free_string,
+};
+
+uint32_t judicial_plugin_abi_version(void) {
+  return ABI_VERSION;
+}
+
+const JudicialPlugin *judicial_plugin(void) {
+  return &plugin;
+}
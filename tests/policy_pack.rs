@@ -0,0 +1,68 @@
+use judicial_core::{JudicialCore, PolicyPack, PolicyPackError, Strictness, SystemAction, Verdict};
+use std::fs;
+
+fn policy_changes(court: &JudicialCore) -> usize {
+    court.query_ledger(|entry| entry.action.action_type == "POLICY_CHANGE", 0, usize::MAX).len()
+}
+
+fn shutdown() -> SystemAction {
+    SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance")
+}
+
+#[test]
+fn a_pack_loaded_at_runtime_takes_effect_at_once() {
+    let path = std::env::temp_dir().join(format!("judicial-pack-{}.toml", std::process::id()));
+    fs::write(
+        &path,
+        r#"
+        strictness = "Paranoid"
+        laws = [1]
+        unknown_action_policy = "FailClosed"
+        "#,
+    )
+    .unwrap();
+
+    let court = JudicialCore::builder().verdict_cache(64, std::time::Duration::from_secs(60)).build();
+    assert!(!court.rule(shutdown()).is_approved());
+
+    court.load_policy_pack(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(court.strictness(), Strictness::Paranoid);
+    // Law 2 is gone, and the cached rejection with it.
+    assert!(matches!(court.rule(shutdown()), Verdict::Approved));
+    assert!(matches!(court.rule(SystemAction::new("TELEPORT", "", "")), Verdict::Rejected(_)));
+
+    let changes = court.query_ledger(|entry| entry.action.action_type == "POLICY_CHANGE", 0, usize::MAX);
+    assert_eq!(changes.len(), 1);
+    assert!(changes[0].action.payload.starts_with("policy_pack: laws [1], strictness paranoid"));
+}
+
+#[test]
+fn an_invalid_pack_changes_nothing() {
+    let court = JudicialCore::new();
+    let pack = PolicyPack::from_toml(
+        r#"
+        strictness = "Paranoid"
+        unknown_action_policy = "FailClosed"
+        laws = [1, 9]
+        "#,
+    )
+    .unwrap();
+
+    assert!(matches!(court.apply_policy_pack(&pack), Err(PolicyPackError::UnknownLaw(9))));
+    assert_eq!(court.strictness(), Strictness::Standard);
+    assert!(!court.rule(shutdown()).is_approved());
+    assert_eq!(policy_changes(&court), 0);
+}
+
+#[test]
+fn packs_apply_on_top_of_the_current_policy() {
+    let court = JudicialCore::new();
+    court.apply_policy_pack(&PolicyPack::from_toml(r#"unknown_action_policy = "Quarantine""#).unwrap()).unwrap();
+    court.apply_policy_pack(&PolicyPack::from_toml(r#"strictness = "Permissive""#).unwrap()).unwrap();
+
+    assert_eq!(court.strictness(), Strictness::Permissive);
+    assert!(matches!(court.rule(SystemAction::new("TELEPORT", "", "")), Verdict::Quarantined(_)));
+    assert_eq!(policy_changes(&court), 2);
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "postgres")]
+
+//! Tests against a real server run when `JUDICIAL_TEST_POSTGRES` holds a
+//! libpq-style connection string, e.g. `host=127.0.0.1 user=postgres`. Each
+//! test creates its own database on that server.
+
+use judicial_core::ledger::{verify_entries, LedgerBackend};
+use judicial_core::postgres::{PostgresBackend, PostgresReviewStore};
+use judicial_core::review::ReviewStore;
+use judicial_core::{JudicialCore, ReviewDecision, ReviewError, SystemAction, UnknownActionPolicy};
+use postgres::{Client, NoTls};
+
+/// Connection parameters for a fresh database, or `None` without a server.
+fn database(name: &str) -> Option<String> {
+    let Ok(server) = std::env::var("JUDICIAL_TEST_POSTGRES") else {
+        eprintln!("JUDICIAL_TEST_POSTGRES is not set; skipping");
+        return None;
+    };
+    let database = format!("judicial_{}_{}", name, std::process::id());
+    let mut admin = Client::connect(&server, NoTls).unwrap();
+    admin.batch_execute(&format!("DROP DATABASE IF EXISTS {}", database)).unwrap();
+    admin.batch_execute(&format!("CREATE DATABASE {}", database)).unwrap();
+    Some(format!("{} dbname={}", server, database))
+}
+
+fn core(params: &str) -> JudicialCore {
+    JudicialCore::builder()
+        .unknown_action_policy(UnknownActionPolicy::Quarantine)
+        .ledger_backend(PostgresBackend::connect(params).unwrap())
+        .review_store(PostgresReviewStore::connect(params).unwrap())
+        .build()
+}
+
+fn read(actor: &str) -> SystemAction {
+    SystemAction::new("DATA_READ", "SELECT 1", "analytics").with_actor(actor)
+}
+
+#[test]
+fn cores_append_to_one_chain() {
+    let Some(params) = database("chain") else { return };
+    let first = core(&params);
+    let second = core(&params);
+
+    first.rule(read("first"));
+    second.rule(read("second"));
+    first.rule(read("first"));
+    assert!(first.verify_ledger().is_ok());
+    assert!(second.verify_ledger().is_ok());
+
+    let mut shared = PostgresBackend::connect(&params).unwrap();
+    shared.flush().unwrap();
+    verify_entries(shared.entries()).unwrap();
+    let actors: Vec<&str> = shared.entries().iter()
+        .filter(|e| e.action.action_type == "DATA_READ")
+        .map(|e| e.action.actor.as_deref().unwrap())
+        .collect();
+    assert_eq!(actors, ["first", "second", "first"]);
+    // The first core caught up with the second's entry before chaining on.
+    assert_eq!(first.query_ledger(|_| true, 0, usize::MAX).len(), shared.entries().len());
+}
+
+#[test]
+fn cores_share_one_review_queue() {
+    let Some(params) = database("reviews") else { return };
+    let first = core(&params);
+    let second = core(&params);
+
+    first.rule(SystemAction::new("TELEPORT", "beam me up", "ops"));
+    let pending = second.reviews().pending();
+    assert_eq!(pending.len(), 1);
+    let id = pending[0].id;
+
+    second.claim_review(id, "alice").unwrap();
+    assert_eq!(first.claim_review(id, "bob").unwrap_err(), ReviewError::AlreadyClaimed { reviewer: "alice".into() });
+    second.resolve_review(id, ReviewDecision::Approve, "known transport").unwrap();
+    assert_eq!(first.resolve_review(id, ReviewDecision::Reject, "no").unwrap_err(), ReviewError::AlreadyResolved);
+    assert!(first.reviews().pending().is_empty());
+
+    let store = PostgresReviewStore::connect(&params).unwrap();
+    assert!(store.get(id + 1).unwrap().is_none());
+    assert_eq!(first.claim_review(id + 1, "bob").unwrap_err(), ReviewError::NotFound(id + 1));
+}
+
+#[test]
+fn unreachable_servers_are_reported() {
+    // Nothing listens on the discard port.
+    let params = "host=127.0.0.1 port=9 user=judicial connect_timeout=2";
+    assert!(PostgresBackend::connect(params).is_err());
+    assert!(PostgresReviewStore::connect(params).is_err());
+}
@@ -0,0 +1,51 @@
+use chrono::Utc;
+use judicial_core::precedent::Precedents;
+use judicial_core::{PrecedentPolicy, ReviewDecision, ReviewItem, ReviewStatus, SystemAction};
+
+const REASON: &str = "Unknown action type 'TELEPORT'";
+
+fn decided(id: u64, decision: ReviewDecision) -> ReviewItem {
+    ReviewItem {
+        id,
+        action: SystemAction::new("TELEPORT", "beam me up", "ops"),
+        reason: REASON.into(),
+        submitted_at: Utc::now(),
+        ledger_hash: None,
+        status: ReviewStatus::Resolved {
+            reviewer: "alice".into(),
+            decision,
+            rationale: "seen it before".into(),
+            resolved_at: Utc::now(),
+        },
+    }
+}
+
+fn precedents(decisions: &[ReviewDecision], confidence_threshold: f64) -> Precedents {
+    let precedents = Precedents::new(PrecedentPolicy { min_decisions: 2, confidence_threshold });
+    for (id, decision) in decisions.iter().enumerate() {
+        precedents.record(&decided(id as u64 + 1, *decision));
+    }
+    precedents
+}
+
+#[test]
+fn agreeing_reviews_set_a_precedent() {
+    let precedents = precedents(&[ReviewDecision::Approve; 3], 0.9);
+    let precedent = precedents.find("TELEPORT", REASON).unwrap();
+    assert_eq!((precedent.decision, precedent.review, precedent.decisions), (ReviewDecision::Approve, 3, 3));
+    assert!(precedents.find("TELEPORT", "some other reason").is_none());
+}
+
+#[test]
+fn a_tie_leans_to_rejection() {
+    let precedents = precedents(&[ReviewDecision::Approve, ReviewDecision::Reject], 0.5);
+    let precedent = precedents.find("TELEPORT", REASON).unwrap();
+    assert_eq!((precedent.decision, precedent.review, precedent.confidence), (ReviewDecision::Reject, 2, 0.5));
+}
+
+#[test]
+fn too_few_or_split_reviews_set_none() {
+    assert!(precedents(&[ReviewDecision::Approve], 0.5).find("TELEPORT", REASON).is_none());
+    let split = [ReviewDecision::Approve, ReviewDecision::Reject, ReviewDecision::Approve];
+    assert!(precedents(&split, 0.9).find("TELEPORT", REASON).is_none());
+}
@@ -0,0 +1,71 @@
+use judicial_core::laws::SafetyLaw;
+use judicial_core::{
+    JudicialCore, LawSet, PolicyPack, PolicyPackError, PolicyProfile, Strictness, SystemAction, Verdict,
+};
+
+fn shutdown(context: &str) -> SystemAction {
+    SystemAction::new("SYSTEM_SHUTDOWN", "halt", context)
+}
+
+/// Lawful, but the rollback is promised in the payload, not the context.
+fn cleanup(context: &str) -> SystemAction {
+    SystemAction::new("SYSTEM_CMD", "backup && rm -rf /data/temp", format!("admin {}", context))
+}
+
+#[test]
+fn the_context_selects_a_profile() {
+    let court = JudicialCore::builder()
+        .profile("sleep_protocol", PolicyProfile::new(LawSet::new().with(SafetyLaw)))
+        .profile("production", PolicyProfile::new(LawSet::master_pair()).with_strictness(Strictness::Paranoid))
+        .build();
+
+    assert!(court.rule(shutdown("sleep_protocol maintenance")).is_approved());
+    assert!(matches!(court.rule(shutdown("maintenance")), Verdict::RejectedWithSuggestion(..)));
+
+    assert!(matches!(court.rule(cleanup("staging")), Verdict::Approved));
+    assert!(matches!(court.rule(cleanup("production")), Verdict::RejectedWithSuggestion(..)));
+    assert_eq!(court.strictness(), Strictness::Standard, "a profile only overrides its own actions");
+
+    // The first registered profile wins when the context names several.
+    assert!(court.rule(shutdown("production sleep_protocol")).is_approved());
+}
+
+#[test]
+fn registering_a_profile_again_replaces_it() {
+    let court = JudicialCore::builder()
+        .profile("staging", PolicyProfile::new(LawSet::new().with(SafetyLaw)))
+        .profile("staging", PolicyProfile::new(LawSet::master_pair()))
+        .build();
+    assert!(!court.rule(shutdown("staging maintenance")).is_approved());
+    assert_eq!(court.health().profiles, 1);
+}
+
+#[test]
+fn policy_packs_install_profiles() {
+    let court = JudicialCore::new();
+    let pack = PolicyPack::from_toml(
+        "[[profiles]]\nname = \"sandbox\"\nlaws = [1]\nstrictness = \"Permissive\"",
+    ).unwrap();
+    court.apply_policy_pack(&pack).unwrap();
+    assert!(court.rule(shutdown("sandbox")).is_approved());
+    assert!(!court.rule(shutdown("maintenance")).is_approved());
+}
+
+#[test]
+fn invalid_profiles_are_refused_or_reported() {
+    let court = JudicialCore::new();
+    let duplicate = PolicyPack::from_toml("[[profiles]]\nname = \"a\"\nlaws = [1]\n[[profiles]]\nname = \"a\"\nlaws = [2]");
+    assert!(matches!(
+        court.apply_policy_pack(&duplicate.unwrap()),
+        Err(PolicyPackError::DuplicateProfile(name)) if name == "a"
+    ));
+    let unknown = PolicyPack::from_toml("[[profiles]]\nname = \"sandbox\"\nlaws = [7]").unwrap();
+    assert!(matches!(court.apply_policy_pack(&unknown), Err(PolicyPackError::UnknownLaw(7))));
+    assert!(!court.rule(shutdown("sandbox")).is_approved(), "a refused pack was applied");
+    assert_eq!(court.health().profiles, 0);
+
+    let empty = JudicialCore::builder().profile("staging", PolicyProfile::new(LawSet::new())).build();
+    let health = empty.health();
+    assert!(!health.healthy);
+    assert_eq!(health.law_issues, ["profile 'staging' has no laws"]);
+}
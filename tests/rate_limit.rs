@@ -0,0 +1,74 @@
+use chrono::{DateTime, Duration, Utc};
+use judicial_core::clock::Clock;
+use judicial_core::{JudicialCore, RateLimit, RateLimitScope, SystemAction, Verdict};
+use std::sync::{Arc, Mutex};
+
+/// A clock that only moves when told to.
+#[derive(Debug, Clone)]
+struct ManualClock(Arc<Mutex<DateTime<Utc>>>);
+
+impl ManualClock {
+    fn new() -> Self {
+        Self(Arc::new(Mutex::new(Utc::now())))
+    }
+
+    fn advance(&self, by: Duration) {
+        *self.0.lock().unwrap() += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap()
+    }
+}
+
+fn read_by(actor: &str) -> SystemAction {
+    SystemAction::new("DATA_READ", "SELECT name FROM users", "analytics").with_actor(actor)
+}
+
+#[test]
+fn rejections_name_sub_second_windows() {
+    let limit = RateLimit { max_rulings: 1, window: Duration::milliseconds(500) };
+    let court = JudicialCore::builder().rate_limit(RateLimitScope::AllActors, limit).build();
+
+    assert!(court.rule(read_by("agent")).is_approved());
+    let Verdict::Rejected(reason) = court.rule(read_by("agent")) else { panic!("not throttled") };
+    assert_eq!(reason, "rate limit: agent exceeded 1 rulings per 500ms");
+}
+
+#[test]
+fn windows_of_idle_actors_are_dropped() {
+    let clock = ManualClock::new();
+    let court = JudicialCore::builder()
+        .clock(clock.clone())
+        .rate_limit(RateLimitScope::AllActors, RateLimit::per_second(5))
+        .build();
+
+    for i in 0..100 {
+        court.rule(read_by(&format!("agent-{}", i)));
+    }
+    assert_eq!(court.health().rate_limit_buckets, 100);
+
+    clock.advance(Duration::seconds(2));
+    court.rule(read_by("agent-0"));
+    assert_eq!(court.health().rate_limit_buckets, 1);
+}
+
+#[test]
+fn dropping_windows_keeps_live_counts() {
+    let clock = ManualClock::new();
+    let court = JudicialCore::builder()
+        .clock(clock.clone())
+        .rate_limit(RateLimitScope::AllActors, RateLimit::per_second(2))
+        .build();
+
+    assert!(court.rule(read_by("agent")).is_approved());
+    clock.advance(Duration::milliseconds(600));
+    assert!(court.rule(read_by("agent")).is_approved());
+    clock.advance(Duration::milliseconds(600));
+    // The first window has run out and been dropped; a new one starts.
+    assert!(court.rule(read_by("agent")).is_approved());
+    assert!(court.rule(read_by("agent")).is_approved());
+    assert!(!court.rule(read_by("agent")).is_approved());
+}
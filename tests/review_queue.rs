@@ -0,0 +1,62 @@
+use judicial_core::{
+    JudicialCore, ReviewDecision, ReviewError, ReviewStatus, SystemAction, UnknownActionPolicy, Verdict,
+};
+use std::time::Duration;
+
+fn quarantining() -> JudicialCore {
+    JudicialCore::builder().unknown_action_policy(UnknownActionPolicy::Quarantine).build()
+}
+
+fn teleport() -> SystemAction {
+    SystemAction::new("TELEPORT", "beam me up", "ops")
+}
+
+#[test]
+fn a_quarantine_is_claimed_resolved_and_ledgered() {
+    let court = quarantining();
+    let submitted = court.reviews().subscribe();
+    assert!(matches!(court.rule(teleport()), Verdict::Quarantined(_)));
+
+    let item = submitted.recv_timeout(Duration::from_secs(1)).unwrap();
+    assert!(matches!(item.status, ReviewStatus::Pending));
+    assert_eq!(item.reason, "Unknown action type 'TELEPORT'");
+    let quarantine = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert_eq!(item.ledger_hash.as_deref(), Some(quarantine.hash.as_str()));
+
+    let claimed = court.claim_review(item.id, "alice").unwrap();
+    assert!(matches!(claimed.status, ReviewStatus::Claimed { ref reviewer, .. } if reviewer == "alice"));
+    assert_eq!(court.reviews().pending().len(), 1, "claimed reviews are still unresolved");
+
+    let resolved = court.resolve_review(item.id, ReviewDecision::Approve, "known transport").unwrap();
+    assert!(matches!(resolved.status, ReviewStatus::Resolved { decision: ReviewDecision::Approve, .. }));
+    assert!(court.reviews().pending().is_empty());
+
+    let decision = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert_eq!(
+        decision.verdict,
+        format!("REVIEW: APPROVED #{} by alice (known transport) for entry {}", item.id, quarantine.hash)
+    );
+    assert!(court.verify_ledger().is_ok());
+}
+
+#[test]
+fn the_lifecycle_is_enforced() {
+    let court = quarantining();
+    court.rule(teleport());
+    let id = court.reviews().pending()[0].id;
+
+    assert_eq!(court.resolve_review(id, ReviewDecision::Reject, "no").unwrap_err(), ReviewError::NotClaimed);
+    court.claim_review(id, "alice").unwrap();
+    assert_eq!(
+        court.claim_review(id, "bob").unwrap_err(),
+        ReviewError::AlreadyClaimed { reviewer: "alice".into() }
+    );
+    court.resolve_review(id, ReviewDecision::Reject, "no").unwrap();
+    assert_eq!(court.resolve_review(id, ReviewDecision::Approve, "yes").unwrap_err(), ReviewError::AlreadyResolved);
+    assert_eq!(court.claim_review(id, "bob").unwrap_err(), ReviewError::AlreadyResolved);
+    assert_eq!(court.claim_review(id + 1, "bob").unwrap_err(), ReviewError::NotFound(id + 1));
+
+    // Only the one decision reached the ledger.
+    let reviews = court.query_ledger(|e| e.verdict.starts_with("REVIEW"), 0, usize::MAX);
+    assert_eq!(reviews.len(), 1);
+}
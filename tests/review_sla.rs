@@ -0,0 +1,133 @@
+use chrono::{DateTime, Utc};
+use judicial_core::clock::Clock;
+use judicial_core::{
+    Court, JudicialCore, Observer, ReviewDecision, ReviewFallback, ReviewSla, ReviewStatus, SlaAlert, SlaStage,
+    SystemAction, UnknownActionPolicy, TIMEOUT_REVIEWER,
+};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A clock that only moves when told to.
+#[derive(Debug, Clone)]
+struct ManualClock(Arc<Mutex<DateTime<Utc>>>);
+
+impl ManualClock {
+    fn new() -> Self {
+        Self(Arc::new(Mutex::new(Utc::now())))
+    }
+
+    fn advance(&self, by: chrono::Duration) {
+        *self.0.lock().unwrap() += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap()
+    }
+}
+
+/// Shared with the core, which takes its observers by value.
+#[derive(Debug, Default, Clone)]
+struct Alerts(Arc<Mutex<Vec<SlaAlert>>>);
+
+impl Observer for Alerts {
+    fn on_review_sla(&self, alert: &SlaAlert) {
+        self.0.lock().unwrap().push(alert.clone());
+    }
+}
+
+impl Alerts {
+    fn stages(&self) -> Vec<(SlaStage, u64)> {
+        self.0.lock().unwrap().iter().map(|alert| (alert.stage, alert.item.id)).collect()
+    }
+}
+
+fn teleport() -> SystemAction {
+    SystemAction::new("TELEPORT", "beam me up", "ops")
+}
+
+fn court(sla: ReviewSla) -> (JudicialCore, ManualClock, Alerts) {
+    let clock = ManualClock::new();
+    let alerts = Alerts::default();
+    let court = JudicialCore::builder()
+        .unknown_action_policy(UnknownActionPolicy::Quarantine)
+        .clock(clock.clone())
+        .observer(alerts.clone())
+        .review_sla(sla)
+        .build();
+    (court, clock, alerts)
+}
+
+fn minutes(n: i64) -> chrono::Duration {
+    chrono::Duration::minutes(n)
+}
+
+#[test]
+fn overdue_reviews_are_rejected_after_one_warning() {
+    let sla = ReviewSla::new(Duration::from_secs(600), ReviewFallback::Reject)
+        .warn_before(Duration::from_secs(120))
+        .link_template("https://reviews.example/{id}");
+    let (court, clock, alerts) = court(sla);
+    court.rule(teleport());
+    let id = court.reviews().pending()[0].id;
+
+    clock.advance(minutes(5));
+    assert!(court.expire_reviews().is_empty());
+    assert!(alerts.stages().is_empty());
+
+    clock.advance(minutes(4));
+    assert!(court.expire_reviews().is_empty());
+    assert!(court.expire_reviews().is_empty());
+    assert_eq!(alerts.stages(), [(SlaStage::Approaching, id)]);
+
+    clock.advance(minutes(2));
+    let expired = court.expire_reviews();
+    assert_eq!(expired.len(), 1);
+    let ReviewStatus::Resolved { reviewer, decision, .. } = &expired[0].status else { panic!("not resolved") };
+    assert_eq!((reviewer.as_str(), *decision), (TIMEOUT_REVIEWER, ReviewDecision::Reject));
+    assert_eq!(alerts.stages(), [(SlaStage::Approaching, id), (SlaStage::Breached, id)]);
+    let breached = alerts.0.lock().unwrap()[1].clone();
+    assert_eq!(breached.link.as_deref(), Some(format!("https://reviews.example/{}", id).as_str()));
+
+    let entry = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert!(entry.verdict.starts_with(&format!("REVIEW: REJECTED #{} by {}", id, TIMEOUT_REVIEWER)), "{}", entry.verdict);
+    assert!(court.expire_reviews().is_empty(), "settled twice");
+}
+
+#[test]
+fn escalation_lets_another_court_decide() {
+    let lenient = Arc::new(Court::new("appellate", JudicialCore::new()));
+    let (court, clock, _) = court(ReviewSla::new(Duration::from_secs(60), ReviewFallback::Escalate(lenient)));
+    court.rule(teleport());
+    court.claim_review(court.reviews().pending()[0].id, "alice").unwrap();
+
+    clock.advance(minutes(2));
+    let expired = court.expire_reviews();
+    let ReviewStatus::Resolved { decision, rationale, .. } = &expired[0].status else { panic!("not resolved") };
+    assert_eq!(*decision, ReviewDecision::Approve, "claimed reviews still time out");
+    assert_eq!(rationale, "no decision within 60s; escalated to appellate: APPROVED");
+}
+
+#[test]
+fn reviews_decided_in_time_are_left_alone() {
+    let sla = ReviewSla::new(Duration::from_secs(60), ReviewFallback::Reject).warn_before(Duration::from_secs(30));
+    let (court, clock, alerts) = court(sla);
+    court.rule(teleport());
+    let id = court.reviews().pending()[0].id;
+    court.claim_review(id, "alice").unwrap();
+    court.resolve_review(id, ReviewDecision::Approve, "fine").unwrap();
+
+    clock.advance(minutes(2));
+    assert!(court.expire_reviews().is_empty());
+    assert!(alerts.stages().is_empty());
+
+    let without_sla = JudicialCore::builder()
+        .unknown_action_policy(UnknownActionPolicy::Quarantine)
+        .clock(clock.clone())
+        .build();
+    without_sla.rule(teleport());
+    clock.advance(minutes(60 * 24));
+    assert!(without_sla.expire_reviews().is_empty());
+    assert_eq!(without_sla.reviews().pending().len(), 1);
+}
@@ -0,0 +1,69 @@
+use judicial_core::{
+    Identity, IdentityError, IdentityProvider, JudicialCore, SystemAction, UnknownActionPolicy, Verdict,
+};
+
+fn actions() -> Vec<SystemAction> {
+    vec![
+        SystemAction::new("DATA_READ", "SELECT 1", "analytics").with_actor("agent"),
+        SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance"),
+        SystemAction::new("DATA_EXPORT", "password=hunter2", "reporting"),
+        SystemAction::new("TELEPORT", "beam me up", "ops"),
+    ]
+}
+
+fn quarantining() -> JudicialCore {
+    JudicialCore::builder().unknown_action_policy(UnknownActionPolicy::Quarantine).build()
+}
+
+#[test]
+fn borrowed_actions_are_ruled_and_recorded_like_owned_ones() {
+    let (owned, borrowed) = (quarantining(), quarantining());
+    for action in actions() {
+        let by_value = owned.rule(action.clone());
+        let by_ref = borrowed.rule_ref(&action);
+        assert_eq!(format!("{:?}", by_value), format!("{:?}", by_ref), "{:?}", action);
+    }
+
+    let recorded = |core: &JudicialCore| -> Vec<String> {
+        core.query_ledger(|_| true, 0, usize::MAX).iter()
+            .map(|e| format!("{:?} {}", e.action, e.verdict))
+            .collect()
+    };
+    assert_eq!(recorded(&owned), recorded(&borrowed));
+    assert!(borrowed.verify_ledger().is_ok());
+    assert_eq!(borrowed.reviews().pending()[0].action.action_type, "TELEPORT");
+    assert_eq!(borrowed.statistics().rulings, 4);
+}
+
+#[derive(Debug)]
+struct Tokens;
+
+impl IdentityProvider for Tokens {
+    fn verify(&self, credentials: &str) -> Result<Identity, IdentityError> {
+        match credentials {
+            "alice-token" => Ok(Identity { actor: "alice".into(), roles: vec!["ops".into()] }),
+            _ => Err(IdentityError("unknown token".into())),
+        }
+    }
+}
+
+#[test]
+fn the_callers_action_is_left_untouched() {
+    let court = JudicialCore::builder().identity_provider(Tokens).build();
+    let mut action = SystemAction::new("DATA_READ", "SELECT 1", "analytics").with_credentials("alice-token");
+    action.roles = vec!["admin".into()];
+
+    assert!(court.rule_ref(&action).is_approved());
+    assert_eq!((action.credentials.as_deref(), action.roles.as_slice()), (Some("alice-token"), &["admin".to_string()][..]));
+    assert_eq!(action.actor, None);
+    let entry = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert_eq!((entry.action.actor.as_deref(), entry.action.roles.as_slice()), (Some("alice"), &["ops".to_string()][..]));
+    assert_eq!(entry.action.credentials, None);
+
+    let forged = SystemAction::new("DATA_READ", "SELECT 1", "analytics").with_credentials("forged");
+    assert!(matches!(court.rule_ref(&forged), Verdict::Rejected(reason) if reason == "identity not verified: unknown token"));
+    assert_eq!(forged.credentials.as_deref(), Some("forged"));
+
+    court.shutdown().unwrap();
+    assert!(matches!(court.rule_ref(&actions()[0]), Verdict::Rejected(_)));
+}
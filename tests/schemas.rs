@@ -0,0 +1,50 @@
+#![cfg(feature = "schemas")]
+
+use judicial_core::schemas::SchemaError;
+use judicial_core::{JudicialCore, SystemAction, Verdict};
+use serde_json::json;
+
+fn write(payload: &str) -> SystemAction {
+    SystemAction::new("DATA_WRITE", payload, "ops")
+}
+
+fn court() -> JudicialCore {
+    let schema = json!({
+        "type": "object",
+        "properties": { "table": { "type": "string" }, "rows": { "type": "integer", "minimum": 1 } },
+        "required": ["table"],
+    });
+    JudicialCore::builder().action_schema("DATA_WRITE", &schema).unwrap().build()
+}
+
+#[test]
+fn payloads_matching_the_schema_go_on_to_the_laws() {
+    let court = court();
+    assert!(matches!(court.rule(write(r#"{"table": "orders", "rows": 10}"#)), Verdict::Approved));
+    // Other action types are not checked.
+    assert!(court.rule(SystemAction::new("DATA_READ", "not json", "analytics")).is_approved());
+
+    assert!(!court.rule(write(r#"{"table": "users; drop table users"}"#)).is_approved());
+}
+
+#[test]
+fn malformed_payloads_are_refused_before_any_law() {
+    let court = court();
+    let Verdict::Malformed(reason) = court.rule(write("table=orders")) else { panic!("not malformed") };
+    assert!(reason.starts_with("DATA_WRITE payload is not JSON: "), "{}", reason);
+
+    let Verdict::Malformed(reason) = court.rule(write(r#"{"rows": 0}"#)) else { panic!("not malformed") };
+    assert!(reason.starts_with("DATA_WRITE payload does not match its schema: "), "{}", reason);
+    assert!(reason.contains("\"table\" is a required property"), "{}", reason);
+    assert!(reason.contains("/rows: "), "{}", reason);
+
+    let entry = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert!(entry.verdict.starts_with("REJECTED_MALFORMED: "), "{}", entry.verdict);
+}
+
+#[test]
+fn invalid_schemas_are_refused_when_building() {
+    let error = JudicialCore::builder().action_schema("DATA_WRITE", &json!({ "type": 12 })).unwrap_err();
+    assert!(matches!(&error, SchemaError { action_type, .. } if action_type == "DATA_WRITE"));
+    assert!(error.to_string().starts_with("invalid schema for DATA_WRITE: "), "{}", error);
+}
@@ -0,0 +1,43 @@
+use judicial_core::laws::SafetyLaw;
+use judicial_core::{JudicialCore, LawSet, PolicyProfile, RateLimit, RateLimitScope, Verdict};
+
+#[test]
+fn scoped_rulings_carry_the_context_and_actor() {
+    let core = JudicialCore::builder()
+        .profile("sandbox", PolicyProfile::new(LawSet::new().with(SafetyLaw)))
+        .build();
+    let sandbox = core.scoped("sandbox", "agent-7");
+    assert_eq!((sandbox.context(), sandbox.actor()), ("sandbox", "agent-7"));
+
+    let action = sandbox.action("DATA_EXPORT", "report", "encrypted");
+    assert_eq!((action.context.as_str(), action.actor.as_deref()), ("sandbox,encrypted", Some("agent-7")));
+    assert_eq!(sandbox.action("DATA_READ", "SELECT 1", "").context, "sandbox");
+
+    // The sandbox profile leaves out the second law.
+    assert!(sandbox.rule("SYSTEM_SHUTDOWN", "halt").is_approved());
+    assert!(!core.scoped("maintenance", "agent-7").rule("SYSTEM_SHUTDOWN", "halt").is_approved());
+
+    let entry = sandbox.core().query_ledger(|_| true, 0, usize::MAX).remove(0);
+    assert_eq!((entry.action.context.as_str(), entry.action.actor.as_deref()), ("sandbox", Some("agent-7")));
+}
+
+#[test]
+fn flags_are_judged_with_the_scope() {
+    let core = JudicialCore::new();
+    let ops = core.scoped("ops", "agent-7");
+    assert!(matches!(ops.rule_with("SYSTEM_SHUTDOWN", "halt", "maintenance"), Verdict::RejectedWithSuggestion(..)));
+    assert!(ops.rule_with("SYSTEM_SHUTDOWN", "halt", "emergency").is_approved());
+}
+
+#[test]
+fn each_scope_is_rate_limited_by_its_actor() {
+    let limit = RateLimit { max_rulings: 1, window: chrono::Duration::seconds(60) };
+    let core = JudicialCore::builder().rate_limit(RateLimitScope::AllActors, limit).build();
+    let first = core.scoped("sandbox", "agent-1");
+    let second = core.scoped("sandbox", "agent-2");
+
+    assert!(first.rule("DATA_READ", "SELECT 1").is_approved());
+    let Verdict::Rejected(reason) = first.rule("DATA_READ", "SELECT 1") else { panic!("not throttled") };
+    assert!(reason.starts_with("rate limit: agent-1 exceeded"), "{}", reason);
+    assert!(second.rule("DATA_READ", "SELECT 1").is_approved());
+}
@@ -0,0 +1,103 @@
+use judicial_core::laws::Law;
+use judicial_core::{
+    JudicialCore, PolicyPack, PolicyPackError, ShutDownError, SnapshotError, Strictness, SystemAction, Verdict,
+};
+use std::io;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A law that reports each action it is asked about, then holds it until
+/// released.
+#[derive(Debug)]
+struct Gate {
+    entered: Mutex<Sender<()>>,
+    release: Mutex<Receiver<()>>,
+}
+
+impl Law for Gate {
+    fn number(&self) -> u32 {
+        9
+    }
+
+    fn description(&self) -> &str {
+        "waits to be released"
+    }
+
+    fn check(&self, _action: &SystemAction) -> Option<String> {
+        self.entered.lock().unwrap().send(()).unwrap();
+        self.release.lock().unwrap().recv().unwrap();
+        None
+    }
+}
+
+/// A core whose rulings wait for the returned sender, and a receiver told
+/// when a ruling reaches the gate.
+fn gated(timeout: Duration) -> (Arc<JudicialCore>, Receiver<()>, Sender<()>) {
+    let (entered, reached) = mpsc::channel();
+    let (release, released) = mpsc::channel();
+    let gate = Gate { entered: Mutex::new(entered), release: Mutex::new(released) };
+    let core = JudicialCore::builder().law(gate).shutdown_timeout(timeout).build();
+    (Arc::new(core), reached, release)
+}
+
+fn read() -> SystemAction {
+    SystemAction::new("DATA_READ", "SELECT 1", "analytics")
+}
+
+#[test]
+fn shutdown_reports_rulings_still_in_flight_at_the_deadline() {
+    let (core, reached, release) = gated(Duration::from_millis(100));
+    let ruling = thread::spawn({
+        let core = Arc::clone(&core);
+        move || core.rule(read())
+    });
+    reached.recv().unwrap();
+
+    let error = core.shutdown().unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::TimedOut);
+    assert!(error.to_string().starts_with("1 rulings still in flight"), "{}", error);
+    assert!(matches!(core.rule(read()), Verdict::Rejected(_)), "accepted a ruling after shutdown");
+
+    release.send(()).unwrap();
+    assert!(ruling.join().unwrap().is_approved());
+    core.shutdown().unwrap();
+    let entries = core.query_ledger(|_| true, 0, usize::MAX);
+    let recorded: Vec<&str> = entries.iter().map(|e| e.verdict.as_str()).collect();
+    assert_eq!(recorded, ["APPROVED", "SHUTDOWN"]);
+    core.shutdown().unwrap();
+}
+
+#[test]
+fn shutdown_returns_as_soon_as_the_last_ruling_finishes() {
+    let (core, reached, release) = gated(Duration::from_secs(30));
+    let ruling = thread::spawn({
+        let core = Arc::clone(&core);
+        move || core.rule(read())
+    });
+    reached.recv().unwrap();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        release.send(()).unwrap();
+    });
+
+    let started = Instant::now();
+    core.shutdown().unwrap();
+    assert!(started.elapsed() < Duration::from_secs(5), "waited {:?}", started.elapsed());
+    assert!(ruling.join().unwrap().is_approved());
+}
+
+#[test]
+fn governance_changes_fail_after_shutdown() {
+    let core = JudicialCore::new();
+    let snapshot = core.snapshot();
+    core.shutdown().unwrap();
+
+    assert_eq!(core.set_strictness(Strictness::Paranoid), Err(ShutDownError));
+    assert_eq!(core.strictness(), Strictness::Standard);
+    let pack = PolicyPack::from_toml("laws = [1]").unwrap();
+    assert!(matches!(core.apply_policy_pack(&pack), Err(PolicyPackError::ShutDown)));
+    assert!(matches!(core.restore(&snapshot), Err(SnapshotError::ShutDown)));
+    assert_eq!(core.query_ledger(|_| true, 0, usize::MAX).len(), 1);
+}
@@ -0,0 +1,71 @@
+use judicial_core::{CoreSnapshot, JudicialCore, PolicyPack, SnapshotError, Strictness, SystemAction};
+
+fn shutdown() -> SystemAction {
+    SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance")
+}
+
+fn only_law_1() -> PolicyPack {
+    PolicyPack::from_toml("laws = [1]").unwrap()
+}
+
+#[test]
+fn restore_returns_to_the_snapshot_strictness() {
+    let court = JudicialCore::new();
+    court.rule(SystemAction::new("DATA_READ", "SELECT 1", "analytics"));
+    let snapshot = court.snapshot();
+
+    court.set_strictness(Strictness::Paranoid).unwrap();
+    court.restore(&snapshot).unwrap();
+
+    assert_eq!(court.strictness(), Strictness::Standard);
+    let last = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert_eq!(last.action.action_type, "POLICY_CHANGE");
+    assert!(last.action.payload.starts_with("restore: restored snapshot at entry 1"));
+    assert!(court.verify_ledger().is_ok());
+}
+
+#[test]
+fn restore_brings_back_laws_changed_since() {
+    let court = JudicialCore::new();
+    let snapshot = court.snapshot();
+
+    court.apply_policy_pack(&only_law_1()).unwrap();
+    assert!(court.rule(shutdown()).is_approved());
+
+    court.restore(&snapshot).unwrap();
+    assert!(!court.rule(shutdown()).is_approved());
+    assert_eq!(court.snapshot().laws, vec![1, 2]);
+}
+
+#[test]
+fn a_deserialized_snapshot_restores_strictness_over_the_same_laws() {
+    let court = JudicialCore::new();
+    let snapshot: CoreSnapshot = serde_json::from_str(&serde_json::to_string(&court.snapshot()).unwrap()).unwrap();
+
+    court.set_strictness(Strictness::Permissive).unwrap();
+    court.restore(&snapshot).unwrap();
+    assert_eq!(court.strictness(), Strictness::Standard);
+
+    court.apply_policy_pack(&only_law_1()).unwrap();
+    assert_eq!(
+        court.restore(&snapshot),
+        Err(SnapshotError::LawSetMismatch { expected: vec![1, 2], found: vec![1] })
+    );
+}
+
+#[test]
+fn snapshots_do_not_cross_ledgers() {
+    let other = JudicialCore::new();
+    other.rule(SystemAction::new("DATA_READ", "SELECT 1", "analytics"));
+
+    let court = JudicialCore::new();
+    assert_eq!(court.restore(&other.snapshot()), Err(SnapshotError::ForeignLedger));
+}
+
+#[test]
+fn restore_is_refused_after_shutdown() {
+    let court = JudicialCore::new();
+    let snapshot = court.snapshot();
+    court.shutdown().unwrap();
+    assert_eq!(court.restore(&snapshot), Err(SnapshotError::ShutDown));
+}
@@ -0,0 +1,53 @@
+use judicial_core::{ComplianceWindow, JudicialCore, RateLimit, RateLimitScope, SystemAction};
+
+#[test]
+fn law_fired_counts_every_violated_law_like_compliance() {
+    let court = JudicialCore::new();
+    court.rule(SystemAction::new("DATA_WRITE", "UPDATE users SET password = 'x'; drop table users", "ops"));
+    court.rule(SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance"));
+    court.rule(SystemAction::new("DATA_READ", "SELECT name FROM users", "analytics"));
+
+    let fired = court.statistics().law_fired;
+    assert_eq!(fired.iter().map(|(law, n)| (*law, *n)).collect::<Vec<_>>(), [(1, 1), (2, 2)]);
+
+    let report = court.compliance_report(ComplianceWindow::Lifetime);
+    for (law, rejections) in fired {
+        assert_eq!(report.by_law[&law].rejections as u64, rejections, "law {}", law);
+    }
+}
+
+#[test]
+fn rulings_are_counted_by_verdict_and_action_type() {
+    let court = JudicialCore::new();
+    court.rule(SystemAction::new("DATA_READ", "SELECT name FROM users", "analytics"));
+    court.rule(SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance"));
+    court.rule(SystemAction::new("SYSTEM_SHUTDOWN", "halt", "emergency"));
+    court.rule(SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance"));
+    court.rule(SystemAction::new("DATA_WRITE", "drop table users", "ops"));
+
+    let stats = court.statistics();
+    assert_eq!(stats.rulings, 5);
+    let verdicts: Vec<(&str, u64)> = stats.verdicts.iter().map(|(v, n)| (v.as_str(), *n)).collect();
+    assert_eq!(verdicts, [("APPROVED", 2), ("REJECTED_WITH_SUGGESTION", 3)]);
+    assert_eq!(stats.rejected_action_types, [("SYSTEM_SHUTDOWN".to_string(), 2), ("DATA_WRITE".to_string(), 1)]);
+    assert!(stats.max_latency_us as f64 >= stats.average_latency_us);
+}
+
+#[test]
+fn throttled_and_unheard_rulings() {
+    let idle = JudicialCore::new().statistics();
+    assert_eq!((idle.rulings, idle.average_latency_us, idle.max_latency_us), (0, 0.0, 0));
+    assert!(idle.verdicts.is_empty() && idle.rejected_action_types.is_empty());
+
+    let limit = RateLimit { max_rulings: 1, window: chrono::Duration::seconds(60) };
+    let court = JudicialCore::builder().rate_limit(RateLimitScope::AllActors, limit).build();
+    let read = SystemAction::new("DATA_READ", "SELECT 1", "analytics").with_actor("agent");
+    assert!(court.rule(read.clone()).is_approved());
+    assert!(!court.rule(read).is_approved());
+
+    let stats = court.statistics();
+    assert_eq!((stats.rate_limits.allowed, stats.rate_limits.throttled), (1, 1));
+    assert_eq!(stats.rate_limits.throttled_by_actor["agent"], 1);
+    assert_eq!(stats.verdicts["REJECTED"], 1, "throttled rulings are rulings too");
+    assert_eq!(stats.rejected_action_types, [("DATA_READ".to_string(), 1)]);
+}
@@ -0,0 +1,31 @@
+use judicial_core::{JudicialCore, Strictness, SystemAction, Verdict};
+
+/// Lawful, but the rollback is promised in the payload, not the context.
+fn cleanup() -> SystemAction {
+    SystemAction::new("SYSTEM_CMD", "backup && rm -rf /data/temp", "admin")
+}
+
+#[test]
+fn standard_strictness_keeps_plain_approvals() {
+    let court = JudicialCore::new();
+    assert!(matches!(court.rule(cleanup()), Verdict::Approved));
+
+    let entry = court.query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert_eq!(entry.verdict, "APPROVED");
+}
+
+#[test]
+fn permissive_strictness_does_not_add_warnings() {
+    let court = JudicialCore::with_strictness(Strictness::Permissive);
+    assert!(matches!(court.rule(cleanup()), Verdict::Approved));
+}
+
+#[test]
+fn paranoid_strictness_rejects_undeclared_rollbacks() {
+    let court = JudicialCore::with_strictness(Strictness::Paranoid);
+    let Verdict::RejectedWithSuggestion(reason, _) = court.rule(cleanup()) else { panic!("not rejected") };
+    assert_eq!(reason, "Destructive action 'rm -rf' relies on undeclared rollback");
+
+    let declared = SystemAction::new("SYSTEM_CMD", "backup && rm -rf /data/temp", "admin rollback");
+    assert!(matches!(court.rule(declared), Verdict::Approved));
+}
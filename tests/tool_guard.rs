@@ -0,0 +1,89 @@
+use judicial_core::{JudicialCore, ToolCall, ToolCallGuard, ToolDecision, UnknownActionPolicy};
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+fn call(tool: &str, arguments: Value) -> ToolCall {
+    ToolCall { tool: tool.into(), arguments, agent_id: "agent-7".into(), credentials: None }
+}
+
+fn guard() -> ToolCallGuard {
+    ToolCallGuard::new(Arc::new(JudicialCore::new()))
+        .map_tool("shell", "SYSTEM_CMD")
+        .map_tool("power", "SYSTEM_SHUTDOWN")
+}
+
+#[test]
+fn lawful_calls_are_allowed_and_ledgered_for_the_agent() {
+    let guard = guard();
+    let decision = guard.check(&call("shell", json!({ "cmd": "backup && rm -rf /data/temp" })));
+    assert_eq!(decision, ToolDecision::Allow { warning: None });
+
+    let entry = guard.core().query_ledger(|_| true, 0, usize::MAX).pop().unwrap();
+    assert_eq!(entry.action.action_type, "SYSTEM_CMD");
+    assert_eq!(entry.action.payload, r#"{"cmd":"backup && rm -rf /data/temp"}"#);
+    assert_eq!((entry.action.context.as_str(), entry.action.actor.as_deref()), ("tool_call", Some("agent-7")));
+
+    let guard = guard.context("tool_call,emergency");
+    assert!(guard.check(&call("power", json!({ "mode": "halt" }))).is_allowed());
+    assert_eq!(guard.action(&call("search", json!("rust"))).action_type, "search", "unmapped tools keep their name");
+}
+
+#[test]
+fn unlawful_calls_are_denied() {
+    let guard = guard();
+    let decision = guard.check(&call("power", json!({ "mode": "halt" })));
+    assert_eq!(decision, ToolDecision::Deny { reason: "Non-emergency system shutdown".into() });
+    assert!(!decision.is_allowed());
+    assert_eq!(
+        serde_json::to_value(&decision).unwrap(),
+        json!({ "decision": "deny", "reason": "Non-emergency system shutdown" })
+    );
+
+    let quarantining = JudicialCore::builder().unknown_action_policy(UnknownActionPolicy::Quarantine).build();
+    let guard = ToolCallGuard::new(Arc::new(quarantining));
+    assert_eq!(
+        guard.check(&call("teleport", json!({}))),
+        ToolDecision::Deny { reason: "held for human review: Unknown action type 'teleport'".into() }
+    );
+}
+
+#[test]
+fn rejected_calls_can_be_rewritten() {
+    let offered = Arc::new(AtomicUsize::new(0));
+    let seen = Arc::clone(&offered);
+    let guard = guard().rewriter(move |call, suggestion| {
+        seen.fetch_add(1, Ordering::SeqCst);
+        assert_eq!(suggestion, "Provide rollback mechanism or sandbox execution.");
+        let cmd = call.arguments["cmd"].as_str()?;
+        let rewritten = if cmd.starts_with("rm ") {
+            format!("backup /data/temp && {}", cmd)
+        } else {
+            format!("{} --dry-run", cmd)
+        };
+        Some(json!({ "cmd": rewritten }))
+    });
+
+    let decision = guard.check(&call("shell", json!({ "cmd": "rm -rf /data/temp" })));
+    assert_eq!(
+        decision,
+        ToolDecision::Modify {
+            arguments: json!({ "cmd": "backup /data/temp && rm -rf /data/temp" }),
+            reason: "Destructive action 'rm -rf' without rollback".into(),
+        }
+    );
+    assert!(decision.is_allowed());
+    let recorded: Vec<String> = guard.core().query_ledger(|_| true, 0, usize::MAX).into_iter()
+        .map(|e| e.verdict)
+        .collect();
+    assert_eq!(recorded.len(), 2, "both the call and its rewrite are ruled on");
+    assert_eq!(recorded[1], "APPROVED");
+
+    // A rewrite that is still unlawful, or no rewrite, leaves the denial.
+    assert_eq!(
+        guard.check(&call("shell", json!({ "cmd": "drop table users" }))),
+        ToolDecision::Deny { reason: "Destructive action 'drop table' without rollback".into() }
+    );
+    assert!(!guard.check(&call("power", json!({ "mode": "halt" }))).is_allowed());
+    assert_eq!(offered.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,64 @@
+use judicial_core::{JudicialCore, Law, SystemAction, UnknownActionPolicy, Verdict};
+
+fn court(policy: UnknownActionPolicy) -> JudicialCore {
+    JudicialCore::builder().unknown_action_policy(policy).build()
+}
+
+fn action(action_type: &str) -> SystemAction {
+    SystemAction::new(action_type, "SELECT name FROM users", "analytics")
+}
+
+/// Keeps the default `governs`, so every action type is known to it.
+#[derive(Debug)]
+struct Catchall;
+
+impl Law for Catchall {
+    fn number(&self) -> u32 {
+        3
+    }
+
+    fn description(&self) -> &str {
+        "Objects to nothing"
+    }
+
+    fn check(&self, _action: &SystemAction) -> Option<String> {
+        None
+    }
+}
+
+#[test]
+fn fail_open_approves_types_the_master_pair_does_not_govern() {
+    assert!(matches!(court(UnknownActionPolicy::FailOpen).rule(action("TELEPORT")), Verdict::Approved));
+}
+
+#[test]
+fn fail_closed_rejects_types_the_master_pair_does_not_govern() {
+    let court = court(UnknownActionPolicy::FailClosed);
+    assert!(matches!(court.rule(action("DATA_READ")), Verdict::Approved));
+    assert!(matches!(court.rule(action("TELEPORT")), Verdict::Rejected(reason) if reason.contains("TELEPORT")));
+}
+
+#[test]
+fn quarantine_holds_types_the_master_pair_does_not_govern() {
+    let court = court(UnknownActionPolicy::Quarantine);
+    assert!(matches!(court.rule(action("TELEPORT")), Verdict::Quarantined(_)));
+}
+
+#[test]
+fn declared_types_narrow_what_is_known() {
+    let court = JudicialCore::builder()
+        .known_action_types(["DATA_READ"])
+        .unknown_action_policy(UnknownActionPolicy::FailClosed)
+        .build();
+    assert!(matches!(court.rule(action("DATA_READ")), Verdict::Approved));
+    assert!(!court.rule(action("DATA_WRITE")).is_approved());
+}
+
+#[test]
+fn a_law_governing_everything_leaves_nothing_unknown() {
+    let court = JudicialCore::builder()
+        .law(Catchall)
+        .unknown_action_policy(UnknownActionPolicy::FailClosed)
+        .build();
+    assert!(matches!(court.rule(action("TELEPORT")), Verdict::Approved));
+}
@@ -0,0 +1,90 @@
+use chrono::{DateTime, Utc};
+use judicial_core::clock::Clock;
+use judicial_core::{JudicialCore, PolicyPack, Strictness, SystemAction, Verdict};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A clock that only moves when told to.
+#[derive(Debug, Clone)]
+struct ManualClock(Arc<Mutex<DateTime<Utc>>>);
+
+impl ManualClock {
+    fn new() -> Self {
+        Self(Arc::new(Mutex::new(Utc::now())))
+    }
+
+    fn advance(&self, by: chrono::Duration) {
+        *self.0.lock().unwrap() += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.0.lock().unwrap()
+    }
+}
+
+fn read() -> SystemAction {
+    SystemAction::new("DATA_READ", "SELECT name FROM users", "analytics")
+}
+
+/// Lawful, but the rollback is promised in the payload, not the context.
+fn cleanup() -> SystemAction {
+    SystemAction::new("SYSTEM_CMD", "backup && rm -rf /data/temp", "admin")
+}
+
+fn shutdown() -> SystemAction {
+    SystemAction::new("SYSTEM_SHUTDOWN", "halt", "maintenance")
+}
+
+#[test]
+fn entries_expire_after_their_ttl() {
+    let clock = ManualClock::new();
+    let court = JudicialCore::builder().clock(clock.clone()).verdict_cache(16, Duration::from_secs(10)).build();
+
+    court.rule(read());
+    court.rule(read());
+    let stats = court.cache_stats().unwrap();
+    assert_eq!((stats.hits, stats.misses, stats.expired), (1, 1, 0));
+
+    clock.advance(chrono::Duration::seconds(11));
+    court.rule(read());
+    let stats = court.cache_stats().unwrap();
+    assert_eq!((stats.hits, stats.misses, stats.expired), (1, 2, 1));
+}
+
+#[test]
+fn a_strictness_change_starts_a_new_epoch() {
+    let court = JudicialCore::builder().verdict_cache(16, Duration::from_secs(60)).build();
+    assert!(matches!(court.rule(cleanup()), Verdict::Approved));
+
+    court.set_strictness(Strictness::Paranoid).unwrap();
+    let stats = court.cache_stats().unwrap();
+    assert_eq!((stats.epoch, stats.invalidations, stats.entries), (1, 1, 0));
+    assert!(!court.rule(cleanup()).is_approved(), "served a verdict from the old policy");
+}
+
+#[test]
+fn a_policy_pack_and_a_restore_empty_the_cache() {
+    let court = JudicialCore::builder().verdict_cache(16, Duration::from_secs(60)).build();
+    let snapshot = court.snapshot();
+    assert!(!court.rule(shutdown()).is_approved());
+
+    court.apply_policy_pack(&PolicyPack::from_toml("laws = [1]").unwrap()).unwrap();
+    assert!(court.rule(shutdown()).is_approved());
+
+    court.restore(&snapshot).unwrap();
+    assert!(!court.rule(shutdown()).is_approved());
+    assert_eq!(court.cache_stats().unwrap().hits, 0);
+}
+
+#[test]
+fn actors_do_not_share_entries() {
+    let court = JudicialCore::builder().verdict_cache(16, Duration::from_secs(60)).build();
+    court.rule(read().with_actor("alice"));
+    court.rule(read().with_actor("bob"));
+    court.rule(read().with_actor("alice"));
+
+    let stats = court.cache_stats().unwrap();
+    assert_eq!((stats.hits, stats.misses, stats.entries), (1, 2, 2));
+}
@@ -0,0 +1,19 @@
+//! Runs on the host with `cargo test --features wasm --test wasm`, and in
+//! the browser runtime with `--target wasm32-unknown-unknown` and
+//! `wasm-bindgen-test-runner` as the target's runner.
+#![cfg(feature = "wasm")]
+
+use judicial_core::wasm::WasmCore;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+#[cfg_attr(not(target_arch = "wasm32"), test)]
+fn rules_and_ledgers_through_the_bindings() {
+    let core = WasmCore::new();
+    assert_eq!(core.rule("DATA_READ", "SELECT 1", "standard"), "\"Approved\"");
+    assert!(core.rule("DATA_READ", "SELECT password FROM users", "standard").contains("Rejected"));
+    assert_eq!(core.compliance_score(), 0.5);
+    let ledger: Vec<serde_json::Value> = serde_json::from_str(&core.export_ledger()).unwrap();
+    assert_eq!(ledger.len(), 2);
+}
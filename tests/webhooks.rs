@@ -0,0 +1,144 @@
+#![cfg(feature = "webhooks")]
+
+use chrono::Utc;
+use judicial_core::webhooks::{WebhookConfig, WebhookNotifier};
+use judicial_core::{JudicialCore, Observer, ReviewItem, ReviewStatus, SystemAction, Verdict};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A URL nothing listens on.
+fn dead_url() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    format!("http://{}/hook", listener.local_addr().unwrap())
+}
+
+/// A URL that accepts connections and never answers.
+fn silent_url() -> (String, TcpListener) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    (format!("http://{}/hook", listener.local_addr().unwrap()), listener)
+}
+
+/// An endpoint that answers 200 and hands over each body it receives.
+fn live_url() -> (String, Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let (bodies, received) = mpsc::channel();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut reader = BufReader::new(stream.unwrap());
+            let mut length = 0;
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                line.clear();
+            }
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+            let mut stream = reader.into_inner();
+            stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").unwrap();
+            if bodies.send(String::from_utf8(body).unwrap()).is_err() {
+                break;
+            }
+        }
+    });
+    (url, received)
+}
+
+fn reject(notifier: &WebhookNotifier, payload: &str) {
+    let action = SystemAction::new("SYSTEM_SHUTDOWN", payload, "maintenance");
+    notifier.on_verdict(&action, &Verdict::Rejected("Law 1".into()));
+}
+
+#[test]
+fn a_full_queue_drops_the_oldest_deliveries() {
+    let config = WebhookConfig::new(dead_url()).retries(3, Duration::from_secs(60)).queue_capacity(4);
+    let notifier = WebhookNotifier::new(config);
+    for i in 0..10 {
+        reject(&notifier, &format!("halt {}", i));
+    }
+
+    // Four wait in the queue and the worker may hold one more.
+    assert!(notifier.dropped() >= 5, "dropped {}", notifier.dropped());
+
+    let closing = Instant::now();
+    drop(notifier);
+    assert!(closing.elapsed() < Duration::from_secs(10), "close waited out the backoff");
+}
+
+#[test]
+fn a_failing_endpoint_does_not_hold_up_the_others() {
+    let (live, received) = live_url();
+    let config = WebhookConfig::new(dead_url()).url(live).retries(5, Duration::from_secs(60));
+    let notifier = WebhookNotifier::new(config);
+    for i in 1..=3 {
+        reject(&notifier, &"x".repeat(i));
+    }
+
+    for i in 1..=3 {
+        let body = received.recv_timeout(Duration::from_secs(5)).expect("live endpoint starved");
+        assert!(body.contains(&format!("\"payload_bytes\":{}", i)), "{}", body);
+    }
+    assert_eq!(notifier.dropped(), 0);
+}
+
+#[test]
+fn webhooks_withhold_the_payload() {
+    let (live, received) = live_url();
+    let notifier = WebhookNotifier::new(WebhookConfig::new(live));
+    let court = JudicialCore::builder().observer(notifier).build();
+
+    let payload = "UPDATE users SET password = 'hunter2'";
+    let action = SystemAction::new("DATA_WRITE", payload, "ops").with_actor("agent");
+    assert!(!court.rule(action.clone()).is_approved());
+
+    let body = received.recv_timeout(Duration::from_secs(5)).expect("no verdict event");
+    assert!(!body.contains("hunter2"), "{}", body);
+    let event: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(event["event"], "verdict");
+    assert_eq!(event["law"], 1);
+    assert_eq!(event["action"]["action_type"], "DATA_WRITE");
+    assert_eq!(event["action"]["actor"], "agent");
+    assert_eq!(event["action"]["payload_bytes"], payload.len());
+    drop(court);
+
+    let (live, received) = live_url();
+    let notifier = WebhookNotifier::new(WebhookConfig::new(live));
+    notifier.on_review_required(&ReviewItem {
+        id: 7,
+        action,
+        reason: "Sensitive data 'password' without proper protection".into(),
+        submitted_at: Utc::now(),
+        ledger_hash: Some("abc123".into()),
+        status: ReviewStatus::Pending,
+    });
+    let body = received.recv_timeout(Duration::from_secs(5)).expect("no review event");
+    assert!(!body.contains("hunter2"), "{}", body);
+    let event: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(event["review"]["ledger_hash"], "abc123");
+}
+
+#[test]
+fn dropping_gives_up_on_a_silent_endpoint_at_the_deadline() {
+    let (silent, _listener) = silent_url();
+    let mut config = WebhookConfig::new(silent)
+        .retries(3, Duration::from_secs(60))
+        .queue_capacity(100)
+        .shutdown_timeout(Duration::from_millis(500));
+    config.timeout = Duration::from_millis(200);
+    let notifier = WebhookNotifier::new(config);
+    for i in 1..=50 {
+        reject(&notifier, &"x".repeat(i));
+    }
+
+    // Fifty deliveries of three 200ms attempts each would take half a minute.
+    let closing = Instant::now();
+    drop(notifier);
+    assert!(closing.elapsed() < Duration::from_secs(2), "close took {:?}", closing.elapsed());
+}